@@ -318,6 +318,36 @@ impl Struct {
     }
 }
 
+/// The description of a struct declared by a module, which clients need to decode its values,
+/// e.g. the payloads of events.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StructAbi {
+    pub name: Identifier,
+    pub abilities: AbilitySet,
+    pub type_param_count: usize,
+    /// The fields in declaration order. Their types refer to the type parameters of the struct
+    /// with `Type::TypeParameter`, so they can be instantiated with `Type::subst`.
+    pub fields: Vec<(Identifier, Type)>,
+}
+
+/// Returns the ABIs of the structs declared in `module`, in declaration order. Native structs
+/// have no fields.
+pub fn module_struct_abis(module: &CompiledModule) -> Vec<StructAbi> {
+    module
+        .struct_defs()
+        .iter()
+        .map(|def| {
+            let (name, s) = Struct::new(module, def);
+            StructAbi {
+                name,
+                abilities: s.abilities,
+                type_param_count: s.type_parameters.len(),
+                fields: s.fields.into_iter().map(|f| (f.name, f.type_)).collect(),
+            }
+        })
+        .collect()
+}
+
 impl Function {
     /// Create a `FunctionSignature` for `FunctionHandle` `f` in module `m`.
     pub fn new(m: &CompiledModule, def: &FunctionDefinition) -> (Identifier, Self) {
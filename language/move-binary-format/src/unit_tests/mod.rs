@@ -6,5 +6,6 @@ mod binary_tests;
 mod compatibility_tests;
 mod control_flow_graph_tests;
 mod deserializer_tests;
+mod normalized_tests;
 mod number_tests;
 mod signature_token_tests;
@@ -0,0 +1,179 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    file_format::*,
+    normalized::{module_struct_abis, StructAbi, Type},
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{StructTag, TypeTag},
+};
+
+// module 0x2::M {
+//     struct Coin has store { value: u64 }
+//     struct Pair<T: copy> has copy, drop { first: T, items: vector<T>, coin: Coin }
+//     struct Wrapper<T> { pair: Pair<T>, owner: address }
+// }
+fn mk_module() -> CompiledModule {
+    let copy_param = StructTypeParameter {
+        constraints: AbilitySet::EMPTY | Ability::Copy,
+        is_phantom: false,
+    };
+    let field = |name: u16, ty: SignatureToken| FieldDefinition {
+        name: IdentifierIndex(name),
+        signature: TypeSignature(ty),
+    };
+    let param = SignatureToken::TypeParameter(0);
+    CompiledModule {
+        version: crate::file_format_common::VERSION_MAX,
+        module_handles: vec![ModuleHandle {
+            address: AddressIdentifierIndex(0),
+            name: IdentifierIndex(0),
+        }],
+        self_module_handle_idx: ModuleHandleIndex(0),
+        identifiers: [
+            "M", "Coin", "Pair", "Wrapper", "value", "first", "items", "coin", "pair", "owner",
+        ]
+        .iter()
+        .map(|name| Identifier::new(*name).unwrap())
+        .collect(),
+        address_identifiers: vec![AccountAddress::TWO],
+        struct_handles: vec![
+            StructHandle {
+                module: ModuleHandleIndex(0),
+                name: IdentifierIndex(1),
+                abilities: AbilitySet::EMPTY | Ability::Store,
+                type_parameters: vec![],
+            },
+            StructHandle {
+                module: ModuleHandleIndex(0),
+                name: IdentifierIndex(2),
+                abilities: AbilitySet::EMPTY | Ability::Copy | Ability::Drop,
+                type_parameters: vec![copy_param],
+            },
+            StructHandle {
+                module: ModuleHandleIndex(0),
+                name: IdentifierIndex(3),
+                abilities: AbilitySet::EMPTY,
+                type_parameters: vec![StructTypeParameter {
+                    constraints: AbilitySet::EMPTY,
+                    is_phantom: false,
+                }],
+            },
+        ],
+        struct_defs: vec![
+            StructDefinition {
+                struct_handle: StructHandleIndex(0),
+                field_information: StructFieldInformation::Declared(vec![field(
+                    4,
+                    SignatureToken::U64,
+                )]),
+            },
+            StructDefinition {
+                struct_handle: StructHandleIndex(1),
+                field_information: StructFieldInformation::Declared(vec![
+                    field(5, param.clone()),
+                    field(6, SignatureToken::Vector(Box::new(param.clone()))),
+                    field(7, SignatureToken::Struct(StructHandleIndex(0))),
+                ]),
+            },
+            StructDefinition {
+                struct_handle: StructHandleIndex(2),
+                field_information: StructFieldInformation::Declared(vec![
+                    field(
+                        8,
+                        SignatureToken::StructInstantiation(StructHandleIndex(1), vec![param]),
+                    ),
+                    field(9, SignatureToken::Address),
+                ]),
+            },
+        ],
+        function_handles: vec![],
+        function_defs: vec![],
+        signatures: vec![],
+        constant_pool: vec![],
+        metadata: vec![],
+        field_handles: vec![],
+        friend_decls: vec![],
+        struct_def_instantiations: vec![],
+        function_instantiations: vec![],
+        field_instantiations: vec![],
+    }
+}
+
+fn ident(name: &str) -> Identifier {
+    Identifier::new(name).unwrap()
+}
+
+fn struct_type(name: &str, type_arguments: Vec<Type>) -> Type {
+    Type::Struct {
+        address: AccountAddress::TWO,
+        module: ident("M"),
+        name: ident(name),
+        type_arguments,
+    }
+}
+
+#[test]
+fn struct_abis_of_generic_struct() {
+    let abis = module_struct_abis(&mk_module());
+    let names: Vec<_> = abis.iter().map(|abi| abi.name.as_str()).collect();
+    assert_eq!(names, vec!["Coin", "Pair", "Wrapper"]);
+
+    assert_eq!(
+        abis[1],
+        StructAbi {
+            name: ident("Pair"),
+            abilities: AbilitySet::EMPTY | Ability::Copy | Ability::Drop,
+            type_param_count: 1,
+            fields: vec![
+                (ident("first"), Type::TypeParameter(0)),
+                (
+                    ident("items"),
+                    Type::Vector(Box::new(Type::TypeParameter(0)))
+                ),
+                (ident("coin"), struct_type("Coin", vec![])),
+            ],
+        }
+    );
+    assert_eq!(
+        abis[2].fields,
+        vec![
+            (
+                ident("pair"),
+                struct_type("Pair", vec![Type::TypeParameter(0)])
+            ),
+            (ident("owner"), Type::Address),
+        ]
+    );
+
+    // Instantiating the field types yields the types of a concrete `Pair<u8>`.
+    let field_tags: Vec<_> = abis[1]
+        .fields
+        .iter()
+        .map(|(_, ty)| ty.subst(&[Type::U8]).into_type_tag().unwrap())
+        .collect();
+    let coin = StructTag {
+        address: AccountAddress::TWO,
+        module: ident("M"),
+        name: ident("Coin"),
+        type_params: vec![],
+    };
+    assert_eq!(
+        field_tags,
+        vec![
+            TypeTag::U8,
+            TypeTag::Vector(Box::new(TypeTag::U8)),
+            TypeTag::Struct(Box::new(coin)),
+        ]
+    );
+}
+
+#[test]
+fn struct_abi_serde_round_trip() {
+    let abis = module_struct_abis(&mk_module());
+    let json = serde_json::to_string(&abis).unwrap();
+    assert_eq!(serde_json::from_str::<Vec<StructAbi>>(&json).unwrap(), abis);
+}
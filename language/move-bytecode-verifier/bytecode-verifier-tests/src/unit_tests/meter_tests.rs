@@ -0,0 +1,52 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_bytecode_verifier::{
+    meter::{BoundMeter, Meter, Scope},
+    VerifierConfig,
+};
+use move_core_types::vm_status::StatusCode;
+
+// Meters two functions of `units` each, and transfers their units to the module scope.
+fn meter_module(config: &VerifierConfig, units: u128) -> Result<(), StatusCode> {
+    let mut meter = BoundMeter::new(config);
+    meter.enter_scope("M", Scope::Module);
+    for name in ["f", "g"] {
+        meter.enter_scope(name, Scope::Function);
+        meter
+            .add(Scope::Function, units)
+            .map_err(|e| e.major_status())?;
+        meter
+            .transfer(Scope::Function, Scope::Module, 1.0)
+            .map_err(|e| e.major_status())?;
+    }
+    Ok(())
+}
+
+#[test]
+fn module_bounded_by_max_per_mod_meter_units() {
+    let config = VerifierConfig {
+        max_per_fun_meter_units: Some(100),
+        max_per_mod_meter_units: Some(150),
+        ..VerifierConfig::default()
+    };
+    // Each function fits into its own budget, but together they exceed the module budget.
+    assert_eq!(meter_module(&config, 70), Ok(()));
+    assert_eq!(
+        meter_module(&config, 80),
+        Err(StatusCode::CONSTRAINT_NOT_SATISFIED)
+    );
+}
+
+#[test]
+fn function_bounded_by_max_per_fun_meter_units() {
+    let config = VerifierConfig {
+        max_per_fun_meter_units: Some(100),
+        max_per_mod_meter_units: Some(1000),
+        ..VerifierConfig::default()
+    };
+    assert_eq!(
+        meter_module(&config, 101),
+        Err(StatusCode::CONSTRAINT_NOT_SATISFIED)
+    );
+}
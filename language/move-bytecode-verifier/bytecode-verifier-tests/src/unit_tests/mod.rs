@@ -19,6 +19,7 @@ pub mod limit_tests;
 pub mod locals;
 pub mod loop_summary_tests;
 pub mod many_back_edges;
+pub mod meter_tests;
 pub mod multi_pass_tests;
 pub mod negative_stack_size_tests;
 pub mod reference_safety_tests;
@@ -0,0 +1,100 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A resolver that caches reads from an underlying resolver for the duration of a block.
+
+use crate::{
+    account_address::AccountAddress,
+    effects::ChangeSet,
+    language_storage::{ModuleId, ResourceKey, StructTag},
+    resolver::{ModuleResolver, ResourceResolver},
+};
+use std::{cell::RefCell, collections::BTreeMap};
+
+/// Wraps a resolver and caches every successful read, including reads of missing data, so that
+/// transactions in the same block touching the same state only hit the base resolver once.
+///
+/// Changes committed during the block must be applied with `apply` so that the cache stays
+/// consistent with the state seen by the next transaction, and the cache must be cleared with
+/// `reset` at every block boundary. Errors from the base resolver are never cached.
+pub struct BlockCacheResolver<R> {
+    base: R,
+    modules: RefCell<BTreeMap<ModuleId, Option<Vec<u8>>>>,
+    resources: RefCell<BTreeMap<(AccountAddress, StructTag), Option<Vec<u8>>>>,
+}
+
+impl<R> BlockCacheResolver<R> {
+    pub fn new(base: R) -> Self {
+        Self {
+            base,
+            modules: RefCell::new(BTreeMap::new()),
+            resources: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn base(&self) -> &R {
+        &self.base
+    }
+
+    /// Updates the cache with the writes of a transaction executed within the current block.
+    pub fn apply(&mut self, changes: &ChangeSet) {
+        let modules = self.modules.get_mut();
+        for (addr, name, op) in changes.modules() {
+            let id = ModuleId::new(addr, name.clone());
+            modules.insert(id, op.ok().map(|blob| blob.to_vec()));
+        }
+        let resources = self.resources.get_mut();
+        for (addr, tag, op) in changes.resources() {
+            resources.insert((addr, tag.clone()), op.ok().map(|blob| blob.to_vec()));
+        }
+    }
+
+    /// Drops all cached entries. Must be called at block boundaries.
+    pub fn reset(&mut self) {
+        self.modules.get_mut().clear();
+        self.resources.get_mut().clear();
+    }
+}
+
+impl<R: ModuleResolver> ModuleResolver for BlockCacheResolver<R> {
+    type Error = R::Error;
+
+    fn get_module(&self, id: &ModuleId) -> Result<Option<Vec<u8>>, Self::Error> {
+        if let Some(blob) = self.modules.borrow().get(id) {
+            return Ok(blob.clone());
+        }
+        let blob = self.base.get_module(id)?;
+        self.modules.borrow_mut().insert(id.clone(), blob.clone());
+        Ok(blob)
+    }
+}
+
+impl<R: ResourceResolver> ResourceResolver for BlockCacheResolver<R> {
+    type Error = R::Error;
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        typ: &StructTag,
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        let key = (*address, typ.clone());
+        if let Some(blob) = self.resources.borrow().get(&key) {
+            return Ok(blob.clone());
+        }
+        let blob = self.base.get_resource(address, typ)?;
+        self.resources.borrow_mut().insert(key, blob.clone());
+        Ok(blob)
+    }
+
+    fn prefetch(&self, keys: &[ResourceKey]) {
+        let resources = self.resources.borrow();
+        let missing: Vec<_> = keys
+            .iter()
+            .filter(|key| !resources.contains_key(&(key.address, key.type_.clone())))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            self.base.prefetch(&missing);
+        }
+    }
+}
@@ -8,10 +8,14 @@ use crate::{
     language_storage::{ModuleId, StructTag, TypeTag},
 };
 use anyhow::{bail, Result};
-use std::collections::btree_map::{self, BTreeMap};
+use serde::{Deserialize, Serialize};
+use std::collections::{
+    btree_map::{self, BTreeMap},
+    BTreeSet,
+};
 
 /// A storage operation.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
 pub enum Op<T> {
     /// Inserts some new data into an empty slot.
     New(T),
@@ -56,7 +60,7 @@ impl<T> Op<T> {
 }
 
 /// A collection of resource and module operations on a Move account.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
 pub struct AccountChangeSet {
     modules: BTreeMap<Identifier, Op<Vec<u8>>>,
     resources: BTreeMap<StructTag, Op<Vec<u8>>>,
@@ -187,15 +191,104 @@ impl AccountChangeSet {
     }
 }
 
+/// The first write on which two change sets disagree, as reported by
+/// `ChangeSet::first_divergence`. A `None` op means the change set has no write at that location.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ChangeSetDivergence {
+    Module {
+        module_id: ModuleId,
+        actual: Option<Op<Vec<u8>>>,
+        expected: Option<Op<Vec<u8>>>,
+    },
+    Resource {
+        address: AccountAddress,
+        struct_tag: StructTag,
+        actual: Option<Op<Vec<u8>>>,
+        expected: Option<Op<Vec<u8>>>,
+    },
+}
+
+// Returns the smallest key on which `actual` and `expected` disagree, together with both values.
+fn first_divergent_entry<K: Ord + Clone, V: PartialEq + Clone>(
+    actual: &BTreeMap<K, V>,
+    expected: &BTreeMap<K, V>,
+) -> Option<(K, Option<V>, Option<V>)> {
+    let keys: BTreeSet<&K> = actual.keys().chain(expected.keys()).collect();
+    keys.into_iter().find_map(|key| {
+        let (actual, expected) = (actual.get(key), expected.get(key));
+        (actual != expected).then(|| (key.clone(), actual.cloned(), expected.cloned()))
+    })
+}
+
+/// The change in storage usage caused by a change set, relative to the state it applies to.
+/// Sizes are the lengths of the serialized modules and resources.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct StorageDelta {
+    /// The total size of the data written by `New` and `Modify` ops.
+    pub bytes_added: u64,
+    /// The total size of the data overwritten by `Modify` ops or removed by `Delete` ops.
+    pub bytes_removed: u64,
+    /// The number of `New` ops.
+    pub slots_created: u64,
+    /// The number of `Delete` ops.
+    pub slots_deleted: u64,
+}
+
+impl StorageDelta {
+    /// Accounts for `op` on a slot which holds `prior_size` bytes before the change set applies.
+    pub fn record<T: AsRef<[u8]>>(&mut self, op: &Op<T>, prior_size: usize) {
+        match op {
+            Op::New(data) => {
+                self.bytes_added += data.as_ref().len() as u64;
+                self.slots_created += 1;
+            }
+            Op::Modify(data) => {
+                self.bytes_added += data.as_ref().len() as u64;
+                self.bytes_removed += prior_size as u64;
+            }
+            Op::Delete => {
+                self.bytes_removed += prior_size as u64;
+                self.slots_deleted += 1;
+            }
+        }
+    }
+}
+
 // TODO: ChangeSet does not have a canonical representation so the derived Ord is not sound.
 
 /// A collection of changes to a Move state. Each AccountChangeSet in the domain of `accounts`
 /// is guaranteed to be nonempty
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedChangeSet")]
 pub struct ChangeSet {
     accounts: BTreeMap<AccountAddress, AccountChangeSet>,
 }
 
+/// The serialized form of a `ChangeSet`, which is only accepted if all account change sets are
+/// nonempty.
+#[derive(Deserialize)]
+#[serde(rename = "ChangeSet")]
+struct UncheckedChangeSet {
+    accounts: BTreeMap<AccountAddress, AccountChangeSet>,
+}
+
+impl TryFrom<UncheckedChangeSet> for ChangeSet {
+    type Error = anyhow::Error;
+
+    fn try_from(unchecked: UncheckedChangeSet) -> Result<Self> {
+        if let Some(addr) = unchecked
+            .accounts
+            .iter()
+            .find_map(|(addr, account)| account.is_empty().then_some(addr))
+        {
+            bail!("Account change set for {} is empty", addr)
+        }
+        Ok(Self {
+            accounts: unchecked.accounts,
+        })
+    }
+}
+
 impl ChangeSet {
     pub fn new() -> Self {
         Self {
@@ -229,6 +322,18 @@ impl ChangeSet {
         self.accounts
     }
 
+    /// Returns a change set with only the module and resource writes under `addr`.
+    pub fn filter_by_address(&self, addr: &AccountAddress) -> ChangeSet {
+        Self {
+            accounts: self
+                .accounts
+                .get_key_value(addr)
+                .map(|(addr, account)| (*addr, account.clone()))
+                .into_iter()
+                .collect(),
+        }
+    }
+
     fn get_or_insert_account_changeset(&mut self, addr: AccountAddress) -> &mut AccountChangeSet {
         match self.accounts.entry(addr) {
             btree_map::Entry::Occupied(entry) => entry.into_mut(),
@@ -293,6 +398,45 @@ impl ChangeSet {
                 .map(move |(struct_tag, op)| (addr, struct_tag, op.as_ref().map(|v| v.as_ref())))
         })
     }
+
+    /// Returns the first write on which `self` differs from `expected`, or `None` if both change
+    /// sets contain the same writes. Module writes are compared before resource writes, each in
+    /// address order.
+    pub fn first_divergence(&self, expected: &ChangeSet) -> Option<ChangeSetDivergence> {
+        let modules = |changes: &ChangeSet| -> BTreeMap<ModuleId, Op<Vec<u8>>> {
+            changes
+                .modules()
+                .map(|(addr, name, op)| (ModuleId::new(addr, name.clone()), op.map(|v| v.to_vec())))
+                .collect()
+        };
+        if let Some((module_id, actual, expected)) =
+            first_divergent_entry(&modules(self), &modules(expected))
+        {
+            return Some(ChangeSetDivergence::Module {
+                module_id,
+                actual,
+                expected,
+            });
+        }
+
+        let resources =
+            |changes: &ChangeSet| -> BTreeMap<(AccountAddress, StructTag), Op<Vec<u8>>> {
+                changes
+                    .resources()
+                    .map(|(addr, struct_tag, op)| {
+                        ((addr, struct_tag.clone()), op.map(|v| v.to_vec()))
+                    })
+                    .collect()
+            };
+        first_divergent_entry(&resources(self), &resources(expected)).map(
+            |((address, struct_tag), actual, expected)| ChangeSetDivergence::Resource {
+                address,
+                struct_tag,
+                actual,
+                expected,
+            },
+        )
+    }
 }
 
 pub type Event = (Vec<u8>, u64, TypeTag, Vec<u8>);
@@ -6,6 +6,7 @@
 
 pub mod abi;
 pub mod account_address;
+pub mod block_cache;
 pub mod effects;
 pub mod errmap;
 pub mod gas_algebra;
@@ -1,8 +1,16 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+
+/// The maximum length of a metadata key. Matches the limit enforced by the binary format.
+pub const METADATA_KEY_SIZE_MAX: usize = 1023;
+/// The maximum length of a metadata value. Matches the limit enforced by the binary format.
+pub const METADATA_VALUE_SIZE_MAX: usize = 65535;
+
 /// Representation of metadata,
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Metadata {
     /// The key identifying the type of metadata.
@@ -10,3 +18,34 @@ pub struct Metadata {
     /// The value of the metadata.
     pub value: Vec<u8>,
 }
+
+impl Metadata {
+    /// Serializes the metadata with BCS, failing if the key or value exceeds its size limit.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.check_size()?;
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    /// Deserializes BCS-encoded metadata, failing if the key or value exceeds its size limit.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let metadata: Self = bcs::from_bytes(bytes)?;
+        metadata.check_size()?;
+        Ok(metadata)
+    }
+
+    fn check_size(&self) -> Result<()> {
+        ensure!(
+            self.key.len() <= METADATA_KEY_SIZE_MAX,
+            "metadata key too large: {} > {}",
+            self.key.len(),
+            METADATA_KEY_SIZE_MAX
+        );
+        ensure!(
+            self.value.len() <= METADATA_VALUE_SIZE_MAX,
+            "metadata value too large: {} > {}",
+            self.value.len(),
+            METADATA_VALUE_SIZE_MAX
+        );
+        Ok(())
+    }
+}
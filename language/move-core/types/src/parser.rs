@@ -5,7 +5,7 @@
 use crate::{
     account_address::AccountAddress,
     identifier::{self, Identifier},
-    language_storage::{StructTag, TypeTag},
+    language_storage::{ModuleId, StructTag, TypeTag},
     transaction_argument::TransactionArgument,
 };
 use anyhow::{bail, format_err, Result};
@@ -47,6 +47,27 @@ impl Token {
     fn is_whitespace(&self) -> bool {
         matches!(self, Self::Whitespace(_))
     }
+
+    // Returns the name spelled by the token, as keywords such as `vector` or `signer` are also
+    // valid module names.
+    fn into_name(self) -> Result<String, Self> {
+        Ok(match self {
+            Self::Name(s) => s,
+            Self::U8Type => "u8".to_owned(),
+            Self::U16Type => "u16".to_owned(),
+            Self::U32Type => "u32".to_owned(),
+            Self::U64Type => "u64".to_owned(),
+            Self::U128Type => "u128".to_owned(),
+            Self::U256Type => "u256".to_owned(),
+            Self::BoolType => "bool".to_owned(),
+            Self::AddressType => "address".to_owned(),
+            Self::VectorType => "vector".to_owned(),
+            Self::SignerType => "signer".to_owned(),
+            Self::True => "true".to_owned(),
+            Self::False => "false".to_owned(),
+            tok => return Err(tok),
+        })
+    }
 }
 
 fn name_token(s: String) -> Token {
@@ -320,6 +341,22 @@ impl<I: Iterator<Item = Token>> Parser<I> {
         })
     }
 
+    fn parse_module_id(&mut self) -> Result<ModuleId> {
+        Ok(match self.next()? {
+            Token::Address(addr) => {
+                self.consume(Token::ColonColon)?;
+                match self.next()?.into_name() {
+                    Ok(module) => ModuleId::new(
+                        AccountAddress::from_hex_literal(&addr)?,
+                        Identifier::new(module)?,
+                    ),
+                    Err(t) => bail!("expected name, got {:?}", t),
+                }
+            }
+            tok => bail!("unexpected token {:?}, expected module id", tok),
+        })
+    }
+
     fn parse_transaction_argument(&mut self) -> Result<TransactionArgument> {
         Ok(match self.next()? {
             Token::U8(s) => TransactionArgument::U8(s.replace('_', "").parse()?),
@@ -394,13 +431,22 @@ pub fn parse_struct_tag(s: &str) -> Result<StructTag> {
     }
 }
 
+/// Parses a module id of the form `address::module`. The address must be a numerical address;
+/// named addresses need to be resolved by the caller.
+pub fn parse_module_id(s: &str) -> Result<ModuleId> {
+    parse(s, |parser| parser.parse_module_id())
+        .map_err(|e| format_err!("invalid module id: {}, {}", s, e))
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
     use crate::{
         account_address::AccountAddress,
-        parser::{parse_struct_tag, parse_transaction_argument, parse_type_tag},
+        identifier::Identifier,
+        language_storage::ModuleId,
+        parser::{parse_module_id, parse_struct_tag, parse_transaction_argument, parse_type_tag},
         transaction_argument::TransactionArgument,
         u256,
     };
@@ -614,6 +660,25 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_parse_module_id() {
+        assert_eq!(
+            parse_module_id("0x1::vector").unwrap(),
+            ModuleId::new(AccountAddress::ONE, Identifier::new("vector").unwrap())
+        );
+        assert_eq!(
+            parse_module_id(" 0x2::Diem_Type ").unwrap(),
+            ModuleId::new(AccountAddress::TWO, Identifier::new("Diem_Type").unwrap())
+        );
+        // A fully qualified function or struct is not a module id.
+        assert!(parse_module_id("0x1::vector::empty").is_err());
+        // Named addresses are not known at this level.
+        assert!(parse_module_id("std::vector").is_err());
+        assert!(parse_module_id("0x1").is_err());
+        assert!(parse_module_id("0x1::").is_err());
+        assert!(parse_module_id("0x1::<u8>").is_err());
+    }
 }
 
 #[test]
@@ -635,4 +700,4 @@ fn tests_parse_type_tag() {
         let actual = parse_type_tag(t.to_string().as_str()).unwrap();
         assert_eq!(&actual, t);
     }
-}
\ No newline at end of file
+}
@@ -4,7 +4,7 @@
 
 use crate::{
     account_address::AccountAddress,
-    language_storage::{ModuleId, StructTag},
+    language_storage::{ModuleId, ResourceKey, StructTag},
 };
 use std::fmt::Debug;
 
@@ -42,6 +42,12 @@ pub trait ResourceResolver {
         address: &AccountAddress,
         typ: &StructTag,
     ) -> Result<Option<Vec<u8>>, Self::Error>;
+
+    /// Hints that the resources at `keys` are about to be read, e.g. because a transaction
+    /// declared them in its access set. Backends with a cache may start loading them in the
+    /// background. The hint is best-effort: it returns immediately, reports no errors, and the
+    /// resources are still read with `get_resource`. Does nothing by default.
+    fn prefetch(&self, _keys: &[ResourceKey]) {}
 }
 
 /// A persistent storage implementation that can resolve both resources and modules
@@ -67,6 +73,10 @@ impl<T: ResourceResolver + ?Sized> ResourceResolver for &T {
     ) -> Result<Option<Vec<u8>>, Self::Error> {
         (**self).get_resource(address, tag)
     }
+
+    fn prefetch(&self, keys: &[ResourceKey]) {
+        (**self).prefetch(keys)
+    }
 }
 
 impl<T: ModuleResolver + ?Sized> ModuleResolver for &T {
@@ -0,0 +1,135 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    account_address::AccountAddress,
+    block_cache::BlockCacheResolver,
+    effects::{ChangeSet, Op},
+    identifier::Identifier,
+    language_storage::{ModuleId, ResourceKey, StructTag},
+    resolver::{ModuleResolver, ResourceResolver},
+};
+use std::{
+    cell::{Cell, RefCell},
+    convert::Infallible,
+};
+
+/// A resolver which serves fixed data and counts how many reads reach it.
+#[derive(Default)]
+struct CountingResolver {
+    reads: Cell<usize>,
+    prefetched: RefCell<Vec<Vec<ResourceKey>>>,
+}
+
+impl ModuleResolver for CountingResolver {
+    type Error = Infallible;
+
+    fn get_module(&self, _id: &ModuleId) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.reads.set(self.reads.get() + 1);
+        Ok(Some(vec![1]))
+    }
+}
+
+impl ResourceResolver for CountingResolver {
+    type Error = Infallible;
+
+    fn get_resource(
+        &self,
+        _address: &AccountAddress,
+        _typ: &StructTag,
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.reads.set(self.reads.get() + 1);
+        Ok(Some(vec![2]))
+    }
+
+    fn prefetch(&self, keys: &[ResourceKey]) {
+        self.prefetched.borrow_mut().push(keys.to_vec());
+    }
+}
+
+fn struct_tag() -> StructTag {
+    StructTag {
+        address: AccountAddress::ONE,
+        module: Identifier::new("M").unwrap(),
+        name: Identifier::new("R").unwrap(),
+        type_params: vec![],
+    }
+}
+
+#[test]
+fn test_second_read_hits_cache() {
+    let resolver = BlockCacheResolver::new(CountingResolver::default());
+    let id = ModuleId::new(AccountAddress::ONE, Identifier::new("M").unwrap());
+    let tag = struct_tag();
+
+    assert_eq!(resolver.get_module(&id).unwrap(), Some(vec![1]));
+    assert_eq!(resolver.get_module(&id).unwrap(), Some(vec![1]));
+    assert_eq!(resolver.base().reads.get(), 1);
+
+    let address = AccountAddress::TWO;
+    assert_eq!(
+        resolver.get_resource(&address, &tag).unwrap(),
+        Some(vec![2])
+    );
+    assert_eq!(
+        resolver.get_resource(&address, &tag).unwrap(),
+        Some(vec![2])
+    );
+    assert_eq!(resolver.base().reads.get(), 2);
+}
+
+#[test]
+fn test_writes_update_cache() {
+    let mut resolver = BlockCacheResolver::new(CountingResolver::default());
+    let tag = struct_tag();
+    let address = AccountAddress::TWO;
+    assert_eq!(
+        resolver.get_resource(&address, &tag).unwrap(),
+        Some(vec![2])
+    );
+
+    let mut changes = ChangeSet::new();
+    changes
+        .add_resource_op(address, tag.clone(), Op::Modify(vec![3]))
+        .unwrap();
+    resolver.apply(&changes);
+    assert_eq!(
+        resolver.get_resource(&address, &tag).unwrap(),
+        Some(vec![3])
+    );
+
+    let mut changes = ChangeSet::new();
+    changes
+        .add_resource_op(address, tag.clone(), Op::Delete)
+        .unwrap();
+    resolver.apply(&changes);
+    assert_eq!(resolver.get_resource(&address, &tag).unwrap(), None);
+    assert_eq!(resolver.base().reads.get(), 1);
+}
+
+#[test]
+fn test_reset_clears_cache() {
+    let mut resolver = BlockCacheResolver::new(CountingResolver::default());
+    let id = ModuleId::new(AccountAddress::ONE, Identifier::new("M").unwrap());
+
+    resolver.get_module(&id).unwrap();
+    resolver.reset();
+    resolver.get_module(&id).unwrap();
+    assert_eq!(resolver.base().reads.get(), 2);
+}
+
+#[test]
+fn test_prefetch_skips_cached_resources() {
+    let resolver = BlockCacheResolver::new(CountingResolver::default());
+    let tag = struct_tag();
+    let cached = ResourceKey::new(AccountAddress::ONE, tag.clone());
+    let uncached = ResourceKey::new(AccountAddress::TWO, tag.clone());
+    resolver.get_resource(&cached.address, &tag).unwrap();
+
+    resolver.prefetch(&[cached.clone(), uncached.clone()]);
+    assert_eq!(*resolver.base().prefetched.borrow(), vec![vec![uncached]]);
+
+    // Nothing reaches the base resolver if all resources are cached.
+    resolver.prefetch(&[cached]);
+    assert_eq!(resolver.base().prefetched.borrow().len(), 1);
+}
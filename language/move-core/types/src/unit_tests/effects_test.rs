@@ -0,0 +1,113 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    account_address::AccountAddress,
+    effects::{AccountChangeSet, ChangeSet, ChangeSetDivergence, Op},
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag},
+};
+
+fn struct_tag(addr: AccountAddress, name: &str) -> StructTag {
+    StructTag {
+        address: addr,
+        module: Identifier::new("M").unwrap(),
+        name: Identifier::new(name).unwrap(),
+        type_params: vec![],
+    }
+}
+
+#[test]
+fn filter_by_address_keeps_only_writes_under_address() {
+    let alice = AccountAddress::from_hex_literal("0xa").unwrap();
+    let bob = AccountAddress::from_hex_literal("0xb").unwrap();
+    let module_id = |addr| ModuleId::new(addr, Identifier::new("M").unwrap());
+
+    let mut changes = ChangeSet::new();
+    changes
+        .add_module_op(module_id(alice), Op::New(vec![1]))
+        .unwrap();
+    changes
+        .add_resource_op(alice, struct_tag(alice, "R"), Op::Modify(vec![2]))
+        .unwrap();
+    changes
+        .add_module_op(module_id(bob), Op::New(vec![3]))
+        .unwrap();
+    changes
+        .add_resource_op(bob, struct_tag(alice, "R"), Op::Delete)
+        .unwrap();
+
+    let filtered = changes.filter_by_address(&alice);
+    assert_eq!(filtered.accounts().keys().collect::<Vec<_>>(), vec![&alice]);
+    assert_eq!(filtered.accounts()[&alice], changes.accounts()[&alice]);
+    assert_eq!(filtered.modules().count(), 1);
+    assert_eq!(filtered.resources().count(), 1);
+
+    let unknown = AccountAddress::from_hex_literal("0xc").unwrap();
+    assert_eq!(changes.filter_by_address(&unknown), ChangeSet::new());
+}
+
+#[test]
+fn first_divergence_reports_first_mismatching_write() {
+    let alice = AccountAddress::from_hex_literal("0xa").unwrap();
+    let bob = AccountAddress::from_hex_literal("0xb").unwrap();
+    let module_id = ModuleId::new(alice, Identifier::new("M").unwrap());
+
+    let mut changes = ChangeSet::new();
+    changes
+        .add_module_op(module_id.clone(), Op::New(vec![1]))
+        .unwrap();
+    changes
+        .add_resource_op(alice, struct_tag(alice, "R"), Op::Modify(vec![2]))
+        .unwrap();
+    changes
+        .add_resource_op(bob, struct_tag(alice, "R"), Op::Delete)
+        .unwrap();
+    assert_eq!(changes.first_divergence(&changes.clone()), None);
+
+    // A different payload under bob is only reported once all module writes match.
+    let mut expected = changes.filter_by_address(&alice);
+    expected
+        .add_resource_op(bob, struct_tag(alice, "R"), Op::Modify(vec![3]))
+        .unwrap();
+    assert_eq!(
+        changes.first_divergence(&expected),
+        Some(ChangeSetDivergence::Resource {
+            address: bob,
+            struct_tag: struct_tag(alice, "R"),
+            actual: Some(Op::Delete),
+            expected: Some(Op::Modify(vec![3])),
+        })
+    );
+
+    // A write missing from the expected change set.
+    let expected = ChangeSet::new();
+    assert_eq!(
+        changes.first_divergence(&expected),
+        Some(ChangeSetDivergence::Module {
+            module_id,
+            actual: Some(Op::New(vec![1])),
+            expected: None,
+        })
+    );
+}
+
+#[test]
+fn deserialize_rejects_empty_account_changeset() {
+    let alice = AccountAddress::from_hex_literal("0xa").unwrap();
+
+    let mut changes = ChangeSet::new();
+    changes
+        .add_resource_op(alice, struct_tag(alice, "R"), Op::Delete)
+        .unwrap();
+    let bytes = bcs::to_bytes(&changes).unwrap();
+    assert_eq!(bcs::from_bytes::<ChangeSet>(&bytes).unwrap(), changes);
+
+    let mut empty = ChangeSet::new();
+    empty
+        .add_account_changeset(alice, AccountChangeSet::new())
+        .unwrap();
+    let bytes = bcs::to_bytes(&empty).unwrap();
+    let err = bcs::from_bytes::<ChangeSet>(&bytes).unwrap_err();
+    assert!(err.to_string().contains("is empty"), "{}", err);
+}
@@ -0,0 +1,46 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::metadata::{Metadata, METADATA_KEY_SIZE_MAX, METADATA_VALUE_SIZE_MAX};
+
+fn round_trip(metadata: Metadata) {
+    let bytes = metadata.to_bytes().unwrap();
+    assert_eq!(Metadata::from_bytes(&bytes).unwrap(), metadata);
+}
+
+#[test]
+fn test_metadata_round_trip() {
+    round_trip(Metadata {
+        key: b"key".to_vec(),
+        value: b"value".to_vec(),
+    });
+    round_trip(Metadata {
+        key: b"key".to_vec(),
+        value: vec![],
+    });
+    round_trip(Metadata {
+        key: vec![1; METADATA_KEY_SIZE_MAX],
+        value: vec![2; METADATA_VALUE_SIZE_MAX],
+    });
+}
+
+#[test]
+fn test_metadata_encoding_is_stable() {
+    let metadata = Metadata {
+        key: vec![1, 2],
+        value: vec![3],
+    };
+    // Length-prefixed key followed by length-prefixed value.
+    assert_eq!(metadata.to_bytes().unwrap(), vec![2, 1, 2, 1, 3]);
+}
+
+#[test]
+fn test_metadata_size_limit() {
+    let too_large = Metadata {
+        key: b"key".to_vec(),
+        value: vec![0; METADATA_VALUE_SIZE_MAX + 1],
+    };
+    assert!(too_large.to_bytes().is_err());
+    let bytes = bcs::to_bytes(&too_large).unwrap();
+    assert!(Metadata::from_bytes(&bytes).is_err());
+}
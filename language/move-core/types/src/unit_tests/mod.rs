@@ -2,7 +2,10 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+mod block_cache_test;
+mod effects_test;
 mod identifier_test;
 mod language_storage_test;
+mod metadata_test;
 mod value_test;
 mod vm_status_test;
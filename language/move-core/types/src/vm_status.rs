@@ -817,6 +817,8 @@ pub mod sub_status {
     // Failure in Token native functions.
     pub const NFE_TOKEN_INVALID_TYPE_ARG_FAILURE: u64 = 0x200;
     pub const NFE_RLP_DECODE_FAILURE: u64 = 0x300;
+    // Failure in string native functions, e.g. an index out of bounds.
+    pub const NFE_STRING_INVALID_ARG_FAILURE: u64 = 0x400;
 }
 
 /// The `Arbitrary` impl only generates validation statuses since the full enum is too large.
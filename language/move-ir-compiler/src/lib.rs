@@ -52,6 +52,14 @@ impl<'a> Compiler<'a> {
         Ok(self.compile_mod(code)?.0)
     }
 
+    /// Compiles the module, also returning its source map.
+    pub fn into_compiled_module_and_source_map(
+        self,
+        code: &str,
+    ) -> Result<(CompiledModule, SourceMap)> {
+        self.compile_mod(code)
+    }
+
     /// Compiles the module into a serialized form.
     pub fn into_module_blob(self, code: &str) -> Result<Vec<u8>> {
         let compiled_module = self.compile_mod(code)?.0;
@@ -1404,6 +1404,22 @@ impl GlobalEnv {
         self.get_module(qid.module_id).into_function(qid.id)
     }
 
+    /// Returns the call graph between all functions in the environment.
+    pub fn call_graph(&self) -> CallGraph {
+        let mut graph = CallGraph::default();
+        for module_env in self.get_modules() {
+            for fun_env in module_env.get_functions() {
+                let fun = fun_env.get_qualified_id();
+                graph.callers.entry(fun).or_default();
+                for callee in fun_env.get_called_functions() {
+                    graph.callers.entry(callee).or_default().insert(fun);
+                }
+                graph.callees.insert(fun, fun_env.get_called_functions());
+            }
+        }
+        graph
+    }
+
     /// Returns an iterator for all modules in the environment.
     pub fn get_modules(&self) -> impl Iterator<Item = ModuleEnv<'_>> {
         self.module_data.iter().map(move |module_data| ModuleEnv {
@@ -1709,6 +1725,70 @@ impl Default for GlobalEnv {
     }
 }
 
+// =================================================================================================
+/// # Call Graph
+
+/// The call graph between the functions of a `GlobalEnv`, as computed by `GlobalEnv::call_graph`.
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    /// The functions called by each function.
+    callees: BTreeMap<QualifiedId<FunId>, BTreeSet<QualifiedId<FunId>>>,
+    /// The functions calling each function.
+    callers: BTreeMap<QualifiedId<FunId>, BTreeSet<QualifiedId<FunId>>>,
+}
+
+impl CallGraph {
+    /// Returns an iterator for all functions in the graph.
+    pub fn functions(&self) -> impl Iterator<Item = QualifiedId<FunId>> + '_ {
+        self.callees.keys().copied()
+    }
+
+    /// Returns the functions that `fun` calls.
+    pub fn callees(
+        &self,
+        fun: QualifiedId<FunId>,
+    ) -> impl Iterator<Item = QualifiedId<FunId>> + '_ {
+        self.callees.get(&fun).into_iter().flatten().copied()
+    }
+
+    /// Returns the functions that call `fun`.
+    pub fn callers(
+        &self,
+        fun: QualifiedId<FunId>,
+    ) -> impl Iterator<Item = QualifiedId<FunId>> + '_ {
+        self.callers.get(&fun).into_iter().flatten().copied()
+    }
+
+    /// Returns all functions ordered such that every function comes after the functions it calls,
+    /// or `None` if the graph contains recursion.
+    pub fn topological_order(&self) -> Option<Vec<QualifiedId<FunId>>> {
+        let mut pending_callees: BTreeMap<_, _> = self
+            .callees
+            .iter()
+            .map(|(fun, callees)| (*fun, callees.len()))
+            .collect();
+        let mut ready: VecDeque<_> = pending_callees
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(fun, _)| *fun)
+            .collect();
+        let mut order = vec![];
+        while let Some(fun) = ready.pop_front() {
+            order.push(fun);
+            for caller in self.callers(fun) {
+                let count = pending_callees
+                    .get_mut(&caller)
+                    .expect("caller in call graph");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push_back(caller);
+                }
+            }
+        }
+        (order.len() == self.callees.len()).then_some(order)
+    }
+}
+
 // =================================================================================================
 /// # Module Environment
 
@@ -2348,6 +2428,7 @@ impl<'env> ModuleEnv<'env> {
                 print_code: true,
                 print_basic_blocks: true,
                 print_locals: true,
+                print_source_lines: false,
             },
         );
         disas
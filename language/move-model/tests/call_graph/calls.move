@@ -0,0 +1,9 @@
+module 0x42::M {
+    public fun a(): u64 { b() + c() }
+    public fun b(): u64 { c() + 0x42::N::d() }
+    public fun c(): u64 { 1 }
+}
+
+module 0x42::N {
+    public fun d(): u64 { 2 }
+}
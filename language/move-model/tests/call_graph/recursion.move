@@ -0,0 +1,4 @@
+module 0x42::R {
+    public fun even(n: u64): bool { if (n == 0) true else odd(n - 1) }
+    public fun odd(n: u64): bool { if (n == 0) false else even(n - 1) }
+}
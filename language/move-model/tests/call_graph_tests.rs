@@ -0,0 +1,84 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_compiler::shared::PackagePaths;
+use move_core_types::{account_address::AccountAddress, identifier::Identifier, language_storage};
+use move_model::{
+    model::{FunId, GlobalEnv, QualifiedId},
+    run_model_builder,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+fn build_env(file: &str) -> GlobalEnv {
+    let path = format!("{}/tests/call_graph/{}", env!("CARGO_MANIFEST_DIR"), file);
+    let targets = vec![PackagePaths {
+        name: None,
+        paths: vec![path],
+        named_address_map: BTreeMap::<String, _>::new(),
+    }];
+    let env = run_model_builder(targets, vec![]).unwrap();
+    assert!(!env.has_errors());
+    env
+}
+
+fn fun_id(env: &GlobalEnv, module: &str, name: &str) -> QualifiedId<FunId> {
+    let module_id = language_storage::ModuleId::new(
+        AccountAddress::from_hex_literal("0x42").unwrap(),
+        Identifier::new(module).unwrap(),
+    );
+    env.find_function_by_language_storage_id_name(&module_id, &Identifier::new(name).unwrap())
+        .unwrap()
+        .get_qualified_id()
+}
+
+#[test]
+fn call_graph_callees_and_callers() {
+    let env = build_env("calls.move");
+    let [a, b, c, d] = [("M", "a"), ("M", "b"), ("M", "c"), ("N", "d")]
+        .map(|(module, name)| fun_id(&env, module, name));
+    let graph = env.call_graph();
+
+    assert_eq!(graph.functions().count(), 4);
+    assert_eq!(
+        graph.callees(a).collect::<BTreeSet<_>>(),
+        BTreeSet::from([b, c])
+    );
+    assert_eq!(
+        graph.callees(b).collect::<BTreeSet<_>>(),
+        BTreeSet::from([c, d])
+    );
+    assert_eq!(graph.callees(d).count(), 0);
+    assert_eq!(
+        graph.callers(c).collect::<BTreeSet<_>>(),
+        BTreeSet::from([a, b])
+    );
+    assert_eq!(
+        graph.callers(d).collect::<BTreeSet<_>>(),
+        BTreeSet::from([b])
+    );
+    assert_eq!(graph.callers(a).count(), 0);
+
+    let order = graph.topological_order().unwrap();
+    let position = |fun| order.iter().position(|f| *f == fun).unwrap();
+    assert_eq!(order.len(), 4);
+    assert!(position(c) < position(b) && position(d) < position(b));
+    assert!(position(b) < position(a));
+}
+
+#[test]
+fn call_graph_recursion_has_no_topological_order() {
+    let env = build_env("recursion.move");
+    let even = fun_id(&env, "R", "even");
+    let odd = fun_id(&env, "R", "odd");
+    let graph = env.call_graph();
+
+    assert_eq!(
+        graph.callees(even).collect::<BTreeSet<_>>(),
+        BTreeSet::from([odd])
+    );
+    assert_eq!(
+        graph.callers(even).collect::<BTreeSet<_>>(),
+        BTreeSet::from([odd])
+    );
+    assert_eq!(graph.topological_order(), None);
+}
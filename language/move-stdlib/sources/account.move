@@ -0,0 +1,18 @@
+/// Module which defines functions operating on account addresses.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::account {
+    use std::option::Option;
+
+    /// The session is not authorized to create a signer for the address.
+    const ECREATE_SIGNER_NOT_AUTHORIZED: u64 = 1;
+
+    /// Parses `bytes` as a hex string, with or without a `0x` prefix, into an address.
+    /// Returns `none` if `bytes` is not a valid hex address.
+    native public fun address_from_hex(bytes: &vector<u8>): Option<address>;
+
+    /// Creates a signer for `addr`, e.g. for a resource account derived by a framework.
+    /// Aborts with `ECREATE_SIGNER_NOT_AUTHORIZED` unless the adapter authorized the session to
+    /// create a signer for `addr`. Adapters only do so for sessions running trusted code.
+    native public fun create_signer(addr: address): signer;
+}
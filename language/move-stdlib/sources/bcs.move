@@ -3,9 +3,30 @@
 /// published on-chain. See https://github.com/diem/bcs#binary-canonical-serialization-bcs for more
 /// details on BCS.
 module std::bcs {
+    use std::option::Option;
+
     /// Return the binary representation of `v` in BCS (Binary Canonical Serialization) format
     native public fun to_bytes<MoveValue>(v: &MoveValue): vector<u8>;
 
+    /// Return the length of `to_bytes(v)`, without allocating the binary representation. Aborts
+    /// whenever `to_bytes(v)` does.
+    native public fun serialized_size<MoveValue>(v: &MoveValue): u64;
+
+    /// Return `bytes` if they are the canonical BCS encoding of a `MoveValue`, and `none` if they
+    /// are malformed or a non-canonical (malleable) encoding, such as a length with a non-minimal
+    /// ULEB128 encoding.
+    native public fun canonicalize<MoveValue>(bytes: &vector<u8>): Option<vector<u8>>;
+
+    /// Return the `MoveValue` whose BCS (Binary Canonical Serialization) encoding is `bytes`.
+    /// Aborts if `bytes` is not the encoding of a `MoveValue`, or has trailing bytes after it.
+    ///
+    /// This constructs values of structs which can otherwise only be packed by their defining
+    /// module. `MoveValue` is therefore restricted to types with `copy` and `drop`, which cannot
+    /// stand for an asset or a privilege, such as coins or capabilities. It also aborts if the result
+    /// breaks the invariants of `String`, `ascii::String`, `ascii::Char` or `Option`, such as a
+    /// `String` which is not valid UTF-8.
+    native public fun from_bytes<MoveValue: copy + drop>(bytes: vector<u8>): MoveValue;
+
     // ==============================
     // Module Specification
     spec module {} // switch to module documentation context
@@ -0,0 +1,23 @@
+/// Module which defines bloom filters, for space-efficient membership tests such as sets of seen
+/// nonces. A filter may report items which were never added, but never misses an added item.
+///
+/// A filter is a non-empty byte vector, e.g. created with `vector` of zeros. An item is mapped to
+/// `num_hashes` bits derived from its SHA3-256 digest `d`: with `h1` and `h2` the little-endian
+/// u64s in `d[0..8]` and `d[8..16]`, and `m` the number of bits of the filter, the i-th bit is
+/// `(h1 + i * h2) mod m` (wrapping u64 arithmetic), and bit `k` is bit `k % 8` of byte `k / 8`,
+/// counting from the least significant bit. Off-chain code must use the same scheme.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::bloom {
+    /// The filter has no bits.
+    const EEMPTY_FILTER: u64 = 1;
+    /// The number of hashes exceeds the number of bits of the filter.
+    const ETOO_MANY_HASHES: u64 = 2;
+
+    /// Returns whether `item` may have been added to `filter`. Aborts with `EEMPTY_FILTER` if
+    /// `filter` is empty, or with `ETOO_MANY_HASHES` if `num_hashes` exceeds its number of bits.
+    native public fun test(filter: &vector<u8>, num_hashes: u64, item: &vector<u8>): bool;
+
+    /// Adds `item` to `filter`. Aborts under the same conditions as `test`.
+    native public fun add(filter: &mut vector<u8>, num_hashes: u64, item: &vector<u8>);
+}
@@ -0,0 +1,14 @@
+/// Module which defines BLS12-381 signature verification.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::bls {
+    /// Returns true if `signature` is a valid signature over `message` under `public_key`, using
+    /// the proof-of-possession ciphersuite with 48 byte compressed public keys in G1 and 96 byte
+    /// compressed signatures in G2. Both may be aggregates; the caller is responsible for checking
+    /// the proofs of possession of aggregated keys. Malformed inputs return false.
+    native public fun bls12381_verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        message: &vector<u8>
+    ): bool;
+}
@@ -0,0 +1,29 @@
+/// Module which defines functions operating on byte vectors.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::bytes {
+    use std::option::Option;
+
+    /// The length of the byte vector is not a multiple of the stride, or the stride is zero.
+    const EINVALID_STRIDE: u64 = 1;
+
+    /// Splits `bytes` into `stride` vectors, where the `i`th vector contains every `stride`th
+    /// byte starting at index `i`.
+    /// Aborts with `EINVALID_STRIDE` if `stride` is zero, longer than `bytes` or does not divide
+    /// the length of `bytes`. In particular, an empty `bytes` always aborts.
+    native public fun deinterleave(bytes: &vector<u8>, stride: u64): vector<vector<u8>>;
+
+    /// Returns true if `haystack` starts with `prefix`. Every vector starts with the empty prefix.
+    native public fun starts_with(haystack: &vector<u8>, prefix: &vector<u8>): bool;
+
+    /// Returns true if `haystack` ends with `suffix`. Every vector ends with the empty suffix.
+    native public fun ends_with(haystack: &vector<u8>, suffix: &vector<u8>): bool;
+
+    /// Decodes the ULEB128 encoded integer starting at `offset` in `bytes`. Returns the value and
+    /// the number of bytes it occupies, or `(none, 0)` if the encoding is truncated, does not fit
+    /// in a u64, or is overlong (not the shortest encoding of the value).
+    native public fun read_uleb128(bytes: &vector<u8>, offset: u64): (Option<u64>, u64);
+
+    /// Returns the bitwise complement of `bytes`, i.e. every byte with all of its bits flipped.
+    native public fun not_bytes(bytes: &vector<u8>): vector<u8>;
+}
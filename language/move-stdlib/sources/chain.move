@@ -0,0 +1,12 @@
+/// Module which provides access to properties of the current transaction.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::chain {
+    /// The session was not given a gas unit price.
+    const EGAS_UNIT_PRICE_NOT_AVAILABLE: u64 = 1;
+
+    /// Returns the gas unit price of the current transaction, i.e. the price per unit of gas the
+    /// sender agreed to pay. Aborts with `EGAS_UNIT_PRICE_NOT_AVAILABLE` if the adapter did not
+    /// provide one.
+    native public fun gas_unit_price(): u64;
+}
@@ -0,0 +1,38 @@
+/// Module which defines decoders for binary-to-text encodings used by other chains, and
+/// run-length and delta encodings for compact storage of sparse data and time series.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::encoding {
+    use std::option::Option;
+
+    /// Decodes a base58check string, such as a Bitcoin address. The last 4 bytes of the decoded
+    /// data must be the first 4 bytes of the double SHA-256 of the payload before them.
+    /// Returns the payload, including any version prefix, or none if `bytes` is not valid base58,
+    /// the checksum does not match or `bytes` is longer than 128 characters.
+    native public fun base58check_decode(bytes: &vector<u8>): Option<vector<u8>>;
+
+    /// Returns the run-length encoding of `bytes`: a sequence of (count, value) byte pairs, each
+    /// standing for `count` repetitions of `value`. Runs longer than 255 bytes are split into
+    /// several pairs.
+    native public fun rle_encode(bytes: &vector<u8>): vector<u8>;
+
+    /// Decodes a sequence of (count, value) byte pairs produced by `rle_encode`. Returns none if
+    /// `bytes` has an odd length or a count is zero.
+    native public fun rle_decode(bytes: &vector<u8>): Option<vector<u8>>;
+
+    /// Returns the delta encoding of `v`: the first value as ULEB128, followed by the difference
+    /// of each value to the one before it as zig-zag encoded ULEB128. Slowly changing sequences,
+    /// such as timestamps or prices, take one or two bytes per value.
+    public fun delta_encode_u64(v: &vector<u64>): vector<u8> {
+        native_delta_encode_u64(v)
+    }
+
+    /// Decodes values produced by `delta_encode_u64`. Returns none if `bytes` ends in the middle
+    /// of a value, or holds a value which overflows a u64 or is not minimally encoded.
+    public fun delta_decode_u64(bytes: &vector<u8>): Option<vector<u64>> {
+        native_delta_decode_u64(bytes)
+    }
+
+    native fun native_delta_encode_u64(v: &vector<u64>): vector<u8>;
+    native fun native_delta_decode_u64(bytes: &vector<u8>): Option<vector<u64>>;
+}
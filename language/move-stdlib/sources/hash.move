@@ -3,6 +3,43 @@
 /// The functions in this module are natively declared both in the Move runtime
 /// as in the Move prover's prelude.
 module std::hash {
+    /// The SipHash key is not 16 bytes long.
+    const EINVALID_SIPHASH_KEY: u64 = 1;
+    /// The requested HKDF output is longer than 255 * 32 bytes.
+    const EHKDF_LENGTH_TOO_LARGE: u64 = 2;
+    /// The type argument of `typed_struct_hash` is not a struct.
+    const ENOT_A_STRUCT: u64 = 3;
+
     native public fun sha2_256(data: vector<u8>): vector<u8>;
     native public fun sha3_256(data: vector<u8>): vector<u8>;
+    /// Returns the Keccak-256 of `data` as used by Ethereum, which is not the same as `sha3_256`.
+    native public fun keccak_256(data: vector<u8>): vector<u8>;
+    /// Returns the unkeyed BLAKE2b of `data` with a 32 byte output.
+    native public fun blake2b_256(data: vector<u8>): vector<u8>;
+
+    /// Returns the SipHash-2-4 of `data` keyed with the 16 byte `key`.
+    /// Aborts with `EINVALID_SIPHASH_KEY` if `key` is not 16 bytes long.
+    native public fun siphash(key: &vector<u8>, data: &vector<u8>): u64;
+
+    /// Returns a deterministic 16 byte id for `name` in `namespace`: the first 16 bytes of the
+    /// SHA3-256 of `namespace` followed by `name`, with the version and variant bits set as in
+    /// a UUIDv5.
+    native public fun derive_id(namespace: &vector<u8>, name: &vector<u8>): vector<u8>;
+
+    /// Derives `length` bytes of key material from the input key material `ikm` with HKDF-SHA256
+    /// (RFC 5869), using `salt` for the extract step and `info` for the expand step.
+    /// Aborts with `EHKDF_LENGTH_TOO_LARGE` if `length` exceeds 255 * 32.
+    native public fun hkdf_sha256(salt: &vector<u8>, ikm: &vector<u8>, info: &vector<u8>, length: u64): vector<u8>;
+
+    /// Returns true if `commitment` is the `sha3_256` of `reveal`, e.g. to check the reveal of a
+    /// commit-reveal scheme. The hashes are compared in constant time. A `commitment` which is
+    /// not 32 bytes long never matches.
+    native public fun verify_commitment(commitment: &vector<u8>, reveal: &vector<u8>): bool;
+
+    /// Returns the EIP-712 style Keccak-256 hash of the struct `v`:
+    /// `keccak256(x"1901" || keccak256(b"MoveTypedStructHash") || hash_struct(v))`, where
+    /// `hash_struct` hashes the type of the struct, named by its canonical type name, and
+    /// its fields encoded as 32 byte words. See `natives/hash.rs` for the full encoding.
+    /// Aborts with `ENOT_A_STRUCT` if `T` is not a struct.
+    native public fun typed_struct_hash<T>(v: &T): vector<u8>;
 }
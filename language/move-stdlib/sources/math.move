@@ -0,0 +1,71 @@
+/// Module which defines integer math functions that are expensive or awkward to
+/// express in Move itself.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::math {
+    use std::option::Option;
+
+    /// The result of the operation does not fit into its type.
+    const EOVERFLOW: u64 = 1;
+    /// The input must not be zero.
+    const EZERO: u64 = 2;
+
+    /// Rotates the bits of `x` left by `n` positions. `n` is taken modulo 64.
+    native public fun rotl_u64(x: u64, n: u32): u64;
+    /// Rotates the bits of `x` right by `n` positions. `n` is taken modulo 64.
+    native public fun rotr_u64(x: u64, n: u32): u64;
+    /// Rotates the bits of `x` left by `n` positions. `n` is taken modulo 128.
+    native public fun rotl_u128(x: u128, n: u32): u128;
+    /// Rotates the bits of `x` right by `n` positions. `n` is taken modulo 128.
+    native public fun rotr_u128(x: u128, n: u32): u128;
+    /// Rotates the bits of `x` left by `n` positions. `n` is taken modulo 256.
+    native public fun rotl_u256(x: u256, n: u32): u256;
+    /// Rotates the bits of `x` right by `n` positions. `n` is taken modulo 256.
+    native public fun rotr_u256(x: u256, n: u32): u256;
+
+    /// Returns the greatest common divisor of `a` and `b`. `gcd_u128(0, 0)` is 0.
+    native public fun gcd_u128(a: u128, b: u128): u128;
+    /// Returns the least common multiple of `a` and `b`, which is 0 if either input is 0.
+    /// Aborts with `EOVERFLOW` if the result does not fit into a `u128`.
+    native public fun lcm_u128(a: u128, b: u128): u128;
+
+    /// Returns the absolute difference of `a` and `b`, that is `max(a, b) - min(a, b)`.
+    native public fun abs_diff_u64(a: u64, b: u64): u64;
+    /// Returns the absolute difference of `a` and `b`, that is `max(a, b) - min(a, b)`.
+    native public fun abs_diff_u128(a: u128, b: u128): u128;
+    /// Returns the absolute difference of `a` and `b`, that is `max(a, b) - min(a, b)`.
+    native public fun abs_diff_u256(a: u256, b: u256): u256;
+
+    /// Returns the square root of `x`, rounded down.
+    native public fun sqrt_u128(x: u128): u128;
+
+    /// Packs `hi` and `lo` into a single `u128`, with `hi` in the upper 64 bits and `lo` in the
+    /// lower 64 bits, i.e. `hi * 2^64 + lo`. Packed pairs order like `(hi, lo)` tuples.
+    native public fun pack_u64_pair(hi: u64, lo: u64): u128;
+    /// Splits `x` into its upper and lower 64 bits, returned as `(hi, lo)`.
+    /// This is the inverse of `pack_u64_pair`.
+    native public fun unpack_u64_pair(x: u128): (u64, u64);
+
+    /// Returns the base 2 logarithm of `x`, rounded down. Aborts with `EZERO` if `x` is 0.
+    native public fun log2_u128(x: u128): u64;
+
+    /// Returns the smallest power of two greater than or equal to `x`. The result for 0 is 1.
+    /// Aborts with `EOVERFLOW` if the result does not fit into a `u64`, i.e. if `x > 2^63`.
+    native public fun next_power_of_two_u64(x: u64): u64;
+
+    /// Returns the sum of `a` and `b`, which are unsigned integers of arbitrary size given as
+    /// little-endian bytes. The sum is little-endian without trailing zero bytes, so a zero sum
+    /// is the empty vector.
+    native public fun bigint_add(a: &vector<u8>, b: &vector<u8>): vector<u8>;
+
+    /// Returns `a / b`, rounded down. Aborts with `EZERO` if `b` is 0.
+    native public fun floor_div_u128(a: u128, b: u128): u128;
+    /// Returns `a / b`, rounded up. This does not overflow for any `a`, unlike computing
+    /// `(a + b - 1) / b`. Aborts with `EZERO` if `b` is 0.
+    native public fun ceil_div_u128(a: u128, b: u128): u128;
+
+    /// Returns the inverse of `a` modulo `modulus`, that is the `x < modulus` with
+    /// `a * x % modulus == 1`, or none if `a` and `modulus` are not coprime. `a` need not be
+    /// reduced. Aborts with `EZERO` if `modulus` is 0.
+    native public fun mod_inverse_u256(a: u256, modulus: u256): Option<u256>;
+}
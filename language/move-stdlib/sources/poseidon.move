@@ -0,0 +1,20 @@
+/// Module which defines the Poseidon hash over the scalar field of BN254, for contracts which
+/// verify or mirror zero-knowledge circuits.
+///
+/// The hash is compatible with circomlib's `Poseidon(n)` template for 1 to 4 inputs: the S-box
+/// `x^5`, 8 full rounds, 56, 57, 56 or 60 partial rounds for 1, 2, 3 or 4 inputs, and the round
+/// constants and MDS matrix generated by the Grain LFSR of the reference implementation. See
+/// `natives/poseidon.rs` for the exact parameters.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::poseidon {
+    /// There are no inputs, or more than 4.
+    const EINVALID_NUM_INPUTS: u64 = 1;
+    /// An input is not below the order of the scalar field of BN254.
+    const ENOT_A_FIELD_ELEMENT: u64 = 2;
+
+    /// Returns the Poseidon hash of `inputs`, which are elements of the scalar field of BN254.
+    /// Aborts with `EINVALID_NUM_INPUTS` if there are no inputs or more than 4, and with
+    /// `ENOT_A_FIELD_ELEMENT` if an input is not below the order of the field.
+    native public fun poseidon_hash(inputs: &vector<u256>): u256;
+}
@@ -0,0 +1,12 @@
+/// Module which defines a decoder for the recursive length prefix (RLP) encoding of Ethereum,
+/// e.g. to verify Ethereum proofs.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::rlp {
+    use std::option::Option;
+
+    /// Decodes the RLP encoded list `bytes`. Returns the payloads of its string items. Nested
+    /// lists are returned in their encoded form, so that they can be decoded with `decode_list`
+    /// in turn. Returns none if `bytes` is not a single, canonically encoded list.
+    native public fun decode_list(bytes: &vector<u8>): Option<vector<vector<u8>>>;
+}
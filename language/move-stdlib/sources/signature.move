@@ -0,0 +1,15 @@
+/// Module which defines Ed25519 signature verification.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::signature {
+    /// Returns true if every signature in `signatures` is a valid Ed25519 signature over the
+    /// message at the same index in `messages`, under the 32 byte public key at the same index in
+    /// `public_keys`. The signatures are checked together, which is cheaper than verifying them
+    /// one by one. Malformed inputs, or vectors of different lengths, return false. An empty batch
+    /// returns false as well, so that a caller cannot accept a batch that checked no signature.
+    native public fun ed25519_batch_verify(
+        signatures: &vector<vector<u8>>,
+        public_keys: &vector<vector<u8>>,
+        messages: &vector<vector<u8>>
+    ): bool;
+}
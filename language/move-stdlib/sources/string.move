@@ -85,10 +85,49 @@ module std::string {
         internal_index_of(&s.bytes, &r.bytes)
     }
 
+    /// Returns the number of unicode scalar values (chars) in this string.
+    public fun char_count(s: &String): u64 {
+        internal_char_count(&s.bytes)
+    }
+
+    /// Splits this string into lines, at `\n` and `\r\n` line endings, which are not part of the lines.
+    /// A line ending at the end of the string does not start another, empty line: `"a\nb\n"` and
+    /// `"a\r\nb"` both have the lines `"a"` and `"b"`, and an empty string has no lines.
+    public fun split_lines(s: &String): vector<String> {
+        let lines = internal_split_lines(&s.bytes);
+        vector::reverse(&mut lines);
+        let result = vector::empty();
+        while (!vector::is_empty(&lines)) {
+            vector::push_back(&mut result, String{bytes: vector::pop_back(&mut lines)});
+        };
+        result
+    }
+
+    /// Returns this string with all characters mapped to their lowercase equivalent, as defined by
+    /// the Unicode `Lowercase` property. The result can have a different length in bytes.
+    public fun to_lowercase(s: &String): String {
+        String{bytes: internal_to_lowercase(&s.bytes)}
+    }
+
+    /// Returns this string with all characters mapped to their uppercase equivalent, as defined by
+    /// the Unicode `Uppercase` property. The result can have a different length in bytes and
+    /// characters, e.g. the German sharp s (U+00DF) maps to `SS`.
+    public fun to_uppercase(s: &String): String {
+        String{bytes: internal_to_uppercase(&s.bytes)}
+    }
+
+    #[test_only]
+    public fun internal_sub_string_for_testing(v: &vector<u8>, i: u64, j: u64): vector<u8> {
+        internal_sub_string(v, i, j)
+    }
 
     // Native API
     native fun internal_check_utf8(v: &vector<u8>): bool;
     native fun internal_is_char_boundary(v: &vector<u8>, i: u64): bool;
     native fun internal_sub_string(v: &vector<u8>, i: u64, j: u64): vector<u8>;
     native fun internal_index_of(v: &vector<u8>, r: &vector<u8>): u64;
+    native fun internal_char_count(v: &vector<u8>): u64;
+    native fun internal_split_lines(v: &vector<u8>): vector<vector<u8>>;
+    native fun internal_to_lowercase(v: &vector<u8>): vector<u8>;
+    native fun internal_to_uppercase(v: &vector<u8>): vector<u8>;
 }
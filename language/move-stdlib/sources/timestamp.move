@@ -0,0 +1,11 @@
+/// Module which provides access to the block timestamp.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::timestamp {
+    /// The session was not given a block timestamp.
+    const ETIMESTAMP_NOT_AVAILABLE: u64 = 1;
+
+    /// Returns the timestamp of the current block, in microseconds since the Unix epoch.
+    /// Aborts with `ETIMESTAMP_NOT_AVAILABLE` if the adapter did not provide one.
+    native public fun now_microseconds(): u64;
+}
@@ -0,0 +1,38 @@
+/// Module which defines functions inspecting the layout of types.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::type_info {
+    use std::option::Option;
+
+    /// The type is not a struct.
+    const ENOT_A_STRUCT: u64 = 1;
+    /// The ability is not one of `ability_copy`, `ability_drop`, `ability_store` and `ability_key`.
+    const EINVALID_ABILITY: u64 = 2;
+
+    /// Returns the number of fields of the struct type `T`. A struct declared without fields
+    /// has a single field at runtime, which the compiler inserts.
+    /// Aborts with `ENOT_A_STRUCT` if `T` is not a struct type.
+    native public fun num_fields<T>(): u64;
+
+    /// Returns true if `T` and `U` are the same type, including all of their type arguments.
+    native public fun type_eq<T, U>(): bool;
+
+    /// Returns the SHA3-256 hash of the published bytes of the module `addr::module_name`, e.g.
+    /// to identify the exact module replaced by an upgrade. Returns none if no such module is
+    /// published.
+    native public fun module_hash(addr: address, module_name: &vector<u8>): Option<vector<u8>>;
+
+    /// Returns true if `T` has `ability`, taking the abilities of the type arguments of `T` into
+    /// account. Aborts with `EINVALID_ABILITY` if `ability` is not one of the values returned by
+    /// `ability_copy`, `ability_drop`, `ability_store` and `ability_key`.
+    native public fun has_ability<T>(ability: u8): bool;
+
+    /// The encoding of the `copy` ability for `has_ability`.
+    public fun ability_copy(): u8 { 0x1 }
+    /// The encoding of the `drop` ability for `has_ability`.
+    public fun ability_drop(): u8 { 0x2 }
+    /// The encoding of the `store` ability for `has_ability`.
+    public fun ability_store(): u8 { 0x4 }
+    /// The encoding of the `key` ability for `has_ability`.
+    public fun ability_key(): u8 { 0x8 }
+}
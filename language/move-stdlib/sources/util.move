@@ -0,0 +1,11 @@
+/// Module which defines general purpose utility functions.
+///
+/// The functions in this module are natively declared in the Move runtime.
+module std::util {
+    /// Aborts with `code` if `cond` is false, and does nothing otherwise.
+    native public fun assert_with_code(cond: bool, code: u64);
+
+    /// Overwrites every byte of `v` with zero, keeping its length, e.g. to clear an ephemeral
+    /// secret. Unlike assigning zeros in Move, the writes cannot be optimized away.
+    native public fun zeroize(v: &mut vector<u8>);
+}
@@ -50,6 +50,83 @@ module std::vector {
     /// Aborts if `i` or `j` is out of bounds.
     native public fun swap<Element>(v: &mut vector<Element>, i: u64, j: u64);
 
+    /// Return the sum of `amounts[i] * weights[i]` over all indices, accumulated in a `u128`.
+    /// Aborts if `amounts` and `weights` have different lengths, or if the sum overflows a `u128`.
+    native public fun dot_product_u64(amounts: &vector<u64>, weights: &vector<u64>): u128;
+
+    /// Shorten the vector `v` to its first `len` elements, dropping the rest.
+    /// Does nothing if `v` has `len` or fewer elements.
+    native public fun truncate<Element: drop>(v: &mut vector<Element>, len: u64);
+
+    /// Return the minimum and the maximum element of `v`, in that order.
+    /// Aborts if `v` is empty.
+    native public fun minmax_u64(v: &vector<u64>): (u64, u64);
+
+    /// Return true if the elements of `v` are in non-decreasing order.
+    /// Empty and single-element vectors are sorted.
+    native public fun is_sorted_u64(v: &vector<u64>): bool;
+
+    /// Return the running sums of `v`: element `i` of the result is the sum of the first `i + 1`
+    /// elements of `v`. The sums are computed in `u128` and therefore cannot overflow.
+    native public fun prefix_sum_u64(v: &vector<u64>): vector<u128>;
+
+    /// Return the number of distinct elements in `v`.
+    native public fun count_distinct_u64(v: &vector<u64>): u64;
+
+    /// Return true if some element occurs more than once in `v`.
+    native public fun has_duplicates_u64(v: &vector<u64>): bool;
+
+    /// Return the weighted median of `values`, where `values[i]` has weight `weights[i]`.
+    /// This is the smallest value such that the values up to and including it carry at least
+    /// half of the total weight. If that is exactly half, the lower of the two middle values is
+    /// returned, so with equal weights and an even length the lower median is returned.
+    /// Aborts if the vectors have different lengths, or if they are empty or all weights are zero.
+    native public fun weighted_median_u64(values: &vector<u64>, weights: &vector<u64>): u64;
+
+    /// Return the `p`-th percentile of `v`, for `p` from 0 to 100, by the nearest-rank method:
+    /// the smallest element such that at least `p` percent of the elements are less than or equal
+    /// to it. There is no interpolation, so the result is always an element of `v`: `p = 0` gives
+    /// the minimum, `p = 100` the maximum, and `p = 50` the lower median for an even length.
+    /// Aborts if `v` is empty or `p > 100`.
+    native public fun percentile_u64(v: &vector<u64>, p: u64): u64;
+
+    /// Return an index `i` selected with probability `weights[i] / sum(weights)`, derived
+    /// deterministically from `seed`. The first 16 bytes of `hash::sha2_256(seed)`, read as a
+    /// big-endian `u128`, are taken modulo the total weight to get a point `p`, and the first
+    /// index at which the running sum of the weights exceeds `p` is returned.
+    /// Aborts if `weights` is empty or all weights are zero.
+    native public fun weighted_sample(weights: &vector<u64>, seed: &vector<u8>): u64;
+
+    /// Return a permutation of `0..len`, shuffled deterministically from `seed` by Fisher-Yates:
+    /// for `i` from `len - 1` down to `1`, the element at `i` is swapped with the one at
+    /// `r % (i + 1)`, where `r` is the next random number. The random numbers are big-endian
+    /// `u128`s, two per block, from the blocks `hash::sha2_256(hash::sha2_256(seed) || k)` for
+    /// `k = 0, 1, ...` serialized as big-endian `u64`s.
+    public fun shuffle_indices(len: u64, seed: &vector<u8>): vector<u64> {
+        native_shuffle_indices(len, seed)
+    }
+
+    native fun native_shuffle_indices(len: u64, seed: &vector<u8>): vector<u64>;
+
+    /// Return element `(r, c)` of the `rows` x `cols` matrix stored in row-major order in `v`,
+    /// i.e. `v[r * cols + c]`. Aborts if `rows * cols` is not the length of `v`, or if `r` or `c`
+    /// is out of range.
+    native public fun get_2d_u64(v: &vector<u64>, rows: u64, cols: u64, r: u64, c: u64): u64;
+
+    /// Return the permutation which stably sorts `keys` in ascending order: element `i` of the
+    /// result is the index in `keys` of the `i`-th smallest key. Equal keys keep their original
+    /// relative order, so the permutation can be applied to records sorted by these keys.
+    native public fun stable_sort_by_key_u64(keys: &vector<u64>): vector<u64>;
+
+    /// Return the inverse of the permutation `perm`: element `perm[i]` of the result is `i`.
+    /// Aborts if `perm` does not contain each of `0, 1, ..., length(perm) - 1` exactly once.
+    native public fun invert_permutation(perm: &vector<u64>): vector<u64>;
+
+    /// Return `v` permuted by `perm`: element `i` of the result is `v[perm[i]]`, so applying the
+    /// result of `stable_sort_by_key_u64(&v)` sorts `v`. Aborts if `perm` and `v` have different
+    /// lengths, or if `perm` does not contain each of `0, 1, ..., length(v) - 1` exactly once.
+    native public fun apply_permutation_u64(v: &vector<u64>, perm: &vector<u64>): vector<u64>;
+
     /// Return an vector of size one containing element `e`.
     public fun singleton<Element>(e: Element): vector<Element> {
         let v = empty();
@@ -130,6 +207,19 @@ module std::vector {
         pragma intrinsic = true;
     }
 
+    /// Like `index_of`, but searches `v` for `e` without borrowing each element from Move, comparing
+    /// elements like `==`. It is charged per comparison and by the size of the compared elements.
+    native public fun native_index_of<Element>(v: &vector<Element>, e: &Element): (bool, u64);
+
+    /// Return a copy of the elements of `v` from index `start` up to, but excluding, index `end`.
+    /// Aborts if `start > end` or `end > length(v)`.
+    public fun slice<Element: copy>(v: &vector<Element>, start: u64, end: u64): vector<Element> {
+        native_slice(v, start, end)
+    }
+
+    /// Copies the sub-range in a single native call instead of borrowing each element from Move.
+    native fun native_slice<Element: copy>(v: &vector<Element>, start: u64, end: u64): vector<Element>;
+
     /// Remove the `i`th element of the vector `v`, shifting all subsequent elements.
     /// This is O(n) and preserves ordering of elements in the vector.
     /// Aborts if `i` is out of bounds.
@@ -0,0 +1,184 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions operating on account addresses.
+
+use crate::natives::helpers::make_module_natives;
+use better_any::{Tid, TidAble};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::{
+    collections::{BTreeSet, VecDeque},
+    sync::Arc,
+};
+
+// Abort codes, these must match the constants in `account.move`.
+const ECREATE_SIGNER_NOT_AUTHORIZED: u64 = 1;
+
+/***************************************************************************************************
+ * native fun address_from_hex
+ *
+ *   Parses a `0x`-prefixed or bare hex string into an address, returning an `Option<address>`.
+ *
+ *   gas cost: base_cost + unit_cost * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressFromHexGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn parse_hex_address(bytes: &[u8]) -> Option<AccountAddress> {
+    let s = std::str::from_utf8(bytes).ok()?;
+    let digits = s.strip_prefix("0x").unwrap_or(s);
+    if digits.is_empty() {
+        return None;
+    }
+    AccountAddress::from_hex_literal(&format!("0x{}", digits)).ok()
+}
+
+fn native_address_from_hex(
+    gas_params: &AddressFromHexGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    // make a std::option::Option<address>
+    let option_val = Value::struct_(Struct::pack(vec![Value::vector_address(
+        parse_hex_address(&bytes),
+    )]));
+    Ok(NativeResult::ok(cost, smallvec![option_val]))
+}
+
+pub fn make_native_address_from_hex(gas_params: AddressFromHexGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_address_from_hex(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun create_signer
+ *
+ *   Creates a signer for `addr`, if the session allows it.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateSignerGasParameters {
+    pub base: InternalGas,
+}
+
+/// Native context extension which authorizes `account::create_signer`.
+///
+/// A signer is the proof that a transaction was authorized by the owner of an address, so
+/// creating one out of thin air bypasses authentication entirely. `create_signer` therefore
+/// aborts unless the session was created with this extension, and only creates signers for the
+/// addresses it allows. Adapters should only add it to sessions running trusted code, e.g. a
+/// framework creating resource accounts, and restrict it to the addresses that code derives.
+#[derive(Tid)]
+pub struct NativeCreateSignerPermission {
+    // `None` allows any address.
+    addresses: Option<BTreeSet<AccountAddress>>,
+}
+
+impl NativeCreateSignerPermission {
+    /// Allows creating a signer for any address.
+    pub fn any_address() -> Self {
+        Self { addresses: None }
+    }
+
+    /// Allows creating signers for the given addresses only.
+    pub fn for_addresses(addresses: impl IntoIterator<Item = AccountAddress>) -> Self {
+        Self {
+            addresses: Some(addresses.into_iter().collect()),
+        }
+    }
+
+    fn allows(&self, addr: &AccountAddress) -> bool {
+        self.addresses
+            .as_ref()
+            .map_or(true, |addresses| addresses.contains(addr))
+    }
+}
+
+fn native_create_signer(
+    gas_params: &CreateSignerGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let addr = pop_arg!(args, AccountAddress);
+
+    let extensions = context.extensions();
+    let authorized = extensions.contains::<NativeCreateSignerPermission>()
+        && extensions
+            .get::<NativeCreateSignerPermission>()
+            .allows(&addr);
+    if !authorized {
+        return Ok(NativeResult::err(
+            gas_params.base,
+            ECREATE_SIGNER_NOT_AUTHORIZED,
+        ));
+    }
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::signer(addr)],
+    ))
+}
+
+pub fn make_native_create_signer(gas_params: CreateSignerGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_create_signer(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub address_from_hex: AddressFromHexGasParameters,
+    pub create_signer: CreateSignerGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "address_from_hex",
+            make_native_address_from_hex(gas_params.address_from_hex),
+        ),
+        (
+            "create_signer",
+            make_native_create_signer(gas_params.create_signer),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
@@ -7,7 +7,9 @@ use move_binary_format::errors::PartialVMResult;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::vm_status::sub_status::NFE_BCS_TO_ADDRESS_FAILURE;
 use move_core_types::{
-    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    gas_algebra::{InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes},
+    u256::U256,
+    value::{MoveStruct, MoveValue},
     vm_status::sub_status::NFE_BCS_SERIALIZATION_FAILURE,
 };
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
@@ -15,7 +17,8 @@ use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
     pop_arg,
-    values::{values_impl::Reference, Value},
+    values::{values_impl::Reference, Struct, Value, Vector, VectorRef},
+    views::{ValueView, ValueVisitor},
 };
 use smallvec::smallvec;
 use std::{collections::VecDeque, sync::Arc};
@@ -90,6 +93,63 @@ pub fn make_native_to_bytes(gas_params: ToBytesGasParameters) -> NativeFunction
     )
 }
 
+/***************************************************************************************************
+ * native fun serialized_size
+ *
+ *   Returns the length of `to_bytes(v)`, without allocating the serialization. Fails like
+ *   `to_bytes`.
+ *
+ *   gas cost: base_cost + per_byte_serialized * size_of(output)
+ *
+ *             If getting the type layout or serializing fails, base_cost + failure_cost is
+ *             charged.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializedSizeGasParameters {
+    pub base: InternalGas,
+    pub per_byte_serialized: InternalGasPerByte,
+    pub failure: InternalGas,
+}
+
+/// Rust implementation of Move's `native public fun serialized_size<T>(&T): u64`
+fn native_serialized_size(
+    gas_params: &SerializedSizeGasParameters,
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let ref_to_val = pop_arg!(args, Reference);
+    let arg_type = ty_args.pop().unwrap();
+
+    let size = match context.type_to_type_layout(&arg_type)? {
+        Some(layout) => ref_to_val.read_ref()?.simple_serialized_size(&layout),
+        None => None,
+    };
+    match size {
+        Some(size) => {
+            let cost =
+                gas_params.base + gas_params.per_byte_serialized * NumBytes::new(size as u64);
+            Ok(NativeResult::ok(cost, smallvec![Value::u64(size as u64)]))
+        }
+        None => Ok(NativeResult::err(
+            gas_params.base + gas_params.failure,
+            NFE_BCS_SERIALIZATION_FAILURE,
+        )),
+    }
+}
+
+pub fn make_native_serialized_size(gas_params: SerializedSizeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_serialized_size(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 /***************************************************************************************************
  * native fun native_to_address
  *
@@ -133,6 +193,227 @@ pub fn make_native_to_address(gas_params: ToAddressGasParameters) -> NativeFunct
         },
     )
 }
+/***************************************************************************************************
+ * native fun canonicalize
+ *
+ *   Deserializes `bytes` as a `T` and serializes it again, returning the result only if it is
+ *   identical to `bytes`.
+ *
+ *   gas cost: base_cost + unit_cost * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalizeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+/// Rust implementation of Move's `native public fun canonicalize<T>(bytes: &vector<u8>): Option<vector<u8>>`
+fn native_canonicalize(
+    gas_params: &CanonicalizeGasParameters,
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+    let arg_type = ty_args.pop().unwrap();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    let canonical = context
+        .type_to_type_layout(&arg_type)?
+        .and_then(|layout| {
+            Value::simple_deserialize(&bytes, &layout)
+                .and_then(|value| value.simple_serialize(&layout))
+        })
+        .filter(|reserialized| reserialized.as_slice() == bytes.as_slice());
+
+    // make a std::option::Option<vector<u8>>
+    let option_val = Value::struct_(Struct::pack(vec![Vector::pack(
+        &Type::Vector(Arc::new(Type::U8)),
+        canonical.map(Value::vector_u8).into_iter().collect(),
+    )?]));
+    Ok(NativeResult::ok(cost, smallvec![option_val]))
+}
+
+pub fn make_native_canonicalize(gas_params: CanonicalizeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_canonicalize(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun from_bytes
+ *
+ *   Deserializes `bytes` as a `T`. Bytes which are not a BCS encoding of a `T`, including an
+ *   encoding followed by trailing bytes, abort. So do values breaking the invariants of the
+ *   standard library's `String`s, `ascii::Char` or `Option`, such as a `String` holding invalid
+ *   UTF-8 or an `Option` with two elements.
+ *
+ *   gas cost: base_cost + per_byte * length_in_bytes + per_value * number_of_values
+ *
+ *   The number of values counts every primitive, struct and vector of the result, so that
+ *   deserializing e.g. a `vector<u8>` costs more than a `u256` of the same encoded size.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+    pub per_value: InternalGasPerArg,
+}
+
+// Counts the values of a value, including itself.
+struct ValueCounter(u64);
+
+impl ValueVisitor for ValueCounter {
+    fn visit_u8(&mut self, _depth: usize, _val: u8) {
+        self.0 += 1;
+    }
+
+    fn visit_u16(&mut self, _depth: usize, _val: u16) {
+        self.0 += 1;
+    }
+
+    fn visit_u32(&mut self, _depth: usize, _val: u32) {
+        self.0 += 1;
+    }
+
+    fn visit_u64(&mut self, _depth: usize, _val: u64) {
+        self.0 += 1;
+    }
+
+    fn visit_u128(&mut self, _depth: usize, _val: u128) {
+        self.0 += 1;
+    }
+
+    fn visit_u256(&mut self, _depth: usize, _val: U256) {
+        self.0 += 1;
+    }
+
+    fn visit_bool(&mut self, _depth: usize, _val: bool) {
+        self.0 += 1;
+    }
+
+    fn visit_address(&mut self, _depth: usize, _val: AccountAddress) {
+        self.0 += 1;
+    }
+
+    fn visit_struct(&mut self, _depth: usize, _len: usize) -> bool {
+        self.0 += 1;
+        true
+    }
+
+    fn visit_vec(&mut self, _depth: usize, _len: usize) -> bool {
+        self.0 += 1;
+        true
+    }
+
+    fn visit_ref(&mut self, _depth: usize, _is_global: bool) -> bool {
+        self.0 += 1;
+        true
+    }
+}
+
+// Checks the invariants that `std::string::String`, `std::ascii::{String, Char}` and
+// `std::option::Option` maintain in their defining modules, and which the string natives rely on. `value` must be
+// deserialized against a fully annotated layout.
+fn respects_std_invariants(value: &MoveValue) -> bool {
+    fn bytes(value: &MoveValue) -> Option<Vec<u8>> {
+        match value {
+            MoveValue::Vector(elems) => elems
+                .iter()
+                .map(|elem| match elem {
+                    MoveValue::U8(b) => Some(*b),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
+    match value {
+        MoveValue::Vector(elems) => elems.iter().all(respects_std_invariants),
+        MoveValue::Struct(MoveStruct::WithTypes { type_, fields }) => {
+            let valid = if type_.address != AccountAddress::ONE {
+                true
+            } else {
+                match (
+                    type_.module.as_str(),
+                    type_.name.as_str(),
+                    fields.as_slice(),
+                ) {
+                    ("string", "String", [(_, field)]) => {
+                        bytes(field).map_or(false, |b| std::str::from_utf8(&b).is_ok())
+                    }
+                    ("ascii", "String", [(_, field)]) => {
+                        bytes(field).map_or(false, |b| b.is_ascii())
+                    }
+                    ("ascii", "Char", [(_, MoveValue::U8(b))]) => b.is_ascii(),
+                    ("option", "Option", [(_, MoveValue::Vector(elems))]) => elems.len() <= 1,
+                    _ => true,
+                }
+            };
+            valid
+                && fields
+                    .iter()
+                    .all(|(_, field)| respects_std_invariants(field))
+        }
+        _ => true,
+    }
+}
+
+/// Rust implementation of Move's `native public fun from_bytes<T: copy + drop>(bytes: vector<u8>): T`
+fn native_from_bytes(
+    gas_params: &FromBytesGasParameters,
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+    let arg_type = ty_args.pop().unwrap();
+
+    let mut cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    // Deserializing against the annotated layout tells which standard library structs the
+    // value contains, so that their invariants can be checked.
+    let respects_invariants = context
+        .type_to_fully_annotated_layout(&arg_type)?
+        .and_then(|layout| MoveValue::simple_deserialize(&bytes, &layout).ok())
+        .map_or(false, |value| respects_std_invariants(&value));
+    if !respects_invariants {
+        return Ok(NativeResult::err(cost, NFE_BCS_SERIALIZATION_FAILURE));
+    }
+    // `simple_deserialize` fails on trailing bytes.
+    let value = match context
+        .type_to_type_layout(&arg_type)?
+        .and_then(|layout| Value::simple_deserialize(&bytes, &layout))
+    {
+        Some(value) => value,
+        None => return Ok(NativeResult::err(cost, NFE_BCS_SERIALIZATION_FAILURE)),
+    };
+    let mut counter = ValueCounter(0);
+    value.visit(&mut counter);
+    cost += gas_params.per_value * NumArgs::new(counter.0);
+
+    Ok(NativeResult::ok(cost, smallvec![value]))
+}
+
+pub fn make_native_from_bytes(gas_params: FromBytesGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_from_bytes(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -140,12 +421,24 @@ pub fn make_native_to_address(gas_params: ToAddressGasParameters) -> NativeFunct
 pub struct GasParameters {
     pub to_bytes: ToBytesGasParameters,
     pub to_address: ToAddressGasParameters,
+    pub canonicalize: CanonicalizeGasParameters,
+    pub from_bytes: FromBytesGasParameters,
+    pub serialized_size: SerializedSizeGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
     let natives = [
         ("to_bytes", make_native_to_bytes(gas_params.to_bytes)),
         ("to_address", make_native_to_address(gas_params.to_address)),
+        (
+            "canonicalize",
+            make_native_canonicalize(gas_params.canonicalize),
+        ),
+        ("from_bytes", make_native_from_bytes(gas_params.from_bytes)),
+        (
+            "serialized_size",
+            make_native_serialized_size(gas_params.serialized_size),
+        ),
     ];
 
     make_module_natives(natives)
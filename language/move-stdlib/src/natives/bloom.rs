@@ -0,0 +1,159 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for bloom filters.
+//!
+//! A filter is a byte vector of `m = 8 * length` bits, where bit `k` is bit `k % 8` (counting from
+//! the least significant bit) of byte `k / 8`. An item is hashed with SHA3-256, and its digest
+//! split into the little-endian u64s `h1` (bytes 0..8) and `h2` (bytes 8..16). The i-th of the
+//! `num_hashes` bits of the item is `(h1 + i * h2) mod m`, computed with wrapping u64 arithmetic.
+//! Off-chain code which builds or checks filters must use the same scheme.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{
+    InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use sha3::{Digest, Sha3_256};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Abort codes, these must match the constants in `bloom.move`.
+const EEMPTY_FILTER: u64 = 1;
+const ETOO_MANY_HASHES: u64 = 2;
+
+// Returns the indices of the bits of `item` in a filter of `num_bits` bits.
+fn bit_indices(item: &[u8], num_hashes: u64, num_bits: u64) -> impl Iterator<Item = u64> {
+    let digest = Sha3_256::digest(item);
+    let h1 = u64::from_le_bytes(digest[0..8].try_into().unwrap());
+    let h2 = u64::from_le_bytes(digest[8..16].try_into().unwrap());
+    (0..num_hashes).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+}
+
+// Checks the filter parameters, returning the abort code if they are invalid. The number of
+// hashes is bounded by the number of bits, as more hashes cannot set more bits.
+fn check_params(filter_len: usize, num_hashes: u64) -> Result<u64, u64> {
+    let num_bits = 8 * filter_len as u64;
+    if num_bits == 0 {
+        return Err(EEMPTY_FILTER);
+    }
+    if num_hashes > num_bits {
+        return Err(ETOO_MANY_HASHES);
+    }
+    Ok(num_bits)
+}
+
+/***************************************************************************************************
+ * native fun test, add
+ *
+ *   `test` returns whether all bits of `item` are set in `filter`, `add` sets them. Both abort if
+ *   the filter is empty or `num_hashes` exceeds the number of bits of the filter.
+ *
+ *   gas cost: base_cost + per_hash_cost * num_hashes + unit_cost * item_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomGasParameters {
+    pub base: InternalGas,
+    pub per_hash: InternalGasPerArg,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl BloomGasParameters {
+    fn cost(&self, num_hashes: u64, item_len: usize) -> InternalGas {
+        self.base
+            + self.per_hash * NumArgs::new(num_hashes)
+            + self.per_byte * NumBytes::new(item_len as u64)
+    }
+}
+
+fn native_test(
+    gas_params: &BloomGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let item = pop_arg!(args, VectorRef);
+    let num_hashes = pop_arg!(args, u64);
+    let filter = pop_arg!(args, VectorRef);
+    let item = item.as_bytes_ref();
+    let filter = filter.as_bytes_ref();
+
+    let num_bits = match check_params(filter.len(), num_hashes) {
+        Ok(num_bits) => num_bits,
+        Err(code) => return Ok(NativeResult::err(gas_params.base, code)),
+    };
+    let cost = gas_params.cost(num_hashes, item.len());
+    let contained = bit_indices(&item, num_hashes, num_bits)
+        .all(|k| filter[(k / 8) as usize] & (1 << (k % 8)) != 0);
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(contained)]))
+}
+
+pub fn make_native_test(gas_params: BloomGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_test(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+fn native_add(
+    gas_params: &BloomGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let item = pop_arg!(args, VectorRef);
+    let num_hashes = pop_arg!(args, u64);
+    let filter = pop_arg!(args, VectorRef);
+    let item = item.as_bytes_ref();
+    let mut filter = filter.as_bytes_mut();
+
+    let num_bits = match check_params(filter.len(), num_hashes) {
+        Ok(num_bits) => num_bits,
+        Err(code) => return Ok(NativeResult::err(gas_params.base, code)),
+    };
+    let cost = gas_params.cost(num_hashes, item.len());
+    for k in bit_indices(&item, num_hashes, num_bits) {
+        filter[(k / 8) as usize] |= 1 << (k % 8);
+    }
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+pub fn make_native_add(gas_params: BloomGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_add(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub bloom: BloomGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("test", make_native_test(gas_params.bloom.clone())),
+        ("add", make_native_add(gas_params.bloom)),
+    ];
+
+    make_module_natives(natives)
+}
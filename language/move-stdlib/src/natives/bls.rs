@@ -0,0 +1,102 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for BLS12-381 signatures.
+
+use crate::natives::helpers::make_module_natives;
+use blst::{
+    min_pk::{PublicKey, Signature},
+    BLST_ERROR,
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// The ciphersuite of the proof-of-possession scheme with public keys in G1 and signatures in G2.
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/***************************************************************************************************
+ * native fun bls12381_verify
+ *
+ *   Verifies a (possibly aggregate) signature over `message` against a (possibly aggregate)
+ *   public key. Malformed signatures and public keys fail verification.
+ *
+ *   gas cost: base_cost + unit_cost * message_length_in_bytes
+ *
+ *   The base cost covers deserialization, the subgroup checks and the pairing.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bls12381VerifyGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn bls12381_verify(signature: &[u8], public_key: &[u8], message: &[u8]) -> bool {
+    // `key_validate` rejects points outside of the subgroup and the identity.
+    let public_key = match PublicKey::key_validate(public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match Signature::from_bytes(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    signature.verify(true, message, DST, &[], &public_key, false) == BLST_ERROR::BLST_SUCCESS
+}
+
+fn native_bls12381_verify(
+    gas_params: &Bls12381VerifyGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let message = pop_arg!(args, VectorRef);
+    let public_key = pop_arg!(args, VectorRef);
+    let signature = pop_arg!(args, VectorRef);
+    let message = message.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(message.len() as u64);
+    let valid = bls12381_verify(
+        &signature.as_bytes_ref(),
+        &public_key.as_bytes_ref(),
+        &message,
+    );
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+pub fn make_native_bls12381_verify(gas_params: Bls12381VerifyGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_bls12381_verify(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub bls12381_verify: Bls12381VerifyGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "bls12381_verify",
+        make_native_bls12381_verify(gas_params.bls12381_verify),
+    )];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,279 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions operating on byte vectors.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, Vector, VectorRef},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Abort codes, these must match the constants in `bytes.move`.
+const EINVALID_STRIDE: u64 = 1;
+
+/***************************************************************************************************
+ * native fun deinterleave
+ *
+ *   Splits `bytes` into `stride` vectors, where the i-th vector holds every `stride`-th byte
+ *   starting at index i. A `stride` longer than `bytes` aborts, so that the number of vectors
+ *   allocated is bounded by the length charged for.
+ *
+ *   gas cost: base_cost + unit_cost * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeinterleaveGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_deinterleave(
+    gas_params: &DeinterleaveGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let stride = pop_arg!(args, u64) as usize;
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    if stride == 0 || stride > bytes.len() || bytes.len() % stride != 0 {
+        return Ok(NativeResult::err(cost, EINVALID_STRIDE));
+    }
+
+    let lanes = (0..stride)
+        .map(|lane| Value::vector_u8(bytes.iter().skip(lane).step_by(stride).copied()))
+        .collect();
+    NativeResult::map_partial_vm_result_one(
+        cost,
+        Vector::pack(&Type::Vector(Arc::new(Type::U8)), lanes),
+    )
+}
+
+pub fn make_native_deinterleave(gas_params: DeinterleaveGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_deinterleave(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun starts_with, ends_with
+ *
+ *   gas cost: base_cost + unit_cost * length_of_prefix_or_suffix_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AffixGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_starts_with(
+    gas_params: &AffixGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let prefix = pop_arg!(args, VectorRef);
+    let haystack = pop_arg!(args, VectorRef);
+    let prefix = prefix.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(prefix.len() as u64);
+    let result = haystack.as_bytes_ref().starts_with(&prefix);
+    NativeResult::map_partial_vm_result_one(cost, Ok(Value::bool(result)))
+}
+
+pub fn make_native_starts_with(gas_params: AffixGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_starts_with(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+fn native_ends_with(
+    gas_params: &AffixGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let suffix = pop_arg!(args, VectorRef);
+    let haystack = pop_arg!(args, VectorRef);
+    let suffix = suffix.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(suffix.len() as u64);
+    let result = haystack.as_bytes_ref().ends_with(&suffix);
+    NativeResult::map_partial_vm_result_one(cost, Ok(Value::bool(result)))
+}
+
+pub fn make_native_ends_with(gas_params: AffixGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_ends_with(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun read_uleb128
+ *
+ *   Decodes the ULEB128 value starting at `offset`, returning the value, or none if the encoding
+ *   is truncated, overflows a u64 or is overlong, and the number of bytes consumed.
+ *
+ *   gas cost: base_cost + unit_cost * bytes_read
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadUleb128GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+// Decodes the ULEB128 value at the start of `bytes`. Returns the value, if the encoding is valid
+// and canonical, together with the number of bytes read.
+pub(crate) fn decode_uleb128(bytes: &[u8]) -> (Option<u64>, usize) {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate() {
+        let digit = (byte & 0x7f) as u64;
+        let shift = 7 * i as u32;
+        // The tenth byte can only hold the most significant bit of a u64.
+        if shift >= u64::BITS || (digit << shift) >> shift != digit {
+            return (None, i + 1);
+        }
+        value |= digit << shift;
+        if byte & 0x80 == 0 {
+            // A trailing zero byte means there is a shorter encoding of the same value.
+            if *byte == 0 && i > 0 {
+                return (None, i + 1);
+            }
+            return (Some(value), i + 1);
+        }
+    }
+    (None, bytes.len())
+}
+
+fn native_read_uleb128(
+    gas_params: &ReadUleb128GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let offset = pop_arg!(args, u64) as usize;
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let (value, bytes_read) = match bytes.get(offset..) {
+        Some(tail) => decode_uleb128(tail),
+        None => (None, 0),
+    };
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes_read as u64);
+    let consumed = if value.is_some() { bytes_read } else { 0 };
+    // make a std::option::Option<u64>
+    let option_val = Value::struct_(Struct::pack(vec![Value::vector_u64(value)]));
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![option_val, Value::u64(consumed as u64)],
+    ))
+}
+
+pub fn make_native_read_uleb128(gas_params: ReadUleb128GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_read_uleb128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun not_bytes
+ *
+ *   Returns the bitwise complement of each byte.
+ *
+ *   gas cost: base_cost + unit_cost * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_not_bytes(
+    gas_params: &NotBytesGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    let complement = Value::vector_u8(bytes.iter().map(|b| !b));
+    Ok(NativeResult::ok(cost, smallvec![complement]))
+}
+
+pub fn make_native_not_bytes(gas_params: NotBytesGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_not_bytes(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub deinterleave: DeinterleaveGasParameters,
+    pub affix: AffixGasParameters,
+    pub read_uleb128: ReadUleb128GasParameters,
+    pub not_bytes: NotBytesGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "deinterleave",
+            make_native_deinterleave(gas_params.deinterleave),
+        ),
+        (
+            "starts_with",
+            make_native_starts_with(gas_params.affix.clone()),
+        ),
+        ("ends_with", make_native_ends_with(gas_params.affix)),
+        (
+            "read_uleb128",
+            make_native_read_uleb128(gas_params.read_uleb128),
+        ),
+        ("not_bytes", make_native_not_bytes(gas_params.not_bytes)),
+    ];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,101 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions reading properties of the current transaction.
+
+use crate::natives::helpers::make_module_natives;
+use better_any::{Tid, TidAble};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::InternalGas;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, values::Value,
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Abort codes, these must match the constants in `chain.move`.
+const EGAS_UNIT_PRICE_NOT_AVAILABLE: u64 = 1;
+
+/// Native context extension which provides the gas unit price of the transaction to
+/// `chain::gas_unit_price`.
+///
+/// Adapters add it to the session of each transaction with the price the sender agreed to pay.
+#[derive(Tid)]
+pub struct NativeGasUnitPriceContext {
+    gas_unit_price: u64,
+}
+
+impl NativeGasUnitPriceContext {
+    pub fn new(gas_unit_price: u64) -> Self {
+        Self { gas_unit_price }
+    }
+
+    /// The price per unit of gas the sender of the transaction agreed to pay.
+    pub fn gas_unit_price(&self) -> u64 {
+        self.gas_unit_price
+    }
+}
+
+/***************************************************************************************************
+ * native fun gas_unit_price
+ *
+ *   Returns the gas unit price provided by the `NativeGasUnitPriceContext` of the session.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasUnitPriceGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_gas_unit_price(
+    gas_params: &GasUnitPriceGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let extensions = context.extensions();
+    if !extensions.contains::<NativeGasUnitPriceContext>() {
+        return Ok(NativeResult::err(
+            gas_params.base,
+            EGAS_UNIT_PRICE_NOT_AVAILABLE,
+        ));
+    }
+    let gas_unit_price = extensions
+        .get::<NativeGasUnitPriceContext>()
+        .gas_unit_price();
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(gas_unit_price)],
+    ))
+}
+
+pub fn make_native_gas_unit_price(gas_params: GasUnitPriceGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_gas_unit_price(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub gas_unit_price: GasUnitPriceGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "gas_unit_price",
+        make_native_gas_unit_price(gas_params.gas_unit_price),
+    )];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,389 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for binary-to-text and compact binary encodings.
+
+use crate::natives::{bytes::decode_uleb128, helpers::make_module_natives};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{
+    InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Reference, Struct, Value, Vector, VectorRef},
+};
+use sha2::{Digest, Sha256};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const CHECKSUM_LEN: usize = 4;
+
+/// The maximal length of a base58check string, which leaves room for extended keys of 111
+/// characters. Decoding takes time quadratic in the length, so longer inputs are rejected rather
+/// than charged for.
+pub const MAX_BASE58CHECK_LEN: usize = 128;
+
+/***************************************************************************************************
+ * native fun base58check_decode
+ *
+ *   Decodes a base58 string and verifies its trailing 4 byte checksum, which is the prefix of the
+ *   double SHA-256 of the payload. Returns the payload, or none on any failure, including an
+ *   input longer than `MAX_BASE58CHECK_LEN`.
+ *
+ *   gas cost: base_cost + unit_cost * input_length_in_bytes
+ *             + sha256_unit_cost * hashed_length_in_bytes
+ *
+ *   The hashed length is that of the payload whenever the checksum is checked, whether or not it
+ *   matches. The hash of the 32 byte digest is covered by the base cost, and so is the quadratic
+ *   part of decoding, as the input length is bounded.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base58CheckDecodeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+    pub sha256_per_byte: InternalGasPerByte,
+}
+
+/// Decodes a base58 string, where each leading '1' stands for a leading zero byte.
+fn base58_decode(input: &[u8]) -> Option<Vec<u8>> {
+    let zeros = input.iter().take_while(|c| **c == b'1').count();
+    // The little-endian digits of the number encoded after the leading zeros.
+    let mut digits: Vec<u8> = vec![];
+    for c in &input[zeros..] {
+        let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+        for digit in digits.iter_mut() {
+            carry += *digit as u32 * 58;
+            *digit = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            digits.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let mut bytes = vec![0; zeros];
+    bytes.extend(digits.iter().rev());
+    Some(bytes)
+}
+
+// Returns the payload if `input` is a valid base58check string, together with the number of bytes
+// hashed to verify the checksum.
+fn base58check_decode(input: &[u8]) -> (Option<Vec<u8>>, usize) {
+    if input.len() > MAX_BASE58CHECK_LEN {
+        return (None, 0);
+    }
+    let mut payload = match base58_decode(input) {
+        Some(payload) if payload.len() >= CHECKSUM_LEN => payload,
+        _ => return (None, 0),
+    };
+    let checksum = payload.split_off(payload.len() - CHECKSUM_LEN);
+    let digest = Sha256::digest(&Sha256::digest(&payload));
+    let hashed_len = payload.len();
+    (
+        (digest[..CHECKSUM_LEN] == checksum[..]).then_some(payload),
+        hashed_len,
+    )
+}
+
+fn native_base58check_decode(
+    gas_params: &Base58CheckDecodeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let input = pop_arg!(args, VectorRef);
+    let input = input.as_bytes_ref();
+
+    let (payload, hashed_len) = base58check_decode(&input);
+    let cost = gas_params.base
+        + gas_params.per_byte * NumBytes::new(input.len() as u64)
+        + gas_params.sha256_per_byte * NumBytes::new(hashed_len as u64);
+    // make a std::option::Option<vector<u8>>
+    let payload = Vector::pack(
+        &Type::Vector(Arc::new(Type::U8)),
+        payload.map(Value::vector_u8).into_iter().collect(),
+    )?;
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::struct_(Struct::pack(vec![payload]))],
+    ))
+}
+
+pub fn make_native_base58check_decode(
+    gas_params: Base58CheckDecodeGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_base58check_decode(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun rle_encode, rle_decode
+ *
+ *   Run-length encoding of bytes as a sequence of (count, value) byte pairs, where each pair
+ *   stands for `count` repetitions of `value`. `rle_encode` emits maximal runs of up to 255
+ *   bytes. `rle_decode` returns none if the input has an odd length or a count of zero.
+ *
+ *   gas cost: base_cost + unit_cost * (input_length_in_bytes + output_length_in_bytes)
+ *
+ *   Decoding is charged for its output, which can be up to 127.5 times longer than its input.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RleGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn rle_encode(bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![];
+    let mut iter = bytes.iter().peekable();
+    while let Some(value) = iter.next() {
+        let mut count = 1u8;
+        while count < u8::MAX && iter.next_if_eq(&value).is_some() {
+            count += 1;
+        }
+        encoded.extend([count, *value]);
+    }
+    encoded
+}
+
+fn rle_decode(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+    let mut decoded = vec![];
+    for pair in bytes.chunks_exact(2) {
+        let (count, value) = (pair[0], pair[1]);
+        if count == 0 {
+            return None;
+        }
+        decoded.extend(std::iter::repeat(value).take(count as usize));
+    }
+    Some(decoded)
+}
+
+fn native_rle_encode(
+    gas_params: &RleGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let encoded = rle_encode(&bytes);
+    let cost =
+        gas_params.base + gas_params.per_byte * NumBytes::new((bytes.len() + encoded.len()) as u64);
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(encoded)]))
+}
+
+pub fn make_native_rle_encode(gas_params: RleGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rle_encode(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+fn native_rle_decode(
+    gas_params: &RleGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let decoded = rle_decode(&bytes);
+    let decoded_len = decoded.as_ref().map_or(0, |decoded| decoded.len());
+    let cost =
+        gas_params.base + gas_params.per_byte * NumBytes::new((bytes.len() + decoded_len) as u64);
+    // make a std::option::Option<vector<u8>>
+    let decoded = Vector::pack(
+        &Type::Vector(Arc::new(Type::U8)),
+        decoded.map(Value::vector_u8).into_iter().collect(),
+    )?;
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::struct_(Struct::pack(vec![decoded]))],
+    ))
+}
+
+pub fn make_native_rle_decode(gas_params: RleGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rle_decode(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun native_delta_encode_u64, native_delta_decode_u64
+ *
+ *   Delta encoding of u64 values: the first value as ULEB128, followed by the difference of each
+ *   value to its predecessor, zig-zag encoded and then ULEB128 encoded. Differences wrap around,
+ *   so every sequence can be encoded. `native_delta_decode_u64` returns none if a ULEB128 value
+ *   is truncated, overflows a u64 or is overlong.
+ *
+ *   gas cost: base_cost + per_element * number_of_values + per_byte * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeltaGasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn encode_uleb128(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn delta_encode_u64(values: &[u64]) -> Vec<u8> {
+    let mut encoded = vec![];
+    let mut prev = 0u64;
+    for (i, value) in values.iter().enumerate() {
+        if i == 0 {
+            encode_uleb128(*value, &mut encoded);
+        } else {
+            let delta = value.wrapping_sub(prev) as i64;
+            encode_uleb128(((delta << 1) ^ (delta >> 63)) as u64, &mut encoded);
+        }
+        prev = *value;
+    }
+    encoded
+}
+
+fn delta_decode_u64(mut bytes: &[u8]) -> Option<Vec<u64>> {
+    let mut decoded = vec![];
+    while !bytes.is_empty() {
+        let (value, bytes_read) = decode_uleb128(bytes);
+        let value = value?;
+        bytes = &bytes[bytes_read..];
+        match decoded.last() {
+            None => decoded.push(value),
+            Some(prev) => {
+                let delta = ((value >> 1) as i64) ^ -((value & 1) as i64);
+                decoded.push(prev.wrapping_add(delta as u64));
+            }
+        }
+    }
+    Some(decoded)
+}
+
+fn native_delta_encode_u64(
+    gas_params: &DeltaGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let values = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let encoded = delta_encode_u64(&values);
+    let cost = gas_params.base
+        + gas_params.per_element * NumArgs::new(values.len() as u64)
+        + gas_params.per_byte * NumBytes::new(encoded.len() as u64);
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(encoded)]))
+}
+
+pub fn make_native_delta_encode_u64(gas_params: DeltaGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_delta_encode_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+fn native_delta_decode_u64(
+    gas_params: &DeltaGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let decoded = delta_decode_u64(&bytes);
+    let decoded_len = decoded.as_ref().map_or(0, |decoded| decoded.len());
+    let cost = gas_params.base
+        + gas_params.per_element * NumArgs::new(decoded_len as u64)
+        + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    // make a std::option::Option<vector<u64>>
+    let decoded = Vector::pack(
+        &Type::Vector(Arc::new(Type::U64)),
+        decoded.map(Value::vector_u64).into_iter().collect(),
+    )?;
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::struct_(Struct::pack(vec![decoded]))],
+    ))
+}
+
+pub fn make_native_delta_decode_u64(gas_params: DeltaGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_delta_decode_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub base58check_decode: Base58CheckDecodeGasParameters,
+    pub rle: RleGasParameters,
+    pub delta: DeltaGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "base58check_decode",
+            make_native_base58check_decode(gas_params.base58check_decode),
+        ),
+        ("rle_encode", make_native_rle_encode(gas_params.rle.clone())),
+        ("rle_decode", make_native_rle_decode(gas_params.rle)),
+        (
+            "native_delta_encode_u64",
+            make_native_delta_encode_u64(gas_params.delta.clone()),
+        ),
+        (
+            "native_delta_decode_u64",
+            make_native_delta_decode_u64(gas_params.delta),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
@@ -3,16 +3,36 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::natives::helpers::make_module_natives;
-use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use hkdf::Hkdf;
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::{
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    language_storage::StructTag,
+    value::{MoveFieldLayout, MoveStructLayout, MoveTypeLayout, MoveValue},
+    vm_status::StatusCode,
+};
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
-    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Reference, Value, VectorRef},
 };
 use sha2::{Digest, Sha256};
-use sha3::Sha3_256;
+use sha3::{Keccak256, Sha3_256};
+use siphasher::sip::SipHasher24;
 use smallvec::smallvec;
-use std::{collections::VecDeque, sync::Arc};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    hash::Hasher,
+    sync::Arc,
+};
+use subtle::ConstantTimeEq;
+
+// Abort codes, these must match the constants in `hash.move`.
+const EINVALID_SIPHASH_KEY: u64 = 1;
+const EHKDF_LENGTH_TOO_LARGE: u64 = 2;
+const ENOT_A_STRUCT: u64 = 3;
 
 /***************************************************************************************************
  * native fun sha2_256
@@ -108,6 +128,511 @@ pub fn make_native_sha3_256(gas_params: Sha3_256GasParameters) -> NativeFunction
     )
 }
 
+/***************************************************************************************************
+ * native fun keccak_256
+ *
+ *   The original Keccak-256 as used by Ethereum, which differs from SHA3-256 in its padding.
+ *
+ *   gas cost: base_cost + unit_cost * max(input_length_in_bytes, legacy_min_input_len)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keccak256GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+    pub legacy_min_input_len: NumBytes,
+}
+
+#[inline]
+fn native_keccak_256(
+    gas_params: &Keccak256GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let hash_arg = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base
+        + gas_params.per_byte
+            * std::cmp::max(
+                NumBytes::new(hash_arg.len() as u64),
+                gas_params.legacy_min_input_len,
+            );
+
+    let hash_vec = Keccak256::digest(hash_arg.as_slice()).to_vec();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(hash_vec)],
+    ))
+}
+
+pub fn make_native_keccak_256(gas_params: Keccak256GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_keccak_256(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun blake2b_256
+ *
+ *   BLAKE2b with a 32 byte output, without a key.
+ *
+ *   gas cost: base_cost + unit_cost * max(input_length_in_bytes, legacy_min_input_len)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blake2b256GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+    pub legacy_min_input_len: NumBytes,
+}
+
+#[inline]
+fn native_blake2b_256(
+    gas_params: &Blake2b256GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let hash_arg = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base
+        + gas_params.per_byte
+            * std::cmp::max(
+                NumBytes::new(hash_arg.len() as u64),
+                gas_params.legacy_min_input_len,
+            );
+
+    let hash_vec = blake2b_simd::Params::new()
+        .hash_length(32)
+        .hash(hash_arg.as_slice())
+        .as_bytes()
+        .to_vec();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(hash_vec)],
+    ))
+}
+
+pub fn make_native_blake2b_256(gas_params: Blake2b256GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_blake2b_256(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun siphash
+ *
+ *   Computes SipHash-2-4 of `data` under a 16 byte `key`.
+ *
+ *   gas cost: base_cost + unit_cost * data_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SipHashGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_siphash(
+    gas_params: &SipHashGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let data = pop_arg!(arguments, VectorRef);
+    let key = pop_arg!(arguments, VectorRef);
+    let data = data.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(data.len() as u64);
+    let key: [u8; 16] = match key.as_bytes_ref().as_slice().try_into() {
+        Ok(key) => key,
+        Err(_) => return Ok(NativeResult::err(cost, EINVALID_SIPHASH_KEY)),
+    };
+
+    let mut hasher = SipHasher24::new_with_key(&key);
+    hasher.write(&data);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u64(hasher.finish())],
+    ))
+}
+
+pub fn make_native_siphash(gas_params: SipHashGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_siphash(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun derive_id
+ *
+ *   Derives a 16 byte id from the first 16 bytes of the SHA3-256 of `namespace || name`, with the
+ *   version and variant bits set as in a UUIDv5.
+ *
+ *   gas cost: base_cost + unit_cost * (namespace_length_in_bytes + name_length_in_bytes)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeriveIdGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_derive_id(
+    gas_params: &DeriveIdGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let name = pop_arg!(arguments, VectorRef);
+    let namespace = pop_arg!(arguments, VectorRef);
+    let name = name.as_bytes_ref();
+    let namespace = namespace.as_bytes_ref();
+
+    let cost = gas_params.base
+        + gas_params.per_byte * NumBytes::new((namespace.len() + name.len()) as u64);
+
+    let digest = Sha3_256::new()
+        .chain(namespace.as_slice())
+        .chain(name.as_slice())
+        .finalize();
+    let mut id = digest[..16].to_vec();
+    // Version 5 in the high nibble of byte 6, and the RFC 4122 variant in the top bits of byte 8.
+    id[6] = (id[6] & 0x0f) | 0x50;
+    id[8] = (id[8] & 0x3f) | 0x80;
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(id)]))
+}
+
+pub fn make_native_derive_id(gas_params: DeriveIdGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_derive_id(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun hkdf_sha256
+ *
+ *   Derives `length` bytes of key material with HKDF-SHA256 (RFC 5869). Aborts if `length`
+ *   exceeds 255 * 32, the most HKDF-SHA256 can derive.
+ *
+ *   gas cost: base_cost + unit_cost * input_length_in_bytes + output_unit_cost * length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HkdfSha256GasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+    pub per_output_byte: InternalGasPerByte,
+}
+
+fn native_hkdf_sha256(
+    gas_params: &HkdfSha256GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 4);
+
+    let length = pop_arg!(arguments, u64);
+    let info = pop_arg!(arguments, VectorRef);
+    let ikm = pop_arg!(arguments, VectorRef);
+    let salt = pop_arg!(arguments, VectorRef);
+    let info = info.as_bytes_ref();
+    let ikm = ikm.as_bytes_ref();
+    let salt = salt.as_bytes_ref();
+
+    if length > 255 * Sha256::output_size() as u64 {
+        return Ok(NativeResult::err(gas_params.base, EHKDF_LENGTH_TOO_LARGE));
+    }
+    let cost = gas_params.base
+        + gas_params.per_byte * NumBytes::new((salt.len() + ikm.len() + info.len()) as u64)
+        + gas_params.per_output_byte * NumBytes::new(length);
+
+    let mut okm = vec![0; length as usize];
+    Hkdf::<Sha256>::new(Some(&salt), &ikm)
+        .expand(&info, &mut okm)
+        .expect("length is within the bound of HKDF-SHA256");
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(okm)]))
+}
+
+pub fn make_native_hkdf_sha256(gas_params: HkdfSha256GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_hkdf_sha256(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun verify_commitment
+ *
+ *   Returns true if `commitment` is the SHA3-256 of `reveal`. The digests are compared in
+ *   constant time, and a commitment which is not 32 bytes long never matches.
+ *
+ *   gas cost: base_cost + unit_cost * reveal_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyCommitmentGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_verify_commitment(
+    gas_params: &VerifyCommitmentGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let reveal = pop_arg!(arguments, VectorRef);
+    let commitment = pop_arg!(arguments, VectorRef);
+    let reveal = reveal.as_bytes_ref();
+    let commitment = commitment.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(reveal.len() as u64);
+    let digest = Sha3_256::digest(reveal.as_slice());
+    // `ct_eq` returns false for slices of different lengths, and only leaks the lengths.
+    let valid = bool::from(digest.as_slice().ct_eq(commitment.as_slice()));
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+pub fn make_native_verify_commitment(gas_params: VerifyCommitmentGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_verify_commitment(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun typed_struct_hash
+ *
+ *   Hashes a struct value in the style of EIP-712, with Keccak-256 in place of the encoding and
+ *   type names of Solidity:
+ *
+ *     typed_struct_hash(v) = keccak256(0x19 || 0x01 || DOMAIN_SEPARATOR || hash_struct(v))
+ *     DOMAIN_SEPARATOR     = keccak256("MoveTypedStructHash")
+ *     hash_struct(s)       = keccak256(keccak256(encode_type(S)) || encode(f_1) || ... || encode(f_n))
+ *
+ *   `encode_type(S)` is `Name(type_1 name_1,...,type_n name_n)` for the fields of `S`, followed
+ *   by the same for every struct type the fields refer to, directly or transitively, sorted by
+ *   name and without duplicates. Type names are canonical, as returned by `type_name::get`, e.g.
+ *   `00000000000000000000000000000001::m::S` or `vector<u64>`. `encode` returns a 32 byte word:
+ *   - integers and booleans are big-endian and left-padded with zeros,
+ *   - addresses and signers are left-padded with zeros,
+ *   - `vector<u8>` is `keccak256` of its bytes, other vectors are `keccak256` of the
+ *     concatenated encodings of their elements,
+ *   - structs are `hash_struct` of the struct.
+ *
+ *   gas cost: base_cost + unit_cost * bytes_hashed
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedStructHashGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+const TYPED_STRUCT_HASH_DOMAIN: &[u8] = b"MoveTypedStructHash";
+
+fn layout_mismatch() -> PartialVMError {
+    PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+        .with_message("value does not match its annotated layout".to_string())
+}
+
+fn annotated_struct(
+    layout: &MoveStructLayout,
+) -> PartialVMResult<(&StructTag, &[MoveFieldLayout])> {
+    match layout {
+        MoveStructLayout::WithTypes { type_, fields } => Ok((type_, fields)),
+        _ => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+            .with_message("expected a fully annotated struct layout".to_string())),
+    }
+}
+
+fn layout_type_name(layout: &MoveTypeLayout) -> PartialVMResult<String> {
+    Ok(match layout {
+        MoveTypeLayout::Bool => "bool".to_string(),
+        MoveTypeLayout::U8 => "u8".to_string(),
+        MoveTypeLayout::U16 => "u16".to_string(),
+        MoveTypeLayout::U32 => "u32".to_string(),
+        MoveTypeLayout::U64 => "u64".to_string(),
+        MoveTypeLayout::U128 => "u128".to_string(),
+        MoveTypeLayout::U256 => "u256".to_string(),
+        MoveTypeLayout::Address => "address".to_string(),
+        MoveTypeLayout::Signer => "signer".to_string(),
+        MoveTypeLayout::Vector(elem) => format!("vector<{}>", layout_type_name(elem)?),
+        MoveTypeLayout::Struct(s) => annotated_struct(s)?.0.to_canonical_string(),
+    })
+}
+
+// Collects `Name(type_1 name_1,...)` for the struct and all struct types it refers to, by name.
+fn collect_struct_types(
+    layout: &MoveTypeLayout,
+    types: &mut BTreeMap<String, String>,
+) -> PartialVMResult<()> {
+    match layout {
+        MoveTypeLayout::Vector(elem) => collect_struct_types(elem, types),
+        MoveTypeLayout::Struct(s) => {
+            let (tag, fields) = annotated_struct(s)?;
+            let name = tag.to_canonical_string();
+            if types.contains_key(&name) {
+                return Ok(());
+            }
+            let members = fields
+                .iter()
+                .map(|f| Ok(format!("{} {}", layout_type_name(&f.layout)?, f.name)))
+                .collect::<PartialVMResult<Vec<_>>>()?;
+            types.insert(name.clone(), format!("{}({})", name, members.join(",")));
+            for field in fields {
+                collect_struct_types(&field.layout, types)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Computes the hashes of the typed struct hash, keeping track of the number of bytes hashed.
+#[derive(Default)]
+struct TypedStructHasher {
+    bytes_hashed: usize,
+}
+
+impl TypedStructHasher {
+    fn keccak256(&mut self, data: &[u8]) -> [u8; 32] {
+        self.bytes_hashed += data.len();
+        Keccak256::digest(data).into()
+    }
+
+    fn hash_struct(
+        &mut self,
+        layout: &MoveStructLayout,
+        fields: &[MoveValue],
+    ) -> PartialVMResult<[u8; 32]> {
+        let (tag, field_layouts) = annotated_struct(layout)?;
+        if field_layouts.len() != fields.len() {
+            return Err(layout_mismatch());
+        }
+        let mut types = BTreeMap::new();
+        collect_struct_types(&MoveTypeLayout::Struct(layout.clone()), &mut types)?;
+        // The type of the struct itself comes first, then the ones it refers to.
+        let mut encoded_type = types
+            .remove(&tag.to_canonical_string())
+            .ok_or_else(layout_mismatch)?;
+        encoded_type.extend(types.into_values());
+
+        let mut data = self.keccak256(encoded_type.as_bytes()).to_vec();
+        for (field_layout, field) in field_layouts.iter().zip(fields) {
+            data.extend(self.encode(&field_layout.layout, field)?);
+        }
+        Ok(self.keccak256(&data))
+    }
+
+    fn encode(&mut self, layout: &MoveTypeLayout, value: &MoveValue) -> PartialVMResult<[u8; 32]> {
+        fn word(bytes_be: &[u8]) -> [u8; 32] {
+            let mut word = [0u8; 32];
+            word[32 - bytes_be.len()..].copy_from_slice(bytes_be);
+            word
+        }
+        Ok(match (layout, value) {
+            (MoveTypeLayout::Bool, MoveValue::Bool(b)) => word(&[*b as u8]),
+            (MoveTypeLayout::U8, MoveValue::U8(x)) => word(&[*x]),
+            (MoveTypeLayout::U16, MoveValue::U16(x)) => word(&x.to_be_bytes()),
+            (MoveTypeLayout::U32, MoveValue::U32(x)) => word(&x.to_be_bytes()),
+            (MoveTypeLayout::U64, MoveValue::U64(x)) => word(&x.to_be_bytes()),
+            (MoveTypeLayout::U128, MoveValue::U128(x)) => word(&x.to_be_bytes()),
+            (MoveTypeLayout::U256, MoveValue::U256(x)) => {
+                let mut bytes = x.to_le_bytes();
+                bytes.reverse();
+                bytes
+            }
+            (MoveTypeLayout::Address, MoveValue::Address(a))
+            | (MoveTypeLayout::Signer, MoveValue::Signer(a)) => word(a.as_ref()),
+            (MoveTypeLayout::Vector(elem), MoveValue::Vector(elems)) => {
+                let mut data = vec![];
+                for e in elems {
+                    match (elem.as_ref(), e) {
+                        (MoveTypeLayout::U8, MoveValue::U8(byte)) => data.push(*byte),
+                        _ => data.extend(self.encode(elem, e)?),
+                    }
+                }
+                self.keccak256(&data)
+            }
+            (MoveTypeLayout::Struct(s), MoveValue::Struct(v)) => self.hash_struct(s, v.fields())?,
+            _ => return Err(layout_mismatch()),
+        })
+    }
+}
+
+fn native_typed_struct_hash(
+    gas_params: &TypedStructHashGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert!(arguments.len() == 1);
+
+    let value = pop_arg!(arguments, Reference).read_ref()?;
+    let ty = &ty_args[0];
+    let (layout, annotated_layout) = match (
+        context.type_to_type_layout(ty)?,
+        context.type_to_fully_annotated_layout(ty)?,
+    ) {
+        (Some(layout), Some(MoveTypeLayout::Struct(annotated))) => (layout, annotated),
+        _ => return Ok(NativeResult::err(gas_params.base, ENOT_A_STRUCT)),
+    };
+    let fields = match value.as_move_value(&layout) {
+        MoveValue::Struct(s) => s.into_fields(),
+        _ => return Err(layout_mismatch()),
+    };
+
+    let mut hasher = TypedStructHasher::default();
+    let struct_hash = hasher.hash_struct(&annotated_layout, &fields)?;
+    let domain_separator = hasher.keccak256(TYPED_STRUCT_HASH_DOMAIN);
+    let mut data = vec![0x19, 0x01];
+    data.extend(domain_separator);
+    data.extend(struct_hash);
+    let hash = hasher.keccak256(&data);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(hasher.bytes_hashed as u64);
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(hash)]))
+}
+
+pub fn make_native_typed_struct_hash(gas_params: TypedStructHashGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_typed_struct_hash(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -115,12 +640,38 @@ pub fn make_native_sha3_256(gas_params: Sha3_256GasParameters) -> NativeFunction
 pub struct GasParameters {
     pub sha2_256: Sha2_256GasParameters,
     pub sha3_256: Sha3_256GasParameters,
+    pub keccak_256: Keccak256GasParameters,
+    pub blake2b_256: Blake2b256GasParameters,
+    pub siphash: SipHashGasParameters,
+    pub derive_id: DeriveIdGasParameters,
+    pub hkdf_sha256: HkdfSha256GasParameters,
+    pub verify_commitment: VerifyCommitmentGasParameters,
+    pub typed_struct_hash: TypedStructHashGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
     let natives = [
         ("sha2_256", make_native_sha2_256(gas_params.sha2_256)),
         ("sha3_256", make_native_sha3_256(gas_params.sha3_256)),
+        ("keccak_256", make_native_keccak_256(gas_params.keccak_256)),
+        (
+            "blake2b_256",
+            make_native_blake2b_256(gas_params.blake2b_256),
+        ),
+        ("siphash", make_native_siphash(gas_params.siphash)),
+        ("derive_id", make_native_derive_id(gas_params.derive_id)),
+        (
+            "hkdf_sha256",
+            make_native_hkdf_sha256(gas_params.hkdf_sha256),
+        ),
+        (
+            "verify_commitment",
+            make_native_verify_commitment(gas_params.verify_commitment),
+        ),
+        (
+            "typed_struct_hash",
+            make_native_typed_struct_hash(gas_params.typed_struct_hash),
+        ),
     ];
 
     make_module_natives(natives)
@@ -0,0 +1,788 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for integer math.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{
+    gas_algebra::{InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes},
+    u256::U256,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, Vector, VectorRef},
+};
+use num_bigint::{BigInt, Sign};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Abort codes, these must match the constants in `math.move`.
+const EOVERFLOW: u64 = 1;
+const EZERO: u64 = 2;
+
+/***************************************************************************************************
+ * native fun rotl_u64, rotr_u64, rotl_u128, rotr_u128, rotl_u256, rotr_u256
+ *
+ *   Rotates the bits of `x` by `n` positions, where `n` is taken modulo the bit width of `x`.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotateGasParameters {
+    pub base: InternalGas,
+}
+
+fn rotate_left_u256(x: U256, n: u32) -> U256 {
+    let n = n % 256;
+    if n == 0 {
+        return x;
+    }
+    // Both shifts are within `1..256`, so they never fail.
+    x.checked_shl(n).unwrap() | x.checked_shr(256 - n).unwrap()
+}
+
+fn native_rotl_u64(
+    gas_params: &RotateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let n = pop_arg!(args, u32);
+    let x = pop_arg!(args, u64);
+    NativeResult::map_partial_vm_result_one(
+        gas_params.base,
+        Ok(Value::u64(x.rotate_left(n % u64::BITS))),
+    )
+}
+
+fn native_rotr_u64(
+    gas_params: &RotateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let n = pop_arg!(args, u32);
+    let x = pop_arg!(args, u64);
+    NativeResult::map_partial_vm_result_one(
+        gas_params.base,
+        Ok(Value::u64(x.rotate_right(n % u64::BITS))),
+    )
+}
+
+fn native_rotl_u128(
+    gas_params: &RotateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let n = pop_arg!(args, u32);
+    let x = pop_arg!(args, u128);
+    NativeResult::map_partial_vm_result_one(
+        gas_params.base,
+        Ok(Value::u128(x.rotate_left(n % u128::BITS))),
+    )
+}
+
+fn native_rotr_u128(
+    gas_params: &RotateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let n = pop_arg!(args, u32);
+    let x = pop_arg!(args, u128);
+    NativeResult::map_partial_vm_result_one(
+        gas_params.base,
+        Ok(Value::u128(x.rotate_right(n % u128::BITS))),
+    )
+}
+
+fn native_rotl_u256(
+    gas_params: &RotateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let n = pop_arg!(args, u32);
+    let x = pop_arg!(args, U256);
+    NativeResult::map_partial_vm_result_one(
+        gas_params.base,
+        Ok(Value::u256(rotate_left_u256(x, n))),
+    )
+}
+
+fn native_rotr_u256(
+    gas_params: &RotateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let n = pop_arg!(args, u32);
+    let x = pop_arg!(args, U256);
+    // Rotating right by `n` is the same as rotating left by `256 - n`.
+    NativeResult::map_partial_vm_result_one(
+        gas_params.base,
+        Ok(Value::u256(rotate_left_u256(x, 256 - n % 256))),
+    )
+}
+
+pub fn make_native_rotl_u64(gas_params: RotateGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rotl_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_rotr_u64(gas_params: RotateGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rotr_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_rotl_u128(gas_params: RotateGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rotl_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_rotr_u128(gas_params: RotateGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rotr_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_rotl_u256(gas_params: RotateGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rotl_u256(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_rotr_u256(gas_params: RotateGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_rotr_u256(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun gcd_u128, lcm_u128
+ *
+ *   gas cost: base_cost + per_iteration * number_of_euclid_iterations
+ *
+ *   The number of iterations of Euclid's algorithm is bounded by the bit length of the inputs.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcdGasParameters {
+    pub base: InternalGas,
+    pub per_iteration: InternalGasPerArg,
+}
+
+/// Returns the greatest common divisor of `a` and `b`, along with the number of iterations taken.
+fn gcd_u128(mut a: u128, mut b: u128) -> (u128, u64) {
+    let mut iterations = 0;
+    while b != 0 {
+        (a, b) = (b, a % b);
+        iterations += 1;
+    }
+    (a, iterations)
+}
+
+fn native_gcd_u128(
+    gas_params: &GcdGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+
+    let (gcd, iterations) = gcd_u128(a, b);
+    let cost = gas_params.base + gas_params.per_iteration * NumArgs::new(iterations);
+    Ok(NativeResult::ok(cost, smallvec![Value::u128(gcd)]))
+}
+
+fn native_lcm_u128(
+    gas_params: &GcdGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+
+    let (gcd, iterations) = gcd_u128(a, b);
+    let cost = gas_params.base + gas_params.per_iteration * NumArgs::new(iterations);
+    if gcd == 0 {
+        // Both inputs are zero.
+        return Ok(NativeResult::ok(cost, smallvec![Value::u128(0)]));
+    }
+    match (a / gcd).checked_mul(b) {
+        Some(lcm) => Ok(NativeResult::ok(cost, smallvec![Value::u128(lcm)])),
+        None => Ok(NativeResult::err(cost, EOVERFLOW)),
+    }
+}
+
+pub fn make_native_gcd_u128(gas_params: GcdGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_gcd_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_lcm_u128(gas_params: GcdGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_lcm_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun sqrt_u128
+ *
+ *   gas cost: base_cost + per_bit * bit_length_of_x
+ *
+ *   Newton's method starts from a power of two above the root, so the number of iterations is
+ *   bounded by the bit length of `x`.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqrtGasParameters {
+    pub base: InternalGas,
+    pub per_bit: InternalGasPerArg,
+}
+
+/// Returns the floor of the square root of `x`.
+fn sqrt_u128(x: u128) -> u128 {
+    if x < 2 {
+        return x;
+    }
+    // 2^ceil(bits / 2) is above the root, and the iteration decreases until it reaches the floor.
+    let bits = u128::BITS - x.leading_zeros();
+    let mut root = 1u128 << ((bits + 1) / 2);
+    loop {
+        let next = (root + x / root) / 2;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+fn native_sqrt_u128(
+    gas_params: &SqrtGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let x = pop_arg!(args, u128);
+
+    let bits = u128::BITS - x.leading_zeros();
+    let cost = gas_params.base + gas_params.per_bit * NumArgs::new(bits as u64);
+    NativeResult::map_partial_vm_result_one(cost, Ok(Value::u128(sqrt_u128(x))))
+}
+
+pub fn make_native_sqrt_u128(gas_params: SqrtGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_sqrt_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun abs_diff_u64, abs_diff_u128, abs_diff_u256
+ *
+ *   Returns `max(a, b) - min(a, b)`, which never underflows.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbsDiffGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_abs_diff_u64(
+    gas_params: &AbsDiffGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let b = pop_arg!(args, u64);
+    let a = pop_arg!(args, u64);
+    let diff = a.abs_diff(b);
+    NativeResult::map_partial_vm_result_one(gas_params.base, Ok(Value::u64(diff)))
+}
+
+fn native_abs_diff_u128(
+    gas_params: &AbsDiffGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+    let diff = a.abs_diff(b);
+    NativeResult::map_partial_vm_result_one(gas_params.base, Ok(Value::u128(diff)))
+}
+
+fn native_abs_diff_u256(
+    gas_params: &AbsDiffGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let b = pop_arg!(args, U256);
+    let a = pop_arg!(args, U256);
+    let diff = if a > b { a - b } else { b - a };
+    NativeResult::map_partial_vm_result_one(gas_params.base, Ok(Value::u256(diff)))
+}
+
+pub fn make_native_abs_diff_u64(gas_params: AbsDiffGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_abs_diff_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_abs_diff_u128(gas_params: AbsDiffGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_abs_diff_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_abs_diff_u256(gas_params: AbsDiffGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_abs_diff_u256(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun pack_u64_pair, unpack_u64_pair
+ *
+ *   Packs `hi` into the upper 64 bits and `lo` into the lower 64 bits of a u128, and back.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct U64PairGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_pack_u64_pair(
+    gas_params: &U64PairGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let lo = pop_arg!(args, u64);
+    let hi = pop_arg!(args, u64);
+    let packed = ((hi as u128) << 64) | lo as u128;
+    NativeResult::map_partial_vm_result_one(gas_params.base, Ok(Value::u128(packed)))
+}
+
+fn native_unpack_u64_pair(
+    gas_params: &U64PairGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let x = pop_arg!(args, u128);
+    let (hi, lo) = ((x >> 64) as u64, x as u64);
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(hi), Value::u64(lo)],
+    ))
+}
+
+pub fn make_native_pack_u64_pair(gas_params: U64PairGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_pack_u64_pair(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn make_native_unpack_u64_pair(gas_params: U64PairGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_unpack_u64_pair(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun log2_u128
+ *
+ *   Returns the floor of the base 2 logarithm of `x`, which is the index of its highest set bit.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Log2GasParameters {
+    pub base: InternalGas,
+}
+
+fn native_log2_u128(
+    gas_params: &Log2GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let x = pop_arg!(args, u128);
+    if x == 0 {
+        return Ok(NativeResult::err(gas_params.base, EZERO));
+    }
+    let log2 = u128::BITS - 1 - x.leading_zeros();
+    NativeResult::map_partial_vm_result_one(gas_params.base, Ok(Value::u64(log2 as u64)))
+}
+
+pub fn make_native_log2_u128(gas_params: Log2GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_log2_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun next_power_of_two_u64
+ *
+ *   Returns the smallest power of two greater than or equal to `x`, where the result for 0 is 1.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextPowerOfTwoGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_next_power_of_two_u64(
+    gas_params: &NextPowerOfTwoGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let x = pop_arg!(args, u64);
+    match x.checked_next_power_of_two() {
+        Some(pow2) => Ok(NativeResult::ok(
+            gas_params.base,
+            smallvec![Value::u64(pow2)],
+        )),
+        None => Ok(NativeResult::err(gas_params.base, EOVERFLOW)),
+    }
+}
+
+pub fn make_native_next_power_of_two_u64(
+    gas_params: NextPowerOfTwoGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_next_power_of_two_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun floor_div_u128, ceil_div_u128
+ *
+ *   Divides `a` by `b`, rounding down or up. Both abort if `b` is 0.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DivGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_floor_div_u128(
+    gas_params: &DivGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+    if b == 0 {
+        return Ok(NativeResult::err(gas_params.base, EZERO));
+    }
+    NativeResult::map_partial_vm_result_one(gas_params.base, Ok(Value::u128(a / b)))
+}
+
+pub fn make_native_floor_div_u128(gas_params: DivGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_floor_div_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+fn native_ceil_div_u128(
+    gas_params: &DivGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+    if b == 0 {
+        return Ok(NativeResult::err(gas_params.base, EZERO));
+    }
+    // Unlike `(a + b - 1) / b`, this cannot overflow.
+    let quotient = a / b + (a % b != 0) as u128;
+    NativeResult::map_partial_vm_result_one(gas_params.base, Ok(Value::u128(quotient)))
+}
+
+pub fn make_native_ceil_div_u128(gas_params: DivGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_ceil_div_u128(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun bigint_add
+ *
+ *   Adds two unsigned integers of arbitrary size, given as little-endian bytes. The sum has no
+ *   trailing zero bytes, so zero is the empty vector.
+ *
+ *   gas cost: base_cost + unit_cost * max(length_of_a, length_of_b)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigIntAddGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn bigint_add(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u16;
+    for (i, digit) in long.iter().enumerate() {
+        let digit_sum = *digit as u16 + *short.get(i).unwrap_or(&0) as u16 + carry;
+        sum.push(digit_sum as u8);
+        carry = digit_sum >> 8;
+    }
+    sum.push(carry as u8);
+    while sum.last() == Some(&0) {
+        sum.pop();
+    }
+    sum
+}
+
+fn native_bigint_add(
+    gas_params: &BigIntAddGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let b = pop_arg!(args, VectorRef);
+    let a = pop_arg!(args, VectorRef);
+    let (a, b) = (a.as_bytes_ref(), b.as_bytes_ref());
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(a.len().max(b.len()) as u64);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(bigint_add(&a, &b))],
+    ))
+}
+
+pub fn make_native_bigint_add(gas_params: BigIntAddGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_bigint_add(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun mod_inverse_u256
+ *
+ *   Returns the inverse of `a` modulo `modulus`, computed with the extended Euclidean algorithm,
+ *   or none if `a` and `modulus` are not coprime. Aborts if `modulus` is 0.
+ *
+ *   gas cost: base_cost + per_bit * bit_length_of_modulus
+ *
+ *   The number of iterations of the algorithm is bounded by the bit length of the modulus.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModInverseGasParameters {
+    pub base: InternalGas,
+    pub per_bit: InternalGasPerArg,
+}
+
+/// Returns the inverse of `a` modulo `modulus`, which must not be 0.
+fn mod_inverse_u256(a: U256, modulus: U256) -> Option<U256> {
+    let to_bigint = |x: U256| BigInt::from_bytes_le(Sign::Plus, &x.to_le_bytes());
+    let m = to_bigint(modulus);
+    // Invariant: `r0 = t0 * a (mod m)` and `r1 = t1 * a (mod m)`.
+    let (mut r0, mut r1) = (m.clone(), to_bigint(a) % &m);
+    let (mut t0, mut t1) = (BigInt::from(0u8), BigInt::from(1u8));
+    while r1 != BigInt::from(0u8) {
+        let q = &r0 / &r1;
+        (r0, r1) = (r1.clone(), r0 - &q * &r1);
+        (t0, t1) = (t1.clone(), t0 - &q * &t1);
+    }
+    // `r0` is the gcd of `a` and `m`.
+    if r0 != BigInt::from(1u8) {
+        return None;
+    }
+    let (_, inverse) = ((t0 % &m + &m) % &m).to_bytes_le();
+    let mut bytes = [0u8; 32];
+    bytes[..inverse.len()].copy_from_slice(&inverse);
+    Some(U256::from_le_bytes(&bytes))
+}
+
+fn native_mod_inverse_u256(
+    gas_params: &ModInverseGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 2);
+    let modulus = pop_arg!(args, U256);
+    let a = pop_arg!(args, U256);
+
+    let bit_length = 256 - modulus.leading_zeros() as u64;
+    let cost = gas_params.base + gas_params.per_bit * NumArgs::new(bit_length);
+    if modulus == U256::zero() {
+        return Ok(NativeResult::err(cost, EZERO));
+    }
+
+    // make a std::option::Option<u256>
+    let option_val = Value::struct_(Struct::pack(vec![Vector::pack(
+        &Type::U256,
+        mod_inverse_u256(a, modulus)
+            .map(Value::u256)
+            .into_iter()
+            .collect(),
+    )?]));
+    Ok(NativeResult::ok(cost, smallvec![option_val]))
+}
+
+pub fn make_native_mod_inverse_u256(gas_params: ModInverseGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_mod_inverse_u256(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub rotate: RotateGasParameters,
+    pub gcd: GcdGasParameters,
+    pub abs_diff: AbsDiffGasParameters,
+    pub sqrt: SqrtGasParameters,
+    pub u64_pair: U64PairGasParameters,
+    pub log2: Log2GasParameters,
+    pub next_power_of_two: NextPowerOfTwoGasParameters,
+    pub bigint_add: BigIntAddGasParameters,
+    pub div: DivGasParameters,
+    pub mod_inverse: ModInverseGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("rotl_u64", make_native_rotl_u64(gas_params.rotate.clone())),
+        ("rotr_u64", make_native_rotr_u64(gas_params.rotate.clone())),
+        ("rotl_u128", make_native_rotl_u128(gas_params.rotate.clone())),
+        ("rotr_u128", make_native_rotr_u128(gas_params.rotate.clone())),
+        ("rotl_u256", make_native_rotl_u256(gas_params.rotate.clone())),
+        ("rotr_u256", make_native_rotr_u256(gas_params.rotate)),
+        ("gcd_u128", make_native_gcd_u128(gas_params.gcd.clone())),
+        ("lcm_u128", make_native_lcm_u128(gas_params.gcd)),
+        (
+            "abs_diff_u64",
+            make_native_abs_diff_u64(gas_params.abs_diff.clone()),
+        ),
+        (
+            "abs_diff_u128",
+            make_native_abs_diff_u128(gas_params.abs_diff.clone()),
+        ),
+        (
+            "abs_diff_u256",
+            make_native_abs_diff_u256(gas_params.abs_diff),
+        ),
+        ("sqrt_u128", make_native_sqrt_u128(gas_params.sqrt)),
+        (
+            "pack_u64_pair",
+            make_native_pack_u64_pair(gas_params.u64_pair.clone()),
+        ),
+        (
+            "unpack_u64_pair",
+            make_native_unpack_u64_pair(gas_params.u64_pair),
+        ),
+        ("log2_u128", make_native_log2_u128(gas_params.log2)),
+        (
+            "next_power_of_two_u64",
+            make_native_next_power_of_two_u64(gas_params.next_power_of_two),
+        ),
+        ("bigint_add", make_native_bigint_add(gas_params.bigint_add)),
+        (
+            "floor_div_u128",
+            make_native_floor_div_u128(gas_params.div.clone()),
+        ),
+        ("ceil_div_u128", make_native_ceil_div_u128(gas_params.div)),
+        (
+            "mod_inverse_u256",
+            make_native_mod_inverse_u256(gas_params.mod_inverse),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
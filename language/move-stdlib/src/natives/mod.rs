@@ -2,15 +2,28 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod account;
 pub mod bcs;
+pub mod bloom;
+pub mod bls;
+pub mod bytes;
+pub mod chain;
 pub mod debug;
+pub mod encoding;
 pub mod event;
 pub mod hash;
+pub mod math;
+pub mod poseidon;
+pub mod rlp;
+pub mod signature;
 pub mod signer;
 pub mod string;
+pub mod timestamp;
+pub mod type_info;
 pub mod type_name;
 #[cfg(feature = "testing")]
 pub mod unit_test;
+pub mod util;
 pub mod vector;
 
 mod helpers;
@@ -20,11 +33,24 @@ use move_vm_runtime::native_functions::{make_table_from_iter, NativeFunctionTabl
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GasParameters {
+    pub account: account::GasParameters,
     pub bcs: bcs::GasParameters,
+    pub bloom: bloom::GasParameters,
+    pub bls: bls::GasParameters,
+    pub bytes: bytes::GasParameters,
+    pub chain: chain::GasParameters,
+    pub encoding: encoding::GasParameters,
     pub hash: hash::GasParameters,
+    pub math: math::GasParameters,
+    pub poseidon: poseidon::GasParameters,
+    pub rlp: rlp::GasParameters,
+    pub signature: signature::GasParameters,
     pub signer: signer::GasParameters,
     pub string: string::GasParameters,
+    pub timestamp: timestamp::GasParameters,
+    pub type_info: type_info::GasParameters,
     pub type_name: type_name::GasParameters,
+    pub util: util::GasParameters,
     pub vector: vector::GasParameters,
 
     #[cfg(feature = "testing")]
@@ -34,6 +60,13 @@ pub struct GasParameters {
 impl GasParameters {
     pub fn zeros() -> Self {
         Self {
+            account: account::GasParameters {
+                address_from_hex: account::AddressFromHexGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                create_signer: account::CreateSignerGasParameters { base: 0.into() },
+            },
             bcs: bcs::GasParameters {
                 to_bytes: bcs::ToBytesGasParameters {
                     per_byte_serialized: 0.into(),
@@ -44,6 +77,83 @@ impl GasParameters {
                     base: 0.into(),
                     per_byte: 0.into(),
                 },
+                canonicalize: bcs::CanonicalizeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                from_bytes: bcs::FromBytesGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                    per_value: 0.into(),
+                },
+                serialized_size: bcs::SerializedSizeGasParameters {
+                    base: 0.into(),
+                    per_byte_serialized: 0.into(),
+                    failure: 0.into(),
+                },
+            },
+
+            bloom: bloom::GasParameters {
+                bloom: bloom::BloomGasParameters {
+                    base: 0.into(),
+                    per_hash: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            bls: bls::GasParameters {
+                bls12381_verify: bls::Bls12381VerifyGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            bytes: bytes::GasParameters {
+                deinterleave: bytes::DeinterleaveGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                affix: bytes::AffixGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                read_uleb128: bytes::ReadUleb128GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                not_bytes: bytes::NotBytesGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            chain: chain::GasParameters {
+                gas_unit_price: chain::GasUnitPriceGasParameters { base: 0.into() },
+            },
+            encoding: encoding::GasParameters {
+                base58check_decode: encoding::Base58CheckDecodeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                    sha256_per_byte: 0.into(),
+                },
+                rle: encoding::RleGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                delta: encoding::DeltaGasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            poseidon: poseidon::GasParameters {
+                poseidon_hash: poseidon::PoseidonHashGasParameters {
+                    base: 0.into(),
+                    per_input: 0.into(),
+                },
+            },
+            rlp: rlp::GasParameters {
+                decode_list: rlp::DecodeListGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
             },
 
             hash: hash::GasParameters {
@@ -57,6 +167,73 @@ impl GasParameters {
                     per_byte: 0.into(),
                     legacy_min_input_len: 0.into(),
                 },
+                keccak_256: hash::Keccak256GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                    legacy_min_input_len: 0.into(),
+                },
+                blake2b_256: hash::Blake2b256GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                    legacy_min_input_len: 0.into(),
+                },
+                siphash: hash::SipHashGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                derive_id: hash::DeriveIdGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                hkdf_sha256: hash::HkdfSha256GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                    per_output_byte: 0.into(),
+                },
+                verify_commitment: hash::VerifyCommitmentGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                typed_struct_hash: hash::TypedStructHashGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            math: math::GasParameters {
+                rotate: math::RotateGasParameters { base: 0.into() },
+                gcd: math::GcdGasParameters {
+                    base: 0.into(),
+                    per_iteration: 0.into(),
+                },
+                abs_diff: math::AbsDiffGasParameters { base: 0.into() },
+                sqrt: math::SqrtGasParameters {
+                    base: 0.into(),
+                    per_bit: 0.into(),
+                },
+                u64_pair: math::U64PairGasParameters { base: 0.into() },
+                log2: math::Log2GasParameters { base: 0.into() },
+                next_power_of_two: math::NextPowerOfTwoGasParameters { base: 0.into() },
+                bigint_add: math::BigIntAddGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                div: math::DivGasParameters { base: 0.into() },
+                mod_inverse: math::ModInverseGasParameters {
+                    base: 0.into(),
+                    per_bit: 0.into(),
+                },
+            },
+            timestamp: timestamp::GasParameters {
+                now_microseconds: timestamp::NowMicrosecondsGasParameters { base: 0.into() },
+            },
+            type_info: type_info::GasParameters {
+                num_fields: type_info::NumFieldsGasParameters { base: 0.into() },
+                type_eq: type_info::TypeEqGasParameters { base: 0.into() },
+                module_hash: type_info::ModuleHashGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                has_ability: type_info::HasAbilityGasParameters { base: 0.into() },
             },
             type_name: type_name::GasParameters {
                 get: type_name::GetGasParameters {
@@ -64,6 +241,13 @@ impl GasParameters {
                     per_byte: 0.into(),
                 },
             },
+            signature: signature::GasParameters {
+                ed25519_batch_verify: signature::Ed25519BatchVerifyGasParameters {
+                    base: 0.into(),
+                    per_signature: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
             signer: signer::GasParameters {
                 borrow_address: signer::BorrowAddressGasParameters { base: 0.into() },
             },
@@ -82,6 +266,29 @@ impl GasParameters {
                     per_byte_pattern: 0.into(),
                     per_byte_searched: 0.into(),
                 },
+                char_count: string::CharCountGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                split_lines: string::SplitLinesGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                to_lowercase: string::ToLowercaseGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                to_uppercase: string::ToUppercaseGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            util: util::GasParameters {
+                assert: util::AssertGasParameters { base: 0.into() },
+                zeroize: util::ZeroizeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
             },
             vector: vector::GasParameters {
                 empty: vector::EmptyGasParameters { base: 0.into() },
@@ -110,6 +317,70 @@ impl GasParameters {
                     base: 0.into(),
                     legacy_per_abstract_memory_unit: 0.into(),
                 },
+                slice: vector::SliceGasParameters {
+                    base: 0.into(),
+                    legacy_per_abstract_memory_unit: 0.into(),
+                },
+                dot_product_u64: vector::DotProductU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                truncate: vector::TruncateGasParameters {
+                    base: 0.into(),
+                    per_element_dropped: 0.into(),
+                },
+                minmax_u64: vector::MinMaxU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                is_sorted_u64: vector::IsSortedU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                prefix_sum_u64: vector::PrefixSumU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                distinct_u64: vector::DistinctU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                weighted_median_u64: vector::WeightedMedianU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                weighted_sample: vector::WeightedSampleGasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                    per_byte: 0.into(),
+                },
+                get_2d_u64: vector::Get2dU64GasParameters { base: 0.into() },
+                stable_sort_by_key_u64: vector::StableSortByKeyU64GasParameters {
+                    base: 0.into(),
+                    per_comparison: 0.into(),
+                },
+                invert_permutation: vector::InvertPermutationGasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                apply_permutation_u64: vector::ApplyPermutationU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                percentile_u64: vector::PercentileU64GasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                },
+                index_of: vector::IndexOfGasParameters {
+                    base: 0.into(),
+                    per_comparison: 0.into(),
+                    legacy_per_abstract_memory_unit: 0.into(),
+                },
+                shuffle_indices: vector::ShuffleIndicesGasParameters {
+                    base: 0.into(),
+                    per_element: 0.into(),
+                    per_byte: 0.into(),
+                },
             },
             #[cfg(feature = "testing")]
             unit_test: unit_test::GasParameters {
@@ -122,9 +393,31 @@ impl GasParameters {
     }
 }
 
+/// Natives which can also be resolved under an older name, as `(module, old name, name)`.
+///
+/// The vector natives behind `append`, `remove` and `reverse` used to be declared under the names
+/// of these functions, which are now Move wrappers. Modules compiled against that standard
+/// library still declare the old natives.
+const NATIVE_ALIASES: &[(&str, &str, &str)] = &[
+    ("vector", "append", "native_append"),
+    ("vector", "remove", "native_remove"),
+    ("vector", "reverse", "native_reverse"),
+];
+
 pub fn all_natives(
     move_std_addr: AccountAddress,
     gas_params: GasParameters,
+) -> NativeFunctionTable {
+    all_natives_with_aliases(move_std_addr, gas_params, false)
+}
+
+/// Returns the same natives as `all_natives`. If `legacy_aliases` is set, the natives in
+/// `NATIVE_ALIASES` are also registered under their old names, so that modules compiled against
+/// an older standard library keep linking during a migration.
+pub fn all_natives_with_aliases(
+    move_std_addr: AccountAddress,
+    gas_params: GasParameters,
+    legacy_aliases: bool,
 ) -> NativeFunctionTable {
     let mut natives = vec![];
 
@@ -135,16 +428,42 @@ pub fn all_natives(
             );
         };
     }
+    add_natives!("account", account::make_all(gas_params.account));
     add_natives!("bcs", bcs::make_all(gas_params.bcs));
+    add_natives!("bloom", bloom::make_all(gas_params.bloom));
+    add_natives!("bls", bls::make_all(gas_params.bls));
+    add_natives!("bytes", bytes::make_all(gas_params.bytes));
+    add_natives!("chain", chain::make_all(gas_params.chain));
+    add_natives!("encoding", encoding::make_all(gas_params.encoding));
     add_natives!("hash", hash::make_all(gas_params.hash));
+    add_natives!("math", math::make_all(gas_params.math));
+    add_natives!("poseidon", poseidon::make_all(gas_params.poseidon));
+    add_natives!("rlp", rlp::make_all(gas_params.rlp));
+    add_natives!("signature", signature::make_all(gas_params.signature));
     add_natives!("signer", signer::make_all(gas_params.signer));
     add_natives!("string", string::make_all(gas_params.string));
+    add_natives!("timestamp", timestamp::make_all(gas_params.timestamp));
+    add_natives!("type_info", type_info::make_all(gas_params.type_info));
     add_natives!("type_name", type_name::make_all(gas_params.type_name));
+    add_natives!("util", util::make_all(gas_params.util));
     add_natives!("vector", vector::make_all(gas_params.vector));
     #[cfg(feature = "testing")]
     {
         add_natives!("unit_test", unit_test::make_all(gas_params.unit_test));
     }
+    if legacy_aliases {
+        let aliases: Vec<_> = NATIVE_ALIASES
+            .iter()
+            .map(|(module_name, old_name, name)| {
+                let (_, _, func) = natives
+                    .iter()
+                    .find(|(m, f, _)| m == module_name && f == name)
+                    .unwrap_or_else(|| panic!("alias of unknown native {}::{}", module_name, name));
+                (module_name.to_string(), old_name.to_string(), func.clone())
+            })
+            .collect();
+        natives.extend(aliases);
+    }
 
     make_table_from_iter(move_std_addr, natives)
 }
@@ -0,0 +1,289 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for the Poseidon hash over the scalar field of BN254.
+//!
+//! The parameters are those of circomlib's `Poseidon(n)` template: a width of `t = n + 1` field
+//! elements, the S-box `x^5`, 8 full rounds and 56, 57, 56 or 60 partial rounds for `t` = 2, 3,
+//! 4 or 5. The round constants and the MDS matrix are generated with the Grain LFSR of the
+//! reference implementation of the Poseidon paper (`generate_parameters_grain.sage`), for a prime
+//! field of 254 bits:
+//!
+//! - The LFSR holds 80 bits, initialized with the field type `1` (2 bits), the S-box type `0`
+//!   (4 bits), the field size `254` (12 bits), `t` (12 bits), the number of full rounds (10 bits),
+//!   the number of partial rounds (10 bits), each most significant bit first, and 30 one bits.
+//!   Every step shifts in the xor of the bits 0, 13, 23, 38, 51 and 62, and the first 160 bits are
+//!   discarded. The output bits are taken in pairs: the second bit is output if the first is 1.
+//! - The round constants are 254 bit big-endian integers of output bits, skipping those which are
+//!   not below the modulus, `t` for each round.
+//! - Then `2t` further integers, reduced modulo the modulus, give `x_0..x_t` and `y_0..y_t`, and
+//!   the MDS matrix is `M[i][j] = 1 / (x_i + y_j)`.
+//!
+//! A round adds the round constants to the state, applies the S-box to all elements in full
+//! rounds and to the first element in partial rounds, and multiplies the state by the MDS matrix.
+//! Half of the full rounds come before the partial rounds and half after. The state starts as
+//! `[0, inputs...]` and the hash is its first element after the last round.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{
+    gas_algebra::{InternalGas, InternalGasPerArg, NumArgs},
+    u256::U256,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Reference, Value, VectorRef},
+};
+use num_bigint::BigUint;
+use once_cell::sync::Lazy;
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Abort codes, these must match the constants in `poseidon.move`.
+const EINVALID_NUM_INPUTS: u64 = 1;
+const ENOT_A_FIELD_ELEMENT: u64 = 2;
+
+/// The maximal number of inputs of `poseidon_hash`.
+pub const MAX_INPUTS: usize = 4;
+
+const FIELD_SIZE_IN_BITS: u64 = 254;
+const FULL_ROUNDS: usize = 8;
+// The number of partial rounds for 1 to `MAX_INPUTS` inputs.
+const PARTIAL_ROUNDS: [usize; MAX_INPUTS] = [56, 57, 56, 60];
+
+// The order of the scalar field of BN254.
+static MODULUS: Lazy<BigUint> = Lazy::new(|| {
+    BigUint::parse_bytes(
+        b"30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+        16,
+    )
+    .unwrap()
+});
+
+// The parameters for 1 to `MAX_INPUTS` inputs.
+static PARAMETERS: Lazy<Vec<PoseidonParameters>> =
+    Lazy::new(|| (1..=MAX_INPUTS).map(PoseidonParameters::new).collect());
+
+// The Grain LFSR which generates the parameters.
+struct Grain {
+    // Bit i of the state is the i-th oldest bit.
+    state: u128,
+}
+
+impl Grain {
+    fn new(width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let fields = [
+            (1, 2),
+            (0, 4),
+            (FIELD_SIZE_IN_BITS, 12),
+            (width as u64, 12),
+            (full_rounds as u64, 10),
+            (partial_rounds as u64, 10),
+            ((1 << 30) - 1, 30),
+        ];
+        let mut grain = Self { state: 0 };
+        let mut pos = 0;
+        for (value, num_bits) in fields {
+            for i in (0..num_bits).rev() {
+                grain.state |= (((value >> i) & 1) as u128) << pos;
+                pos += 1;
+            }
+        }
+        for _ in 0..160 {
+            grain.next_bit();
+        }
+        grain
+    }
+
+    fn next_bit(&mut self) -> bool {
+        let bit = [0, 13, 23, 38, 51, 62]
+            .iter()
+            .fold(0, |acc, i| acc ^ ((self.state >> i) & 1));
+        self.state = (self.state >> 1) | (bit << 79);
+        bit == 1
+    }
+
+    fn output_bit(&mut self) -> bool {
+        loop {
+            let keep = self.next_bit();
+            let bit = self.next_bit();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    fn output_integer(&mut self) -> BigUint {
+        let mut value = BigUint::default();
+        for _ in 0..FIELD_SIZE_IN_BITS {
+            value = (value << 1u32) + (self.output_bit() as u32);
+        }
+        value
+    }
+}
+
+struct PoseidonParameters {
+    partial_rounds: usize,
+    // The `width` round constants of each round.
+    round_constants: Vec<Vec<BigUint>>,
+    mds: Vec<Vec<BigUint>>,
+}
+
+impl PoseidonParameters {
+    fn new(num_inputs: usize) -> Self {
+        let width = num_inputs + 1;
+        let partial_rounds = PARTIAL_ROUNDS[num_inputs - 1];
+        let modulus = &*MODULUS;
+        let mut grain = Grain::new(width, FULL_ROUNDS, partial_rounds);
+
+        let mut constants = vec![];
+        while constants.len() < (FULL_ROUNDS + partial_rounds) * width {
+            let constant = grain.output_integer();
+            if constant < *modulus {
+                constants.push(constant);
+            }
+        }
+        let round_constants = constants.chunks(width).map(|c| c.to_vec()).collect();
+
+        let xs_ys: Vec<_> = (0..2 * width)
+            .map(|_| grain.output_integer() % modulus)
+            .collect();
+        let (xs, ys) = xs_ys.split_at(width);
+        let exponent = modulus - 2u32;
+        let mds = xs
+            .iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| ((x + y) % modulus).modpow(&exponent, modulus))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            partial_rounds,
+            round_constants,
+            mds,
+        }
+    }
+
+    fn hash(&self, inputs: &[BigUint]) -> BigUint {
+        let modulus = &*MODULUS;
+        let sbox = |x: &BigUint| x.modpow(&BigUint::from(5u32), modulus);
+
+        let mut state: Vec<BigUint> = std::iter::once(BigUint::default())
+            .chain(inputs.iter().cloned())
+            .collect();
+        let first_partial_round = FULL_ROUNDS / 2;
+        let last_partial_round = first_partial_round + self.partial_rounds;
+        for (round, constants) in self.round_constants.iter().enumerate() {
+            for (x, c) in state.iter_mut().zip(constants) {
+                *x = (&*x + c) % modulus;
+            }
+            if (first_partial_round..last_partial_round).contains(&round) {
+                state[0] = sbox(&state[0]);
+            } else {
+                for x in state.iter_mut() {
+                    *x = sbox(x);
+                }
+            }
+            state = self
+                .mds
+                .iter()
+                .map(|row| row.iter().zip(&state).map(|(m, x)| m * x).sum::<BigUint>() % modulus)
+                .collect();
+        }
+        state.swap_remove(0)
+    }
+}
+
+fn u256_to_biguint(x: U256) -> BigUint {
+    BigUint::from_bytes_le(&x.to_le_bytes())
+}
+
+fn biguint_to_u256(x: &BigUint) -> U256 {
+    let mut bytes = [0u8; 32];
+    let le_bytes = x.to_bytes_le();
+    bytes[..le_bytes.len()].copy_from_slice(&le_bytes);
+    U256::from_le_bytes(&bytes)
+}
+
+/***************************************************************************************************
+ * native fun poseidon_hash
+ *
+ *   Returns the circomlib compatible Poseidon hash of 1 to `MAX_INPUTS` field elements.
+ *
+ *   gas cost: base_cost + per_input * number_of_inputs
+ *
+ *   The cost per input covers the rounds of the permutation, whose work grows with its width.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonHashGasParameters {
+    pub base: InternalGas,
+    pub per_input: InternalGasPerArg,
+}
+
+fn native_poseidon_hash(
+    gas_params: &PoseidonHashGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let inputs = pop_arg!(args, VectorRef);
+    let len = inputs.len(&Type::U256)?.value_as::<u64>()? as usize;
+    if len == 0 || len > MAX_INPUTS {
+        return Ok(NativeResult::err(gas_params.base, EINVALID_NUM_INPUTS));
+    }
+
+    let cost = gas_params.base + gas_params.per_input * NumArgs::new(len as u64);
+    let mut elements = Vec::with_capacity(len);
+    for i in 0..len {
+        let element = inputs
+            .borrow_elem(i, &Type::U256)?
+            .value_as::<Reference>()?
+            .read_ref()?
+            .value_as::<U256>()?;
+        let element = u256_to_biguint(element);
+        if element >= *MODULUS {
+            return Ok(NativeResult::err(cost, ENOT_A_FIELD_ELEMENT));
+        }
+        elements.push(element);
+    }
+
+    let hash = PARAMETERS[len - 1].hash(&elements);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u256(biguint_to_u256(&hash))],
+    ))
+}
+
+pub fn make_native_poseidon_hash(gas_params: PoseidonHashGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_poseidon_hash(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub poseidon_hash: PoseidonHashGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "poseidon_hash",
+        make_native_poseidon_hash(gas_params.poseidon_hash),
+    )];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,163 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for the recursive length prefix (RLP) encoding of Ethereum.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, Vector, VectorRef},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/***************************************************************************************************
+ * native fun decode_list
+ *
+ *   Decodes an RLP encoded list. Returns the payloads of its string items, and nested lists in
+ *   their encoded form, or none if the input is not a single, canonically encoded list.
+ *
+ *   gas cost: base_cost + unit_cost * input_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeListGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+// The prefix of an RLP item.
+struct Header {
+    is_list: bool,
+    header_len: usize,
+    payload_len: usize,
+}
+
+// Decodes the length of the long forms, which follows the prefix in `len_of_len` big-endian
+// bytes. Returns the length of the header and the length of the payload.
+fn decode_long_length(bytes: &[u8], len_of_len: usize) -> Option<(usize, usize)> {
+    let len_bytes = bytes.get(1..1 + len_of_len)?;
+    // The length must not have leading zeros, and shorter payloads must use the short form.
+    if len_bytes[0] == 0 || len_of_len > 8 {
+        return None;
+    }
+    let len = len_bytes
+        .iter()
+        .fold(0u64, |len, byte| (len << 8) | *byte as u64);
+    if len < 56 {
+        return None;
+    }
+    Some((1 + len_of_len, usize::try_from(len).ok()?))
+}
+
+// Decodes the header of the item at the start of `bytes`, checking that its payload is in bounds.
+fn decode_header(bytes: &[u8]) -> Option<Header> {
+    let prefix = *bytes.first()?;
+    let (is_list, header_len, payload_len) = match prefix {
+        // A single byte below 0x80 is its own encoding.
+        0x00..=0x7f => (false, 0, 1),
+        0x80..=0xb7 => (false, 1, (prefix - 0x80) as usize),
+        0xb8..=0xbf => {
+            let (header_len, payload_len) = decode_long_length(bytes, (prefix - 0xb7) as usize)?;
+            (false, header_len, payload_len)
+        }
+        0xc0..=0xf7 => (true, 1, (prefix - 0xc0) as usize),
+        0xf8..=0xff => {
+            let (header_len, payload_len) = decode_long_length(bytes, (prefix - 0xf7) as usize)?;
+            (true, header_len, payload_len)
+        }
+    };
+    if bytes.len() - header_len < payload_len {
+        return None;
+    }
+    // A single byte below 0x80 must not be encoded as a string of length one.
+    if prefix == 0x81 && bytes[1] < 0x80 {
+        return None;
+    }
+    Some(Header {
+        is_list,
+        header_len,
+        payload_len,
+    })
+}
+
+fn rlp_decode_list(bytes: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let header = decode_header(bytes)?;
+    if !header.is_list || header.header_len + header.payload_len != bytes.len() {
+        return None;
+    }
+    let mut payload = &bytes[header.header_len..];
+    let mut items = vec![];
+    while !payload.is_empty() {
+        let item = decode_header(payload)?;
+        let item_len = item.header_len + item.payload_len;
+        // Nested lists keep their header, so that they can be decoded in turn.
+        let start = if item.is_list { 0 } else { item.header_len };
+        items.push(payload[start..item_len].to_vec());
+        payload = &payload[item_len..];
+    }
+    Some(items)
+}
+
+fn native_decode_list(
+    gas_params: &DecodeListGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    let byte_vector_ty = Type::Vector(Arc::new(Type::U8));
+    let items = rlp_decode_list(&bytes)
+        .map(|items| {
+            Vector::pack(
+                &byte_vector_ty,
+                items.into_iter().map(Value::vector_u8).collect(),
+            )
+        })
+        .transpose()?;
+    // make a std::option::Option<vector<vector<u8>>>
+    let items = Vector::pack(
+        &Type::Vector(Arc::new(byte_vector_ty)),
+        items.into_iter().collect(),
+    )?;
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::struct_(Struct::pack(vec![items]))],
+    ))
+}
+
+pub fn make_native_decode_list(gas_params: DecodeListGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_decode_list(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub decode_list: DecodeListGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "decode_list",
+        make_native_decode_list(gas_params.decode_list),
+    )];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,124 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for Ed25519 signatures.
+
+use crate::natives::helpers::make_module_natives;
+use ed25519_dalek::{Signature, VerifyingKey};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{
+    InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Reference, Value},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Reads a `&vector<vector<u8>>` argument.
+fn read_byte_vectors(reference: Reference) -> PartialVMResult<Vec<Vec<u8>>> {
+    reference
+        .read_ref()?
+        .value_as::<Vec<Value>>()?
+        .into_iter()
+        .map(|bytes| bytes.value_as::<Vec<u8>>())
+        .collect()
+}
+
+/***************************************************************************************************
+ * native fun ed25519_batch_verify
+ *
+ *   Verifies that each signature is valid for the message and public key at the same index, with
+ *   a single batched check. Malformed signatures and public keys, vectors of different lengths, or
+ *   an empty batch fail verification.
+ *
+ *   gas cost: base_cost + per_signature_cost * num_signatures
+ *             + unit_cost * total_message_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ed25519BatchVerifyGasParameters {
+    pub base: InternalGas,
+    pub per_signature: InternalGasPerArg,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn ed25519_batch_verify(
+    signatures: &[Vec<u8>],
+    public_keys: &[Vec<u8>],
+    messages: &[Vec<u8>],
+) -> bool {
+    // An empty batch vouches for nothing, so it is not accepted.
+    if signatures.is_empty()
+        || signatures.len() != public_keys.len()
+        || signatures.len() != messages.len()
+    {
+        return false;
+    }
+    let signatures: Result<Vec<_>, _> = signatures
+        .iter()
+        .map(|signature| Signature::from_slice(signature))
+        .collect();
+    let public_keys: Result<Vec<_>, _> = public_keys
+        .iter()
+        .map(|public_key| VerifyingKey::try_from(public_key.as_slice()))
+        .collect();
+    let (signatures, public_keys) = match (signatures, public_keys) {
+        (Ok(signatures), Ok(public_keys)) => (signatures, public_keys),
+        _ => return false,
+    };
+    let messages: Vec<&[u8]> = messages.iter().map(|message| message.as_slice()).collect();
+    ed25519_dalek::verify_batch(&messages, &signatures, &public_keys).is_ok()
+}
+
+fn native_ed25519_batch_verify(
+    gas_params: &Ed25519BatchVerifyGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let messages = read_byte_vectors(pop_arg!(args, Reference))?;
+    let public_keys = read_byte_vectors(pop_arg!(args, Reference))?;
+    let signatures = read_byte_vectors(pop_arg!(args, Reference))?;
+
+    let message_bytes: usize = messages.iter().map(|message| message.len()).sum();
+    let cost = gas_params.base
+        + gas_params.per_signature * NumArgs::new(signatures.len() as u64)
+        + gas_params.per_byte * NumBytes::new(message_bytes as u64);
+    let valid = ed25519_batch_verify(&signatures, &public_keys, &messages);
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+pub fn make_native_ed25519_batch_verify(
+    gas_params: Ed25519BatchVerifyGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_ed25519_batch_verify(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub ed25519_batch_verify: Ed25519BatchVerifyGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "ed25519_batch_verify",
+        make_native_ed25519_batch_verify(gas_params.ed25519_batch_verify),
+    )];
+
+    make_module_natives(natives)
+}
@@ -6,13 +6,16 @@
 
 use crate::natives::helpers::make_module_natives;
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_core_types::{
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    vm_status::sub_status::NFE_STRING_INVALID_ARG_FAILURE,
+};
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
     pop_arg,
-    values::{Value, VectorRef},
+    values::{Value, Vector, VectorRef},
 };
 use std::{collections::VecDeque, sync::Arc};
 
@@ -120,8 +123,10 @@ fn native_sub_string(
     let i = pop_arg!(args, u64) as usize;
 
     if j < i {
-        // TODO: what abort code should we use here?
-        return Ok(NativeResult::err(gas_params.base, 1));
+        return Ok(NativeResult::err(
+            gas_params.base,
+            NFE_STRING_INVALID_ARG_FAILURE,
+        ));
     }
 
     let s_arg = pop_arg!(args, VectorRef);
@@ -130,6 +135,13 @@ fn native_sub_string(
         // This is safe because we guarantee the bytes to be utf8.
         std::str::from_utf8_unchecked(s_ref.as_slice())
     };
+    // Slicing would panic on indices past the end or inside of a multi-byte char.
+    if j > s_str.len() || !s_str.is_char_boundary(i) || !s_str.is_char_boundary(j) {
+        return Ok(NativeResult::err(
+            gas_params.base,
+            NFE_STRING_INVALID_ARG_FAILURE,
+        ));
+    }
     let v = Value::vector_u8(s_str[i..j].as_bytes().iter().cloned());
 
     let cost = gas_params.base + gas_params.per_byte * NumBytes::new((j - i) as u64);
@@ -147,7 +159,11 @@ pub fn make_native_sub_string(gas_params: SubStringGasParameters) -> NativeFunct
 /***************************************************************************************************
  * native fun internal_index_of
  *
- *   gas cost: base_cost + unit_cost * bytes_searched
+ *   The search stops at the first occurrence, so the bytes searched are those before it, or the
+ *   whole string if there is none.
+ *
+ *   gas cost: base_cost + per_byte_pattern * pattern_length_in_bytes
+ *                       + per_byte_searched * bytes_searched
  *
  **************************************************************************************************/
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -190,6 +206,185 @@ pub fn make_native_index_of(gas_params: IndexOfGasParameters) -> NativeFunction
     )
 }
 
+/***************************************************************************************************
+ * native fun internal_char_count
+ *
+ *   gas cost: base_cost + unit_cost * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharCountGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_char_count(
+    gas_params: &CharCountGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let s_arg = pop_arg!(args, VectorRef);
+    let s_ref = s_arg.as_bytes_ref();
+    let s_str = unsafe {
+        // This is safe because we guarantee the bytes to be utf8.
+        std::str::from_utf8_unchecked(s_ref.as_slice())
+    };
+    let count = s_str.chars().count();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(s_str.len() as u64);
+    NativeResult::map_partial_vm_result_one(cost, Ok(Value::u64(count as u64)))
+}
+
+pub fn make_native_char_count(gas_params: CharCountGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_char_count(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun internal_split_lines
+ *
+ *   Splits at `\n` and `\r\n`. A line ending at the end does not start another line, so an
+ *   empty string has no lines.
+ *
+ *   gas cost: base_cost + unit_cost * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitLinesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_split_lines(
+    gas_params: &SplitLinesGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let s_arg = pop_arg!(args, VectorRef);
+    let s_ref = s_arg.as_bytes_ref();
+    let bytes = s_ref.as_slice();
+
+    // Splitting at ASCII bytes keeps the lines valid utf8.
+    let lines = bytes
+        .split_inclusive(|b| *b == b'\n')
+        .map(|line| {
+            let line = match line.strip_suffix(b"\n") {
+                Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+                // A `\r` is only part of the line ending when it precedes a `\n`.
+                None => line,
+            };
+            Value::vector_u8(line.iter().copied())
+        })
+        .collect();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    NativeResult::map_partial_vm_result_one(
+        cost,
+        Vector::pack(&Type::Vector(Arc::new(Type::U8)), lines),
+    )
+}
+
+pub fn make_native_split_lines(gas_params: SplitLinesGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_split_lines(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun internal_to_lowercase
+ *
+ *   Case mapping can change the length of a string, e.g. `ẞ` (3 bytes) lowercases to `ß` (2 bytes),
+ *   so the cost is charged for the longer of the input, which is scanned, and the result, which is
+ *   allocated.
+ *
+ *   gas cost: base_cost + unit_cost * max(input_length_in_bytes, output_length_in_bytes)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToLowercaseGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_to_lowercase(
+    gas_params: &ToLowercaseGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let s_arg = pop_arg!(args, VectorRef);
+    let s_ref = s_arg.as_bytes_ref();
+    let s_str = unsafe {
+        // This is safe because we guarantee the bytes to be utf8.
+        std::str::from_utf8_unchecked(s_ref.as_slice())
+    };
+    let lower = s_str.to_lowercase();
+
+    let len = std::cmp::max(s_str.len(), lower.len());
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(len as u64);
+    NativeResult::map_partial_vm_result_one(cost, Ok(Value::vector_u8(lower.into_bytes())))
+}
+
+pub fn make_native_to_lowercase(gas_params: ToLowercaseGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_to_lowercase(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun internal_to_uppercase
+ *
+ *   Like `internal_to_lowercase`, the cost is charged for the longer of the input and the result.
+ *
+ *   gas cost: base_cost + unit_cost * max(input_length_in_bytes, output_length_in_bytes)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToUppercaseGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_to_uppercase(
+    gas_params: &ToUppercaseGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let s_arg = pop_arg!(args, VectorRef);
+    let s_ref = s_arg.as_bytes_ref();
+    let s_str = unsafe {
+        // This is safe because we guarantee the bytes to be utf8.
+        std::str::from_utf8_unchecked(s_ref.as_slice())
+    };
+    let upper = s_str.to_uppercase();
+
+    let len = std::cmp::max(s_str.len(), upper.len());
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(len as u64);
+    NativeResult::map_partial_vm_result_one(cost, Ok(Value::vector_u8(upper.into_bytes())))
+}
+
+pub fn make_native_to_uppercase(gas_params: ToUppercaseGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_to_uppercase(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -199,6 +394,10 @@ pub struct GasParameters {
     pub is_char_boundary: IsCharBoundaryGasParameters,
     pub sub_string: SubStringGasParameters,
     pub index_of: IndexOfGasParameters,
+    pub char_count: CharCountGasParameters,
+    pub split_lines: SplitLinesGasParameters,
+    pub to_lowercase: ToLowercaseGasParameters,
+    pub to_uppercase: ToUppercaseGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
@@ -219,6 +418,22 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "internal_index_of",
             make_native_index_of(gas_params.index_of),
         ),
+        (
+            "internal_char_count",
+            make_native_char_count(gas_params.char_count),
+        ),
+        (
+            "internal_split_lines",
+            make_native_split_lines(gas_params.split_lines),
+        ),
+        (
+            "internal_to_lowercase",
+            make_native_to_lowercase(gas_params.to_lowercase),
+        ),
+        (
+            "internal_to_uppercase",
+            make_native_to_uppercase(gas_params.to_uppercase),
+        ),
     ];
 
     make_module_natives(natives)
@@ -0,0 +1,95 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions reading the block timestamp.
+
+use crate::natives::helpers::make_module_natives;
+use better_any::{Tid, TidAble};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::InternalGas;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, values::Value,
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Abort codes, these must match the constants in `timestamp.move`.
+const ETIMESTAMP_NOT_AVAILABLE: u64 = 1;
+
+/// Native context extension which provides the block timestamp to `timestamp::now_microseconds`.
+///
+/// Adapters add it to a session with the timestamp of the block the session executes in.
+#[derive(Tid)]
+pub struct NativeTimestampContext {
+    micros: u64,
+}
+
+impl NativeTimestampContext {
+    pub fn new(micros: u64) -> Self {
+        Self { micros }
+    }
+
+    /// The block timestamp, in microseconds since the Unix epoch.
+    pub fn micros(&self) -> u64 {
+        self.micros
+    }
+}
+
+/***************************************************************************************************
+ * native fun now_microseconds
+ *
+ *   Returns the block timestamp provided by the `NativeTimestampContext` of the session.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NowMicrosecondsGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_now_microseconds(
+    gas_params: &NowMicrosecondsGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let extensions = context.extensions();
+    if !extensions.contains::<NativeTimestampContext>() {
+        return Ok(NativeResult::err(gas_params.base, ETIMESTAMP_NOT_AVAILABLE));
+    }
+    let micros = extensions.get::<NativeTimestampContext>().micros();
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(micros)],
+    ))
+}
+
+pub fn make_native_now_microseconds(gas_params: NowMicrosecondsGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_now_microseconds(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub now_microseconds: NowMicrosecondsGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "now_microseconds",
+        make_native_now_microseconds(gas_params.now_microseconds),
+    )];
+
+    make_module_natives(natives)
+}
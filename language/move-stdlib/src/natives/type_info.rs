@@ -0,0 +1,234 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions inspecting the layout of types.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    identifier::Identifier,
+    language_storage::ModuleId,
+    value::MoveTypeLayout,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, Vector, VectorRef},
+};
+use sha3::{Digest, Sha3_256};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+// Abort codes, these must match the constants in `type_info.move`.
+const ENOT_A_STRUCT: u64 = 1;
+const EINVALID_ABILITY: u64 = 2;
+
+/***************************************************************************************************
+ * native fun num_fields
+ *
+ *   Returns the number of fields of the struct type `T`.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumFieldsGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_num_fields(
+    gas_params: &NumFieldsGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert!(args.is_empty());
+
+    let num_fields = match context.type_to_type_layout(&ty_args[0])? {
+        Some(MoveTypeLayout::Struct(layout)) => layout.fields().len(),
+        _ => return Ok(NativeResult::err(gas_params.base, ENOT_A_STRUCT)),
+    };
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(num_fields as u64)],
+    ))
+}
+
+pub fn make_native_num_fields(gas_params: NumFieldsGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_num_fields(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun type_eq
+ *
+ *   Returns true if `T` and `U` are the same type.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeEqGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_type_eq(
+    gas_params: &TypeEqGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 2);
+    debug_assert!(args.is_empty());
+
+    // Loaded structs are identified by their index in the loader's cache, so comparing the types
+    // compares struct instantiations structurally.
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::bool(ty_args[0] == ty_args[1])],
+    ))
+}
+
+pub fn make_native_type_eq(gas_params: TypeEqGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_type_eq(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun module_hash
+ *
+ *   Returns the SHA3-256 of the published bytes of the module `addr::module_name`, or none if no
+ *   such module is published.
+ *
+ *   gas cost: base_cost + unit_cost * module_size_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleHashGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_module_hash(
+    gas_params: &ModuleHashGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let module_name = pop_arg!(args, VectorRef);
+    let addr = pop_arg!(args, AccountAddress);
+
+    let module_bytes = match String::from_utf8(module_name.as_bytes_ref().to_vec())
+        .ok()
+        .and_then(|name| Identifier::new(name).ok())
+    {
+        Some(name) => context.load_module_bytes(&ModuleId::new(addr, name))?,
+        None => None,
+    };
+    let module_size = module_bytes.as_ref().map_or(0, |bytes| bytes.len());
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(module_size as u64);
+    // make a std::option::Option<vector<u8>>
+    let hash = Vector::pack(
+        &Type::Vector(Arc::new(Type::U8)),
+        module_bytes
+            .map(|bytes| Value::vector_u8(Sha3_256::digest(&bytes)))
+            .into_iter()
+            .collect(),
+    )?;
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::struct_(Struct::pack(vec![hash]))],
+    ))
+}
+
+pub fn make_native_module_hash(gas_params: ModuleHashGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_module_hash(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun has_ability
+ *
+ *   Returns true if `T` has `ability`, which is encoded as in the binary format: copy = 0x1,
+ *   drop = 0x2, store = 0x4 and key = 0x8. Aborts if `ability` is not one of these.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HasAbilityGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_has_ability(
+    gas_params: &HasAbilityGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert!(args.len() == 1);
+
+    let ability = pop_arg!(args, u8);
+    if !matches!(ability, 0x1 | 0x2 | 0x4 | 0x8) {
+        return Ok(NativeResult::err(gas_params.base, EINVALID_ABILITY));
+    }
+    let abilities = context.type_abilities(&ty_args[0])?;
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::bool(abilities.into_u8() & ability != 0)],
+    ))
+}
+
+pub fn make_native_has_ability(gas_params: HasAbilityGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_has_ability(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub num_fields: NumFieldsGasParameters,
+    pub type_eq: TypeEqGasParameters,
+    pub module_hash: ModuleHashGasParameters,
+    pub has_ability: HasAbilityGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("num_fields", make_native_num_fields(gas_params.num_fields)),
+        ("type_eq", make_native_type_eq(gas_params.type_eq)),
+        (
+            "module_hash",
+            make_native_module_hash(gas_params.module_hash),
+        ),
+        (
+            "has_ability",
+            make_native_has_ability(gas_params.has_ability),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
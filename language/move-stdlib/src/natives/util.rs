@@ -0,0 +1,116 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native utility functions.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+use zeroize::Zeroize;
+
+/***************************************************************************************************
+ * native fun assert_with_code
+ *
+ *   Aborts with `code` if `cond` is false.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_assert(
+    gas_params: &AssertGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let code = pop_arg!(args, u64);
+    let cond = pop_arg!(args, bool);
+
+    if !cond {
+        return Ok(NativeResult::err(gas_params.base, code));
+    }
+    Ok(NativeResult::ok(gas_params.base, smallvec![]))
+}
+
+pub fn make_native_assert(gas_params: AssertGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_assert(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun zeroize
+ *
+ *   Overwrites every byte of `v` with zero, keeping its length. The `zeroize` crate ensures the
+ *   writes are not optimized away.
+ *
+ *   gas cost: base_cost + unit_cost * length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZeroizeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_zeroize(
+    gas_params: &ZeroizeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let v = pop_arg!(args, VectorRef);
+    let mut bytes = v.as_bytes_mut();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+    // Zeroizing the `Vec` itself would also truncate it, so only its elements are cleared.
+    bytes.as_mut_slice().zeroize();
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+pub fn make_native_zeroize(gas_params: ZeroizeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_zeroize(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasParameters {
+    pub assert: AssertGasParameters,
+    pub zeroize: ZeroizeGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("assert_with_code", make_native_assert(gas_params.assert)),
+        ("zeroize", make_native_zeroize(gas_params.zeroize)),
+    ];
+
+    make_module_natives(natives)
+}
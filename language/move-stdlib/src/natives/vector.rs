@@ -6,7 +6,10 @@ use crate::natives::helpers::make_module_natives;
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_core_types::gas_algebra::AbstractMemorySize;
 use move_core_types::{
-    gas_algebra::{InternalGas, InternalGasPerAbstractMemoryUnit},
+    gas_algebra::{
+        InternalGas, InternalGasPerAbstractMemoryUnit, InternalGasPerArg, InternalGasPerByte,
+        NumArgs, NumBytes,
+    },
     vm_status::StatusCode,
 };
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
@@ -14,10 +17,18 @@ use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
     pop_arg,
-    values::{Value, Vector, VectorRef},
+    values::{
+        Reference, Value, Vector, VectorRef, INDEX_OUT_OF_BOUNDS, VEC_EMPTY, VEC_LENGTH_MISMATCH,
+        VEC_NOT_A_PERMUTATION, VEC_OVERFLOW,
+    },
     views::ValueView,
 };
-use std::{collections::VecDeque, sync::Arc};
+use sha2::{Digest, Sha256};
+use smallvec::smallvec;
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+};
 
 /***************************************************************************************************
  * native fun empty
@@ -231,6 +242,56 @@ pub fn native_spawn_from(
     NativeResult::map_partial_vm_result_one(cost, res.map_err(native_error_to_abort))
 }
 
+/***************************************************************************************************
+ * native fun native_slice
+ *
+ *   Returns a copy of the elements of `v` in `start..end`, aborting if `start > end` or
+ *   `end > length(v)`.
+ *
+ *   gas cost: base_cost + legacy_unit_cost * max(1, size_of(slice))
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SliceGasParameters {
+    pub base: InternalGas,
+    pub legacy_per_abstract_memory_unit: InternalGasPerAbstractMemoryUnit,
+}
+
+pub fn native_slice(
+    gas_params: &SliceGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 3);
+
+    let end = pop_arg!(args, u64);
+    let start = pop_arg!(args, u64);
+    let r = pop_arg!(args, VectorRef);
+
+    let len = r.len(&ty_args[0])?.value_as::<u64>()?;
+    if start > end || end > len {
+        return Ok(NativeResult::err(gas_params.base, INDEX_OUT_OF_BOUNDS));
+    }
+
+    let mut memory_cost = 0.into();
+    let mut cost = gas_params.base;
+    let res = r.slice(&mut memory_cost, start as usize, end as usize, &ty_args[0]);
+    if gas_params.legacy_per_abstract_memory_unit != 0.into() {
+        cost += gas_params.legacy_per_abstract_memory_unit * std::cmp::max(memory_cost, 1.into());
+    }
+    NativeResult::map_partial_vm_result_one(cost, res.map_err(native_error_to_abort))
+}
+
+pub fn make_native_slice(gas_params: SliceGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_slice(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 /***************************************************************************************************
  * native fun destroy_empty
  *
@@ -423,6 +484,862 @@ pub fn make_native_reverse(gas_params: ReverseGasParameters) -> NativeFunction {
     )
 }
 
+/***************************************************************************************************
+ * native fun dot_product_u64
+ *
+ *   gas cost: base_cost + per_element * length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotProductU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_dot_product_u64(
+    gas_params: &DotProductU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let weights = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+    let amounts = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(amounts.len() as u64);
+    if amounts.len() != weights.len() {
+        return Ok(NativeResult::err(cost, VEC_LENGTH_MISMATCH));
+    }
+
+    // The product of two u64 values always fits into a u128, so only the sum can overflow.
+    let mut sum: u128 = 0;
+    for (amount, weight) in amounts.into_iter().zip(weights) {
+        sum = match sum.checked_add(amount as u128 * weight as u128) {
+            Some(sum) => sum,
+            None => return Ok(NativeResult::err(cost, VEC_OVERFLOW)),
+        };
+    }
+    Ok(NativeResult::ok(cost, smallvec![Value::u128(sum)]))
+}
+
+pub fn make_native_dot_product_u64(gas_params: DotProductU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_dot_product_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun truncate
+ *
+ *   gas cost: base_cost + per_element_dropped * num_dropped
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TruncateGasParameters {
+    pub base: InternalGas,
+    pub per_element_dropped: InternalGasPerArg,
+}
+
+pub fn native_truncate(
+    gas_params: &TruncateGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let len = pop_arg!(args, u64) as usize;
+    let r = pop_arg!(args, VectorRef);
+    let dropped = r.truncate(len, &ty_args[0])?;
+    let cost = gas_params.base + gas_params.per_element_dropped * NumArgs::new(dropped as u64);
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+pub fn make_native_truncate(gas_params: TruncateGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_truncate(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun minmax_u64
+ *
+ *   gas cost: base_cost + per_element * length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinMaxU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_minmax_u64(
+    gas_params: &MinMaxU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let v = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(v.len() as u64);
+    let mut iter = v.into_iter();
+    let first = match iter.next() {
+        Some(first) => first,
+        None => return Ok(NativeResult::err(cost, VEC_EMPTY)),
+    };
+    let (min, max) = iter.fold((first, first), |(min, max), x| (min.min(x), max.max(x)));
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u64(min), Value::u64(max)],
+    ))
+}
+
+pub fn make_native_minmax_u64(gas_params: MinMaxU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_minmax_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun is_sorted_u64
+ *
+ *   gas cost: base_cost + per_element * length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsSortedU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_is_sorted_u64(
+    gas_params: &IsSortedU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let v = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(v.len() as u64);
+    let sorted = v.windows(2).all(|pair| pair[0] <= pair[1]);
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(sorted)]))
+}
+
+pub fn make_native_is_sorted_u64(gas_params: IsSortedU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_is_sorted_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun prefix_sum_u64
+ *
+ *   gas cost: base_cost + per_element * length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSumU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_prefix_sum_u64(
+    gas_params: &PrefixSumU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let v = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(v.len() as u64);
+    // The sum of at most 2^64 values below 2^64 always fits into a u128.
+    let sums = v.iter().scan(0u128, |sum, x| {
+        *sum += *x as u128;
+        Some(*sum)
+    });
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u128(sums)]))
+}
+
+pub fn make_native_prefix_sum_u64(gas_params: PrefixSumU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_prefix_sum_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun count_distinct_u64, has_duplicates_u64
+ *
+ *   gas cost: base_cost + per_element * elements_visited
+ *
+ *   `has_duplicates_u64` stops at the first duplicate, `count_distinct_u64` visits all elements.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistinctU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_count_distinct_u64(
+    gas_params: &DistinctU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let v = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(v.len() as u64);
+    let distinct = v.iter().collect::<HashSet<_>>().len();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u64(distinct as u64)],
+    ))
+}
+
+pub fn make_native_count_distinct_u64(gas_params: DistinctU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_count_distinct_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+pub fn native_has_duplicates_u64(
+    gas_params: &DistinctU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let v = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let mut seen = HashSet::with_capacity(v.len());
+    let duplicate = v.iter().position(|x| !seen.insert(x));
+    let visited = duplicate.map_or(v.len(), |idx| idx + 1);
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(visited as u64);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(duplicate.is_some())],
+    ))
+}
+
+pub fn make_native_has_duplicates_u64(gas_params: DistinctU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_has_duplicates_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun weighted_median_u64
+ *
+ *   gas cost: base_cost + per_element * length * bit_length(length)
+ *
+ *   The values are sorted, hence the `n log n` factor.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedMedianU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_weighted_median_u64(
+    gas_params: &WeightedMedianU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let weights = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+    let values = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let n = values.len() as u64;
+    let log_n = (u64::BITS - n.leading_zeros()) as u64;
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(n * log_n);
+    if values.len() != weights.len() {
+        return Ok(NativeResult::err(cost, VEC_LENGTH_MISMATCH));
+    }
+
+    let mut pairs = values.into_iter().zip(weights).collect::<Vec<_>>();
+    pairs.sort_unstable_by_key(|(value, _)| *value);
+    // Sums of u64 weights fit into a u128, and so does twice the running sum.
+    let total = pairs
+        .iter()
+        .map(|(_, weight)| *weight as u128)
+        .sum::<u128>();
+    if total == 0 {
+        return Ok(NativeResult::err(cost, VEC_EMPTY));
+    }
+    // The lower weighted median: the first value at which the running weight reaches half of the
+    // total weight.
+    let mut running = 0u128;
+    let median = pairs
+        .into_iter()
+        .find(|(_, weight)| {
+            running += *weight as u128;
+            2 * running >= total
+        })
+        .map(|(value, _)| value)
+        .expect("the running weight reaches the total weight");
+    Ok(NativeResult::ok(cost, smallvec![Value::u64(median)]))
+}
+
+pub fn make_native_weighted_median_u64(
+    gas_params: WeightedMedianU64GasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_weighted_median_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun weighted_sample
+ *
+ *   Selects index `i` with probability `weights[i] / sum(weights)`, deterministically from
+ *   `seed`: the first 16 bytes of SHA2-256(seed), read as a big-endian u128, are reduced modulo
+ *   the total weight to a point `p`, and the first index whose running weight exceeds `p` is
+ *   selected.
+ *
+ *   gas cost: base_cost + per_element * length + per_byte * seed_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedSampleGasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+    pub per_byte: InternalGasPerByte,
+}
+
+pub fn native_weighted_sample(
+    gas_params: &WeightedSampleGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let seed = pop_arg!(args, VectorRef);
+    let seed = seed.as_bytes_ref();
+    let weights = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base
+        + gas_params.per_element * NumArgs::new(weights.len() as u64)
+        + gas_params.per_byte * NumBytes::new(seed.len() as u64);
+    // Sums of u64 weights fit into a u128.
+    let total = weights.iter().map(|weight| *weight as u128).sum::<u128>();
+    if total == 0 {
+        return Ok(NativeResult::err(cost, VEC_EMPTY));
+    }
+
+    let digest = Sha256::digest(&seed);
+    let mut prefix = [0u8; 16];
+    prefix.copy_from_slice(&digest[..16]);
+    let point = u128::from_be_bytes(prefix) % total;
+    let mut running = 0u128;
+    let index = weights
+        .iter()
+        .position(|weight| {
+            running += *weight as u128;
+            running > point
+        })
+        .expect("the running weight reaches the total weight");
+    Ok(NativeResult::ok(cost, smallvec![Value::u64(index as u64)]))
+}
+
+pub fn make_native_weighted_sample(gas_params: WeightedSampleGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_weighted_sample(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun stable_sort_by_key_u64
+ *
+ *   Returns the permutation which stably sorts `keys`: the indices of `keys`, ordered by key, and
+ *   by index among equal keys.
+ *
+ *   gas cost: base_cost + per_comparison * length * ceil(log2(length))
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StableSortByKeyU64GasParameters {
+    pub base: InternalGas,
+    pub per_comparison: InternalGasPerArg,
+}
+
+pub fn native_stable_sort_by_key_u64(
+    gas_params: &StableSortByKeyU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let keys = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let n = keys.len() as u64;
+    let ceil_log2 = match n {
+        0 => 0,
+        n => (u64::BITS - (n - 1).leading_zeros()) as u64,
+    };
+    let cost = gas_params.base + gas_params.per_comparison * NumArgs::new(n * ceil_log2);
+    let mut permutation: Vec<u64> = (0..n).collect();
+    // `sort_by_key` is stable, so equal keys keep the order of their indices.
+    permutation.sort_by_key(|i| keys[*i as usize]);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u64(permutation)],
+    ))
+}
+
+pub fn make_native_stable_sort_by_key_u64(
+    gas_params: StableSortByKeyU64GasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_stable_sort_by_key_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun invert_permutation
+ *
+ *   Returns the inverse of `perm`, which must hold each of `0..length` exactly once.
+ *
+ *   gas cost: base_cost + per_element * length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvertPermutationGasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_invert_permutation(
+    gas_params: &InvertPermutationGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let perm = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(perm.len() as u64);
+    // `None` marks the indices which no element of `perm` maps to yet.
+    let mut inverse = vec![None; perm.len()];
+    for (i, p) in perm.iter().enumerate() {
+        match inverse.get_mut(*p as usize) {
+            Some(slot @ None) => *slot = Some(i as u64),
+            _ => return Ok(NativeResult::err(cost, VEC_NOT_A_PERMUTATION)),
+        }
+    }
+    // Every slot is set, as the elements are distinct and there are as many as slots.
+    let inverse = inverse.into_iter().flatten();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u64(inverse)],
+    ))
+}
+
+pub fn make_native_invert_permutation(
+    gas_params: InvertPermutationGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_invert_permutation(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun apply_permutation_u64
+ *
+ *   Returns the vector whose element `i` is `v[perm[i]]`. `perm` must have the length of `v` and
+ *   hold each of `0..length` exactly once.
+ *
+ *   gas cost: base_cost + per_element * length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyPermutationU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_apply_permutation_u64(
+    gas_params: &ApplyPermutationU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let perm = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+    let v = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(v.len() as u64);
+    if perm.len() != v.len() {
+        return Ok(NativeResult::err(cost, VEC_LENGTH_MISMATCH));
+    }
+    let mut seen = vec![false; perm.len()];
+    let mut result = Vec::with_capacity(perm.len());
+    for p in perm {
+        match seen.get_mut(p as usize) {
+            Some(seen @ false) => *seen = true,
+            _ => return Ok(NativeResult::err(cost, VEC_NOT_A_PERMUTATION)),
+        }
+        result.push(v[p as usize]);
+    }
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u64(result)]))
+}
+
+pub fn make_native_apply_permutation_u64(
+    gas_params: ApplyPermutationU64GasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_apply_permutation_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun percentile_u64
+ *
+ *   Returns the `p`-th percentile of `v` by the nearest-rank method: the element at rank
+ *   `ceil(p * length / 100)` of the sorted values, counting from 1, or the minimum if `p` is 0.
+ *
+ *   gas cost: base_cost + per_element * length * bit_length(length)
+ *
+ *   The values are sorted, hence the `n log n` factor.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PercentileU64GasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+pub fn native_percentile_u64(
+    gas_params: &PercentileU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let p = pop_arg!(args, u64);
+    let mut values = pop_arg!(args, Reference)
+        .read_ref()?
+        .value_as::<Vec<u64>>()?;
+
+    let n = values.len() as u64;
+    let log_n = (u64::BITS - n.leading_zeros()) as u64;
+    let cost = gas_params.base + gas_params.per_element * NumArgs::new(n * log_n);
+    if p > 100 {
+        return Ok(NativeResult::err(cost, INDEX_OUT_OF_BOUNDS));
+    }
+    if values.is_empty() {
+        return Ok(NativeResult::err(cost, VEC_EMPTY));
+    }
+
+    values.sort_unstable();
+    // `p * n` cannot overflow, as a vector has far fewer than `u64::MAX / 100` elements.
+    let rank = (p * n + 99) / 100;
+    let percentile = values[rank.saturating_sub(1) as usize];
+    Ok(NativeResult::ok(cost, smallvec![Value::u64(percentile)]))
+}
+
+pub fn make_native_percentile_u64(gas_params: PercentileU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_percentile_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun native_index_of
+ *
+ *   Returns `(true, i)` for the first index `i` at which `v` holds a value equal to `e`, and
+ *   `(false, 0)` if there is none. Values are compared like with `==`.
+ *
+ *   gas cost: base_cost + per_comparison * number_of_elements_compared
+ *                       + legacy_unit_cost * sum(size_of(e) + size_of(v[i]) for each compared i)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexOfGasParameters {
+    pub base: InternalGas,
+    pub per_comparison: InternalGasPerArg,
+    pub legacy_per_abstract_memory_unit: InternalGasPerAbstractMemoryUnit,
+}
+
+/// Abstract memory size of the value behind the reference `r`, the way `==` is charged.
+fn size_behind_ref(r: &Value) -> PartialVMResult<AbstractMemorySize> {
+    Ok(r.copy_value()?
+        .value_as::<Reference>()?
+        .value_view()
+        .legacy_abstract_memory_size())
+}
+
+pub fn native_index_of(
+    gas_params: &IndexOfGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let e = args.pop_back().unwrap();
+    let v = pop_arg!(args, VectorRef);
+
+    // Comparing structured values costs time proportional to their size, so each comparison is
+    // charged by the size of both operands in addition to the flat per-comparison cost.
+    let e_size = size_behind_ref(&e)?;
+    let mut cost = gas_params.base;
+    let len = v.len(&ty_args[0])?.value_as::<u64>()?;
+    for i in 0..len {
+        let elem = v.borrow_elem(i as usize, &ty_args[0])?;
+        cost += gas_params.per_comparison * NumArgs::new(1)
+            + gas_params.legacy_per_abstract_memory_unit * (e_size + size_behind_ref(&elem)?);
+        if elem.equals(&e)? {
+            return Ok(NativeResult::ok(
+                cost,
+                smallvec![Value::bool(true), Value::u64(i)],
+            ));
+        }
+    }
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(false), Value::u64(0)],
+    ))
+}
+
+pub fn make_native_index_of(gas_params: IndexOfGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_index_of(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun native_shuffle_indices
+ *
+ *   Returns a permutation of `0..len` derived deterministically from `seed`, by a Fisher-Yates
+ *   shuffle: for `i` from `len - 1` down to `1`, element `i` is swapped with element
+ *   `r mod (i + 1)`, where `r` is the next random number. The random numbers are big-endian u128s,
+ *   two per block, taken from the blocks `SHA2-256(SHA2-256(seed) || k)` for `k = 0, 1, ...`
+ *   encoded as big-endian u64s.
+ *
+ *   gas cost: base_cost + per_element * len + per_byte * seed_length_in_bytes
+ *
+ *   As `len` is not bounded by an existing vector, the native runs out of gas before allocating
+ *   the result if the cost exceeds the remaining gas.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShuffleIndicesGasParameters {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+    pub per_byte: InternalGasPerByte,
+}
+
+// The random numbers of `native_shuffle_indices`.
+struct ShuffleRng {
+    key: [u8; 32],
+    counter: u64,
+    // The second number of the last block, if not used yet.
+    pending: Option<u128>,
+}
+
+impl ShuffleRng {
+    fn new(seed: &[u8]) -> Self {
+        Self {
+            key: Sha256::digest(seed).into(),
+            counter: 0,
+            pending: None,
+        }
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        if let Some(next) = self.pending.take() {
+            return next;
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(self.key);
+        hasher.update(self.counter.to_be_bytes());
+        let block = hasher.finalize();
+        self.counter += 1;
+        let (first, second) = block.split_at(16);
+        self.pending = Some(u128::from_be_bytes(second.try_into().unwrap()));
+        u128::from_be_bytes(first.try_into().unwrap())
+    }
+}
+
+pub fn native_shuffle_indices(
+    gas_params: &ShuffleIndicesGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let seed = pop_arg!(args, VectorRef);
+    let seed = seed.as_bytes_ref();
+    let len = pop_arg!(args, u64);
+
+    let cost = gas_params.base
+        + gas_params.per_element * NumArgs::new(len)
+        + gas_params.per_byte * NumBytes::new(seed.len() as u64);
+    if cost > context.gas_balance() {
+        return Ok(NativeResult::out_of_gas(cost));
+    }
+
+    let mut rng = ShuffleRng::new(&seed);
+    let mut permutation: Vec<u64> = (0..len).collect();
+    for i in (1..permutation.len()).rev() {
+        let j = rng.next_u128() % (i as u128 + 1);
+        permutation.swap(i, j as usize);
+    }
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u64(permutation)],
+    ))
+}
+
+pub fn make_native_shuffle_indices(gas_params: ShuffleIndicesGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_shuffle_indices(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun get_2d_u64
+ *
+ *   Reads element `(r, c)` of a `rows` x `cols` matrix stored in row-major order.
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Get2dU64GasParameters {
+    pub base: InternalGas,
+}
+
+pub fn native_get_2d_u64(
+    gas_params: &Get2dU64GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 5);
+
+    let c = pop_arg!(args, u64);
+    let r = pop_arg!(args, u64);
+    let cols = pop_arg!(args, u64);
+    let rows = pop_arg!(args, u64);
+    let v = pop_arg!(args, VectorRef);
+
+    // Only the accessed element is read, so that the cost does not depend on the length.
+    let len = v.len(&Type::U64)?.value_as::<u64>()?;
+    if rows.checked_mul(cols) != Some(len) {
+        return Ok(NativeResult::err(gas_params.base, VEC_LENGTH_MISMATCH));
+    }
+    if r >= rows || c >= cols {
+        return Ok(NativeResult::err(gas_params.base, INDEX_OUT_OF_BOUNDS));
+    }
+    let elem = v
+        .borrow_elem((r * cols + c) as usize, &Type::U64)?
+        .value_as::<Reference>()?
+        .read_ref()?;
+    Ok(NativeResult::ok(gas_params.base, smallvec![elem]))
+}
+
+pub fn make_native_get_2d_u64(gas_params: Get2dU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_get_2d_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 fn native_error_to_abort(err: PartialVMError) -> PartialVMError {
     let (major_status, sub_status_opt, message_opt, exec_state_opt, indices, offsets) =
         err.all_data();
@@ -461,6 +1378,22 @@ pub struct GasParameters {
     pub remove: RemoveGasParameters,
     pub reverse: ReverseGasParameters,
     pub spawn_from: SpawnFromParameters,
+    pub slice: SliceGasParameters,
+    pub dot_product_u64: DotProductU64GasParameters,
+    pub truncate: TruncateGasParameters,
+    pub minmax_u64: MinMaxU64GasParameters,
+    pub is_sorted_u64: IsSortedU64GasParameters,
+    pub prefix_sum_u64: PrefixSumU64GasParameters,
+    pub distinct_u64: DistinctU64GasParameters,
+    pub weighted_median_u64: WeightedMedianU64GasParameters,
+    pub weighted_sample: WeightedSampleGasParameters,
+    pub get_2d_u64: Get2dU64GasParameters,
+    pub stable_sort_by_key_u64: StableSortByKeyU64GasParameters,
+    pub invert_permutation: InvertPermutationGasParameters,
+    pub apply_permutation_u64: ApplyPermutationU64GasParameters,
+    pub percentile_u64: PercentileU64GasParameters,
+    pub index_of: IndexOfGasParameters,
+    pub shuffle_indices: ShuffleIndicesGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
@@ -472,6 +1405,7 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
         ("borrow_mut", make_native_borrow(gas_params.borrow)),
         ("pop_back", make_native_pop_back(gas_params.pop_back)),
         ("spawn_from", make_spawn_from(gas_params.spawn_from)),
+        ("native_slice", make_native_slice(gas_params.slice)),
         (
             "destroy_empty",
             make_native_destroy_empty(gas_params.destroy_empty),
@@ -480,6 +1414,58 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
         ("native_append", make_native_append(gas_params.append)),
         ("native_remove", make_native_remove(gas_params.remove)),
         ("native_reverse", make_native_reverse(gas_params.reverse)),
+        (
+            "dot_product_u64",
+            make_native_dot_product_u64(gas_params.dot_product_u64),
+        ),
+        ("truncate", make_native_truncate(gas_params.truncate)),
+        ("minmax_u64", make_native_minmax_u64(gas_params.minmax_u64)),
+        (
+            "is_sorted_u64",
+            make_native_is_sorted_u64(gas_params.is_sorted_u64),
+        ),
+        (
+            "prefix_sum_u64",
+            make_native_prefix_sum_u64(gas_params.prefix_sum_u64),
+        ),
+        (
+            "count_distinct_u64",
+            make_native_count_distinct_u64(gas_params.distinct_u64.clone()),
+        ),
+        (
+            "has_duplicates_u64",
+            make_native_has_duplicates_u64(gas_params.distinct_u64),
+        ),
+        (
+            "weighted_median_u64",
+            make_native_weighted_median_u64(gas_params.weighted_median_u64),
+        ),
+        (
+            "weighted_sample",
+            make_native_weighted_sample(gas_params.weighted_sample),
+        ),
+        ("get_2d_u64", make_native_get_2d_u64(gas_params.get_2d_u64)),
+        (
+            "stable_sort_by_key_u64",
+            make_native_stable_sort_by_key_u64(gas_params.stable_sort_by_key_u64),
+        ),
+        (
+            "invert_permutation",
+            make_native_invert_permutation(gas_params.invert_permutation),
+        ),
+        (
+            "apply_permutation_u64",
+            make_native_apply_permutation_u64(gas_params.apply_permutation_u64),
+        ),
+        (
+            "percentile_u64",
+            make_native_percentile_u64(gas_params.percentile_u64),
+        ),
+        ("native_index_of", make_native_index_of(gas_params.index_of)),
+        (
+            "native_shuffle_indices",
+            make_native_shuffle_indices(gas_params.shuffle_indices),
+        ),
     ];
 
     make_module_natives(natives)
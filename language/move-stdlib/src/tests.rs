@@ -2,8 +2,11 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::natives::GasParameters;
 use anyhow::bail;
-use std::{cmp::Ordering, path::Path};
+use move_core_types::account_address::AccountAddress;
+use move_vm_runtime::native_functions::NativeFunctionTable;
+use std::{cmp::Ordering, path::Path, sync::Arc};
 use tempfile::tempdir;
 use walkdir::{DirEntry, WalkDir};
 
@@ -36,6 +39,26 @@ fn check_that_the_errmap_is_updated() {
     );
 }
 
+#[test]
+fn legacy_native_aliases_resolve_to_same_function() {
+    let find = |table: &NativeFunctionTable, name: &str| {
+        table
+            .iter()
+            .find(|(_, m, f, _)| m.as_str() == "vector" && f.as_str() == name)
+            .map(|(_, _, _, func)| Arc::as_ptr(func) as *const ())
+    };
+
+    let natives = crate::natives::all_natives(AccountAddress::ONE, GasParameters::zeros());
+    assert!(find(&natives, "native_append").is_some());
+    assert!(find(&natives, "append").is_none());
+
+    let natives =
+        crate::natives::all_natives_with_aliases(AccountAddress::ONE, GasParameters::zeros(), true);
+    assert!(find(&natives, "append").is_some());
+    assert_eq!(find(&natives, "append"), find(&natives, "native_append"));
+    assert_eq!(find(&natives, "reverse"), find(&natives, "native_reverse"));
+}
+
 fn check_dirs_not_diff<A: AsRef<Path>, B: AsRef<Path>>(
     actual: A,
     expected: B,
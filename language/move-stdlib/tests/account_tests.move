@@ -0,0 +1,35 @@
+#[test_only]
+module std::account_tests {
+    use std::account;
+    use std::option;
+
+    #[test]
+    fun address_from_hex_short() {
+        assert!(account::address_from_hex(&b"0x1") == option::some(@0x1), 0);
+        assert!(account::address_from_hex(&b"1") == option::some(@0x1), 1);
+    }
+
+    #[test]
+    fun address_from_hex_full() {
+        let full = b"0x000000000000000000000000cafebabe";
+        assert!(account::address_from_hex(&full) == option::some(@0xcafebabe), 0);
+        let full = b"000000000000000000000000cafebabe";
+        assert!(account::address_from_hex(&full) == option::some(@0xcafebabe), 1);
+    }
+
+    #[test]
+    fun address_from_hex_invalid() {
+        assert!(option::is_none(&account::address_from_hex(&b"0xzz")), 0);
+        assert!(option::is_none(&account::address_from_hex(&b"")), 1);
+        assert!(option::is_none(&account::address_from_hex(&b"0x")), 2);
+        assert!(option::is_none(&account::address_from_hex(&x"ff")), 3);
+        // One hex digit longer than an address.
+        assert!(option::is_none(&account::address_from_hex(&b"0x100000000000000000000000000000000")), 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = account::ECREATE_SIGNER_NOT_AUTHORIZED)]
+    fun create_signer_not_authorized() {
+        account::create_signer(@0xcafe);
+    }
+}
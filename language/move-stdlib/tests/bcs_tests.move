@@ -1,6 +1,10 @@
 #[test_only]
 module std::bcs_tests {
+    use std::ascii;
     use std::bcs;
+    use std::option::{Self, Option};
+    use std::string::{Self, String};
+    use std::vector;
 
     struct Box<T> has copy, drop, store { x: T }
     struct Box3<T> has copy, drop, store { x: Box<Box<T>> }
@@ -100,4 +104,148 @@ module std::bcs_tests {
     fun encode_129() {
         bcs::to_bytes(&Box { x: box127(true) });
     }
+
+    #[test]
+    fun canonicalize_canonical_input() {
+        let bytes = bcs::to_bytes(&vector[1u64, 2, 3]);
+        assert!(bcs::canonicalize<vector<u64>>(&bytes) == option::some(bytes), 0);
+        let bytes = bcs::to_bytes(&Box { x: @0x42 });
+        assert!(bcs::canonicalize<Box<address>>(&bytes) == option::some(bytes), 1);
+    }
+
+    #[test]
+    fun canonicalize_non_minimal_length() {
+        // a one element vector<u8>, with its length encoded as the non-minimal ULEB128 0x8100
+        assert!(option::is_none(&bcs::canonicalize<vector<u8>>(&x"810007")), 0);
+    }
+
+    #[test]
+    fun canonicalize_malformed() {
+        // not a valid bool
+        assert!(option::is_none(&bcs::canonicalize<bool>(&x"02")), 0);
+        // trailing bytes
+        assert!(option::is_none(&bcs::canonicalize<u8>(&x"0102")), 1);
+        // truncated
+        assert!(option::is_none(&bcs::canonicalize<u64>(&x"01")), 2);
+    }
+
+    #[test]
+    fun from_bytes_primitives() {
+        assert!(bcs::from_bytes<bool>(bcs::to_bytes(&true)) == true, 0);
+        assert!(bcs::from_bytes<u8>(x"2a") == 42, 1);
+        assert!(bcs::from_bytes<u64>(x"0100000000000000") == 1, 2);
+        assert!(bcs::from_bytes<u256>(bcs::to_bytes(&7u256)) == 7, 3);
+        let addr = @0x89b9f9d1fadc027cf9532d6f99041522;
+        assert!(bcs::from_bytes<address>(bcs::to_bytes(&addr)) == addr, 4);
+    }
+
+    #[test]
+    fun from_bytes_vectors() {
+        assert!(bcs::from_bytes<vector<u8>>(x"020f10") == x"0f10", 0);
+        let v = vector[vector[1u64, 2], vector[], vector[3]];
+        assert!(bcs::from_bytes<vector<vector<u64>>>(bcs::to_bytes(&v)) == v, 1);
+    }
+
+    #[test]
+    fun from_bytes_structs() {
+        let b = Box { x: vector[@0x1, @0x2] };
+        assert!(bcs::from_bytes<Box<vector<address>>>(bcs::to_bytes(&b)) == b, 0);
+        let b = box3(Box { x: 255u8 });
+        assert!(bcs::from_bytes<Box3<Box<u8>>>(bcs::to_bytes(&b)) == b, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x1C5, location = std::bcs)] // NFE_BCS_SERIALIZATION_FAILURE
+    fun from_bytes_malformed() {
+        // not a valid bool
+        bcs::from_bytes<bool>(x"02");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x1C5, location = std::bcs)] // NFE_BCS_SERIALIZATION_FAILURE
+    fun from_bytes_trailing_bytes() {
+        bcs::from_bytes<u8>(x"0102");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x1C5, location = std::bcs)] // NFE_BCS_SERIALIZATION_FAILURE
+    fun from_bytes_truncated() {
+        bcs::from_bytes<Box<u64>>(x"01");
+    }
+
+    #[test]
+    fun from_bytes_std_structs() {
+        let s = string::utf8(b"caf\xc3\xa9");
+        assert!(bcs::from_bytes<String>(bcs::to_bytes(&s)) == s, 0);
+        let s = ascii::string(b"abc");
+        assert!(bcs::from_bytes<ascii::String>(bcs::to_bytes(&s)) == s, 1);
+        let o = option::some(Box { x: string::utf8(b"x") });
+        assert!(bcs::from_bytes<Option<Box<String>>>(bcs::to_bytes(&o)) == o, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x1C5, location = std::bcs)] // NFE_BCS_SERIALIZATION_FAILURE
+    fun from_bytes_string_invalid_utf8() {
+        bcs::from_bytes<String>(x"01ff");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x1C5, location = std::bcs)] // NFE_BCS_SERIALIZATION_FAILURE
+    fun from_bytes_nested_string_invalid_utf8() {
+        bcs::from_bytes<vector<Box<String>>>(x"0202616201ff");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x1C5, location = std::bcs)] // NFE_BCS_SERIALIZATION_FAILURE
+    fun from_bytes_ascii_string_not_ascii() {
+        bcs::from_bytes<ascii::String>(x"0180");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x1C5, location = std::bcs)] // NFE_BCS_SERIALIZATION_FAILURE
+    fun from_bytes_option_with_two_elements() {
+        bcs::from_bytes<Option<u8>>(x"020102");
+    }
+
+    #[test]
+    fun serialized_size_primitives() {
+        assert!(bcs::serialized_size(&true) == vector::length(&bcs::to_bytes(&true)), 0);
+        assert!(bcs::serialized_size(&1u8) == 1, 1);
+        assert!(bcs::serialized_size(&1u128) == 16, 2);
+        assert!(bcs::serialized_size(&1u256) == 32, 3);
+        assert!(bcs::serialized_size(&@0x1) == vector::length(&bcs::to_bytes(&@0x1)), 4);
+    }
+
+    #[test]
+    fun serialized_size_vectors() {
+        let v = vector<u64>[];
+        assert!(bcs::serialized_size(&v) == vector::length(&bcs::to_bytes(&v)), 0);
+        // 128 elements need a two byte ULEB128 length.
+        let v = vector[];
+        let i = 0;
+        while (i < 128) {
+            vector::push_back(&mut v, (i as u8));
+            i = i + 1;
+        };
+        assert!(bcs::serialized_size(&v) == 130, 1);
+        assert!(bcs::serialized_size(&v) == vector::length(&bcs::to_bytes(&v)), 2);
+        let v = vector[vector[1u64, 2], vector[], vector[3]];
+        assert!(bcs::serialized_size(&v) == vector::length(&bcs::to_bytes(&v)), 3);
+    }
+
+    #[test]
+    fun serialized_size_structs() {
+        let b = Box { x: vector[@0x1, @0x2] };
+        assert!(bcs::serialized_size(&b) == vector::length(&bcs::to_bytes(&b)), 0);
+        let b = box63(Box { x: vector[true, false] });
+        assert!(bcs::serialized_size(&b) == vector::length(&bcs::to_bytes(&b)), 1);
+        let b = box127(true);
+        assert!(bcs::serialized_size(&b) == 1, 2);
+    }
+
+    #[test]
+    #[expected_failure] // VM_MAX_VALUE_DEPTH_REACHED
+    fun serialized_size_129() {
+        bcs::serialized_size(&Box { x: box127(true) });
+    }
 }
@@ -0,0 +1,48 @@
+#[test_only]
+module std::bloom_tests {
+    use std::bloom;
+
+    // 256 bits
+    const EMPTY_FILTER: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test]
+    fun added_items_are_found() {
+        let filter = EMPTY_FILTER;
+        bloom::add(&mut filter, 3, &b"alice");
+        bloom::add(&mut filter, 3, &b"bob");
+        assert!(bloom::test(&filter, 3, &b"alice"), 0);
+        assert!(bloom::test(&filter, 3, &b"bob"), 1);
+    }
+
+    #[test]
+    fun absent_items_are_not_found() {
+        let filter = EMPTY_FILTER;
+        assert!(!bloom::test(&filter, 3, &b"alice"), 0);
+        bloom::add(&mut filter, 3, &b"alice");
+        assert!(filter != EMPTY_FILTER, 1);
+        assert!(!bloom::test(&filter, 3, &b"carol"), 2);
+        assert!(!bloom::test(&filter, 3, &b"dave"), 3);
+    }
+
+    #[test]
+    fun saturated_filter_contains_everything() {
+        let filter = x"ffff";
+        assert!(bloom::test(&filter, 16, &b"anything"), 0);
+        assert!(bloom::test(&filter, 1, &x""), 1);
+        bloom::add(&mut filter, 16, &b"anything");
+        assert!(filter == x"ffff", 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = bloom::EEMPTY_FILTER)]
+    fun empty_filter() {
+        bloom::test(&x"", 1, &b"alice");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = bloom::ETOO_MANY_HASHES)]
+    fun too_many_hashes() {
+        let filter = x"00";
+        bloom::add(&mut filter, 9, &b"alice");
+    }
+}
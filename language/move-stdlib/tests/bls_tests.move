@@ -0,0 +1,26 @@
+#[test_only]
+module std::bls_tests {
+    use std::bls;
+
+    const PUBLIC_KEY: vector<u8> = x"a6ceb0760781082c1954d2a4ec868c82e81d0b2bfb6d95b28bfcae30842fc58387da58dcfed367f74d878739285cae92";
+    const SIGNATURE: vector<u8> = x"b4681e3ac696f324e7e91c47701467cb804b78641a1bc45244241bbce9c7aecdc9528339c9e83a87fe2618f534433aea1191e6e4ef398db848683e646da59a62d89dc84a57336c3958ba01f3224914615cc00885b02fe9ae70072098cb261f68";
+
+    #[test]
+    fun bls12381_verify_valid_signature() {
+        assert!(bls::bls12381_verify(&SIGNATURE, &PUBLIC_KEY, &b"hello move"), 0);
+    }
+
+    #[test]
+    fun bls12381_verify_wrong_message() {
+        assert!(!bls::bls12381_verify(&SIGNATURE, &PUBLIC_KEY, &b"hello world"), 0);
+    }
+
+    #[test]
+    fun bls12381_verify_malformed_public_key() {
+        assert!(!bls::bls12381_verify(&SIGNATURE, &x"a6ceb076", &b"hello move"), 0);
+        // The point at infinity is a well-formed encoding, but not a valid public key.
+        let identity = x"c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+        assert!(!bls::bls12381_verify(&SIGNATURE, &identity, &b"hello move"), 1);
+        assert!(!bls::bls12381_verify(&x"", &PUBLIC_KEY, &b"hello move"), 2);
+    }
+}
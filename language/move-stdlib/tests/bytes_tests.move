@@ -0,0 +1,110 @@
+#[test_only]
+module std::bytes_tests {
+    use std::bytes;
+    use std::option;
+
+    #[test]
+    fun deinterleave_stride_one() {
+        assert!(bytes::deinterleave(&b"abc", 1) == vector[b"abc"], 0);
+        assert!(bytes::deinterleave(&b"abc", 3) == vector[b"a", b"b", b"c"], 1);
+    }
+
+    #[test]
+    fun deinterleave_stride_two() {
+        assert!(bytes::deinterleave(&x"0102030405060708", 2) == vector[x"01030507", x"02040608"], 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = bytes::EINVALID_STRIDE)]
+    fun deinterleave_non_multiple_length() {
+        bytes::deinterleave(&b"abcde", 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = bytes::EINVALID_STRIDE)]
+    fun deinterleave_zero_stride() {
+        bytes::deinterleave(&b"abc", 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = bytes::EINVALID_STRIDE)]
+    fun deinterleave_stride_longer_than_input() {
+        bytes::deinterleave(&b"", 18446744073709551615);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = bytes::EINVALID_STRIDE)]
+    fun deinterleave_empty() {
+        bytes::deinterleave(&b"", 1);
+    }
+
+    #[test]
+    fun starts_with_and_ends_with_match() {
+        assert!(bytes::starts_with(&b"\x7fELF", &b"\x7fE"), 0);
+        assert!(bytes::ends_with(&b"hello.move", &b".move"), 1);
+        assert!(bytes::starts_with(&b"abc", &b"abc"), 2);
+        assert!(bytes::ends_with(&b"abc", &b"abc"), 3);
+        assert!(bytes::starts_with(&b"abc", &b""), 4);
+        assert!(bytes::ends_with(&b"", &b""), 5);
+    }
+
+    #[test]
+    fun starts_with_and_ends_with_mismatch() {
+        assert!(!bytes::starts_with(&b"abc", &b"b"), 0);
+        assert!(!bytes::ends_with(&b"abc", &b"b"), 1);
+    }
+
+    #[test]
+    fun affix_longer_than_haystack() {
+        assert!(!bytes::starts_with(&b"ab", &b"abc"), 0);
+        assert!(!bytes::ends_with(&b"bc", &b"abc"), 1);
+    }
+
+    #[test]
+    fun read_uleb128_single_byte() {
+        let (value, len) = bytes::read_uleb128(&x"00", 0);
+        assert!(value == option::some(0) && len == 1, 0);
+        let (value, len) = bytes::read_uleb128(&x"ff7f", 1);
+        assert!(value == option::some(127) && len == 1, 1);
+    }
+
+    #[test]
+    fun read_uleb128_multi_byte() {
+        let (value, len) = bytes::read_uleb128(&x"e58e26ff", 0);
+        assert!(value == option::some(624485) && len == 3, 0);
+        let (value, len) = bytes::read_uleb128(&x"ffffffffffffffffff01", 0);
+        assert!(value == option::some(18446744073709551615) && len == 10, 1);
+    }
+
+    #[test]
+    fun read_uleb128_malformed() {
+        // overlong: 1 encoded with a redundant trailing zero byte
+        let (value, len) = bytes::read_uleb128(&x"8100", 0);
+        assert!(option::is_none(&value) && len == 0, 0);
+        // overflows a u64
+        let (value, _) = bytes::read_uleb128(&x"ffffffffffffffffff02", 0);
+        assert!(option::is_none(&value), 1);
+        // truncated
+        let (value, _) = bytes::read_uleb128(&x"8080", 0);
+        assert!(option::is_none(&value), 2);
+        // offset out of range
+        let (value, _) = bytes::read_uleb128(&x"01", 2);
+        assert!(option::is_none(&value), 3);
+    }
+
+    #[test]
+    fun not_bytes_all_zero() {
+        assert!(bytes::not_bytes(&x"000000") == x"ffffff", 0);
+    }
+
+    #[test]
+    fun not_bytes_all_ones() {
+        assert!(bytes::not_bytes(&x"ffff") == x"0000", 0);
+        assert!(bytes::not_bytes(&x"0f5a") == x"f0a5", 1);
+    }
+
+    #[test]
+    fun not_bytes_empty() {
+        assert!(bytes::not_bytes(&vector[]) == vector[], 0);
+    }
+}
@@ -0,0 +1,121 @@
+#[test_only]
+module std::encoding_tests {
+    use std::encoding;
+    use std::option;
+
+    #[test]
+    fun base58check_decode_address() {
+        let payload = encoding::base58check_decode(&b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2");
+        assert!(payload == option::some(x"0077bff20c60e522dfaa3350c39b030a5d004e839a"), 0);
+    }
+
+    #[test]
+    fun base58check_decode_leading_zeros() {
+        let payload = encoding::base58check_decode(&b"112L5B5yqsz2g67");
+        assert!(payload == option::some(x"000068656c6c6f"), 0);
+    }
+
+    #[test]
+    fun base58check_decode_corrupted_checksum() {
+        // The last character of the address above is changed.
+        assert!(option::is_none(&encoding::base58check_decode(&b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3")), 0);
+    }
+
+    #[test]
+    fun base58check_decode_invalid_input() {
+        // '0' is not in the base58 alphabet.
+        assert!(option::is_none(&encoding::base58check_decode(&b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN0")), 0);
+        // Too short to hold a checksum.
+        assert!(option::is_none(&encoding::base58check_decode(&b"111")), 1);
+        assert!(option::is_none(&encoding::base58check_decode(&b"")), 2);
+    }
+
+    #[test]
+    fun base58check_decode_length_limit() {
+        // 89 and 90 bytes of 0x42 with valid checksums, encoded in 127 and 129 characters.
+        let payload = encoding::base58check_decode(&b"GPwQdV2oMaXVLSCuxHUJGFdoKviggexHDKyKgezmo38ZgcQdxwmKr2s1tQmbxHupxtLKkL7tb6YaRpqEfqLkQpKywLbF95niVAKXpV3ZmNFisWmfSFduVwCAYNDajfy");
+        assert!(option::is_some(&payload), 0);
+        assert!(std::vector::length(option::borrow(&payload)) == 89, 1);
+        assert!(option::is_none(&encoding::base58check_decode(&b"2AxF9J2iwbpya4nFaj1hVCKbRUY6zA2WzZQjbU2hziCQPFqHL9vieBRDkuuusH3eAhfaHkFPQhVUNYa7uLQNAc7tnJqU9Qg8n7J8o2anKcpoyoxQ8eTbtiiCH7Xp9xnRN")), 2);
+    }
+
+    #[test]
+    fun rle_encode_runs() {
+        assert!(encoding::rle_encode(&x"000000ff0101") == x"030001ff0201", 0);
+        assert!(encoding::rle_encode(&x"") == x"", 1);
+        assert!(encoding::rle_encode(&x"2a") == x"012a", 2);
+    }
+
+    #[test]
+    fun rle_round_trip() {
+        // 8 zeros, 0x07, 5 zeros and two 0x09
+        let bytes = x"00000000000000000700000000000909";
+        let encoded = encoding::rle_encode(&bytes);
+        assert!(encoded == x"0800010705000209", 0);
+        assert!(encoding::rle_decode(&encoded) == option::some(bytes), 1);
+        assert!(encoding::rle_decode(&x"") == option::some(x""), 2);
+    }
+
+    #[test]
+    fun rle_round_trip_long_run() {
+        // A run of 300 zeros is split into runs of 255 and 45.
+        let bytes = std::vector::empty();
+        let i = 0;
+        while (i < 300) {
+            std::vector::push_back(&mut bytes, 0);
+            i = i + 1;
+        };
+        let encoded = encoding::rle_encode(&bytes);
+        assert!(encoded == x"ff002d00", 0);
+        assert!(encoding::rle_decode(&encoded) == option::some(bytes), 1);
+    }
+
+    #[test]
+    fun rle_decode_malformed() {
+        // Odd length
+        assert!(option::is_none(&encoding::rle_decode(&x"0300ff")), 0);
+        // Zero count
+        assert!(option::is_none(&encoding::rle_decode(&x"0300002a")), 1);
+    }
+
+    #[test]
+    fun delta_round_trip_increasing() {
+        let v = vector[1000, 1001, 1003, 1003, 1200];
+        let encoded = encoding::delta_encode_u64(&v);
+        // 1000, then the deltas 1, 2, 0 and 197 zig-zag encoded as 2, 4, 0 and 394.
+        assert!(encoded == x"e8070204008a03", 0);
+        assert!(encoding::delta_decode_u64(&encoded) == option::some(v), 1);
+    }
+
+    #[test]
+    fun delta_round_trip_decreasing() {
+        let v = vector[500, 499, 300, 0];
+        let encoded = encoding::delta_encode_u64(&v);
+        // 500, then the deltas -1, -199 and -300 zig-zag encoded as 1, 397 and 599.
+        assert!(encoded == x"f403018d03d704", 0);
+        assert!(encoding::delta_decode_u64(&encoded) == option::some(v), 1);
+    }
+
+    #[test]
+    fun delta_round_trip_extremes() {
+        // The differences wrap around.
+        let v = vector[0, 18446744073709551615, 0, 9223372036854775808, 1];
+        assert!(encoding::delta_decode_u64(&encoding::delta_encode_u64(&v)) == option::some(v), 0);
+        let v = vector[];
+        assert!(encoding::delta_encode_u64(&v) == x"", 1);
+        assert!(encoding::delta_decode_u64(&x"") == option::some(v), 2);
+        let v = vector[42];
+        assert!(encoding::delta_encode_u64(&v) == x"2a", 3);
+        assert!(encoding::delta_decode_u64(&x"2a") == option::some(v), 4);
+    }
+
+    #[test]
+    fun delta_decode_malformed() {
+        // Truncated delta
+        assert!(option::is_none(&encoding::delta_decode_u64(&x"e80782")), 0);
+        // Overlong encoding of the first value
+        assert!(option::is_none(&encoding::delta_decode_u64(&x"8000")), 1);
+        // Overflows a u64
+        assert!(option::is_none(&encoding::delta_decode_u64(&x"ffffffffffffffffff02")), 2);
+    }
+}
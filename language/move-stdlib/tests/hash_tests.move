@@ -15,4 +15,199 @@ module std::hash_tests {
         let expected_output = x"3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532";
         assert!(hash::sha3_256(input) == expected_output, 0);
     }
+
+    #[test]
+    fun keccak_256_expected_hash() {
+        let input = x"616263";
+        let expected_output = x"4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+        assert!(hash::keccak_256(input) == expected_output, 0);
+        let expected_empty = x"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+        assert!(hash::keccak_256(x"") == expected_empty, 1);
+        let expected_long = x"05decf93cb1867fae14d0f331f88a5a0ad6a0127f544bebc00fccda5102dcc33";
+        assert!(hash::keccak_256(long_input()) == expected_long, 2);
+    }
+
+    #[test]
+    fun blake2b_256_expected_hash() {
+        let input = x"616263";
+        let expected_output = x"bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319";
+        assert!(hash::blake2b_256(input) == expected_output, 0);
+        let expected_empty = x"0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8";
+        assert!(hash::blake2b_256(x"") == expected_empty, 1);
+        let expected_long = x"43b679acbd5cafb246505193cd92879906a65ce97295e75272d0d2b8f6f9f5a9";
+        assert!(hash::blake2b_256(long_input()) == expected_long, 2);
+    }
+
+    // Longer than the block sizes of Keccak-256 (136 bytes) and BLAKE2b (128 bytes), so that the
+    // input spans several blocks.
+    fun long_input(): vector<u8> {
+        b"The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog. Pack my box with five dozen liquor jugs."
+    }
+
+    // Test vectors from the SipHash reference implementation: the key is 00 01 .. 0f and the
+    // input of length n is 00 01 .. (n - 1).
+    #[test]
+    fun siphash_reference_vectors() {
+        let key = x"000102030405060708090a0b0c0d0e0f";
+        assert!(hash::siphash(&key, &x"") == 0x726fdb47dd0e0e31, 0);
+        assert!(hash::siphash(&key, &x"00") == 0x74f839c593dc67fd, 1);
+        assert!(hash::siphash(&key, &x"0001020304050607") == 0x93f5f5799a932462, 2);
+        assert!(hash::siphash(&key, &x"000102030405060708090a0b0c0d0e") == 0xa129ca6149be45e5, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = hash::EINVALID_SIPHASH_KEY)]
+    fun siphash_invalid_key_length() {
+        hash::siphash(&x"000102030405060708090a0b0c0d0e", &x"00");
+    }
+
+    #[test]
+    fun derive_id_expected_id() {
+        let id = hash::derive_id(&b"starcoin", &b"object");
+        assert!(id == x"4f4f462bfbf852dc86b8cf247d578af0", 0);
+        // The id is deterministic.
+        assert!(hash::derive_id(&b"starcoin", &b"object") == id, 1);
+        assert!(hash::derive_id(&x"", &x"") == x"a7ffc6f8bf1e576691c14756a061d662", 2);
+    }
+
+    #[test]
+    fun derive_id_different_inputs() {
+        let id = hash::derive_id(&b"starcoin", &b"object");
+        assert!(hash::derive_id(&b"starcoin", &b"objects") != id, 0);
+        assert!(hash::derive_id(&b"aptos", &b"object") != id, 1);
+    }
+
+    #[test]
+    fun hkdf_sha256_rfc5869_vectors() {
+        // Test case 1
+        let okm = hash::hkdf_sha256(
+            &x"000102030405060708090a0b0c",
+            &x"0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            &x"f0f1f2f3f4f5f6f7f8f9",
+            42,
+        );
+        assert!(okm == x"3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865", 0);
+        // Test case 2
+        let okm = hash::hkdf_sha256(
+            &x"606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeaf",
+            &x"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f",
+            &x"b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+            82,
+        );
+        assert!(okm == x"b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87", 1);
+        // Test case 3, with an empty salt and info
+        let okm = hash::hkdf_sha256(&x"", &x"0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b", &x"", 42);
+        assert!(okm == x"8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8", 2);
+    }
+
+    #[test]
+    fun hkdf_sha256_max_length() {
+        let okm = hash::hkdf_sha256(&x"", &x"00", &x"", 255 * 32);
+        assert!(std::vector::length(&okm) == 255 * 32, 0);
+        assert!(std::vector::is_empty(&hash::hkdf_sha256(&x"", &x"00", &x"", 0)), 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = hash::EHKDF_LENGTH_TOO_LARGE)]
+    fun hkdf_sha256_length_too_large() {
+        hash::hkdf_sha256(&x"", &x"00", &x"", 255 * 32 + 1);
+    }
+
+    #[test]
+    fun verify_commitment_correct_reveal() {
+        let reveal = b"my secret bid: 42";
+        let commitment = hash::sha3_256(reveal);
+        assert!(hash::verify_commitment(&commitment, &reveal), 0);
+        assert!(hash::verify_commitment(&hash::sha3_256(x""), &x""), 1);
+    }
+
+    #[test]
+    fun verify_commitment_wrong_reveal() {
+        let commitment = hash::sha3_256(b"my secret bid: 42");
+        assert!(!hash::verify_commitment(&commitment, &b"my secret bid: 43"), 0);
+        assert!(!hash::verify_commitment(&commitment, &x""), 1);
+    }
+
+    #[test]
+    fun verify_commitment_malformed_length() {
+        let reveal = b"my secret bid: 42";
+        let commitment = hash::sha3_256(reveal);
+        // A truncated commitment
+        std::vector::pop_back(&mut commitment);
+        assert!(!hash::verify_commitment(&commitment, &reveal), 0);
+        assert!(!hash::verify_commitment(&x"", &reveal), 1);
+        // A commitment with trailing bytes
+        let commitment = hash::sha3_256(reveal);
+        std::vector::push_back(&mut commitment, 0);
+        assert!(!hash::verify_commitment(&commitment, &reveal), 2);
+    }
+
+    struct Person has drop {
+        name: vector<u8>,
+        wallet: address,
+    }
+
+    struct Mail has drop {
+        from: Person,
+        to: Person,
+        contents: vector<u8>,
+    }
+
+    struct Numbers has drop {
+        flag: bool,
+        small: u8,
+        big: u256,
+        values: vector<u64>,
+        people: vector<Person>,
+    }
+
+    // The expected hashes are computed with an off-chain implementation of the scheme. With 16
+    // byte addresses, the type of `Person` is encoded as
+    // `00000000000000000000000000000001::hash_tests::Person(vector<u8> name,address wallet)`.
+    #[test]
+    fun typed_struct_hash_flat_struct() {
+        let person = Person { name: b"Cow", wallet: @0xCAFE };
+        let expected = x"a4f30d2daea3fee23f12d931c03450444d2690d274e3b9d9e6bded4565bf36cb";
+        assert!(hash::typed_struct_hash(&person) == expected, 0);
+    }
+
+    #[test]
+    fun typed_struct_hash_nested_structs() {
+        let mail = Mail {
+            from: Person { name: b"Cow", wallet: @0xCAFE },
+            to: Person { name: b"Bob", wallet: @0xBEEF },
+            contents: b"Hello, Bob!",
+        };
+        let expected = x"1327959c10fd1fe7ce4e2f43b01ca58994805493cb8376a365ac56fccb8a8d2c";
+        assert!(hash::typed_struct_hash(&mail) == expected, 0);
+    }
+
+    #[test]
+    fun typed_struct_hash_numbers_and_vectors() {
+        let numbers = Numbers {
+            flag: true,
+            small: 255,
+            big: 115792089237316195423570985008687907853269984665640564039457584007913129639935,
+            values: vector[1, 2, 3],
+            people: vector[
+                Person { name: b"Cow", wallet: @0xCAFE },
+                Person { name: b"Bob", wallet: @0xBEEF },
+            ],
+        };
+        let expected = x"0784a219aa3cea7e13d96ca7743cd157c2c1475cbaf5f0dc4100c4164c4aa09f";
+        assert!(hash::typed_struct_hash(&numbers) == expected, 0);
+    }
+
+    #[test]
+    fun typed_struct_hash_depends_on_field_values() {
+        let cow = Person { name: b"Cow", wallet: @0xCAFE };
+        let other = Person { name: b"Cow", wallet: @0xCAFF };
+        assert!(hash::typed_struct_hash(&cow) != hash::typed_struct_hash(&other), 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 3, location = std::hash)] // ENOT_A_STRUCT
+    fun typed_struct_hash_not_a_struct() {
+        hash::typed_struct_hash(&42);
+    }
 }
@@ -0,0 +1,266 @@
+#[test_only]
+module std::math_tests {
+    use std::math;
+    use std::option;
+
+    #[test]
+    fun rotate_by_zero() {
+        assert!(math::rotl_u64(0x8000000000000001, 0) == 0x8000000000000001, 0);
+        assert!(math::rotr_u64(0x8000000000000001, 0) == 0x8000000000000001, 1);
+        assert!(math::rotl_u128(0xff, 0) == 0xff, 2);
+        assert!(math::rotr_u128(0xff, 0) == 0xff, 3);
+        assert!(math::rotl_u256(0xff, 0) == 0xff, 4);
+        assert!(math::rotr_u256(0xff, 0) == 0xff, 5);
+    }
+
+    #[test]
+    fun rotate_by_full_width() {
+        assert!(math::rotl_u64(0x0123456789abcdef, 64) == 0x0123456789abcdef, 0);
+        assert!(math::rotr_u64(0x0123456789abcdef, 64) == 0x0123456789abcdef, 1);
+        assert!(math::rotl_u128(0x0123456789abcdef, 128) == 0x0123456789abcdef, 2);
+        assert!(math::rotr_u128(0x0123456789abcdef, 128) == 0x0123456789abcdef, 3);
+        assert!(math::rotl_u256(0x0123456789abcdef, 256) == 0x0123456789abcdef, 4);
+        assert!(math::rotr_u256(0x0123456789abcdef, 256) == 0x0123456789abcdef, 5);
+    }
+
+    #[test]
+    fun rotate_partial() {
+        assert!(math::rotl_u64(0x8000000000000001, 1) == 3, 0);
+        assert!(math::rotr_u64(0x8000000000000001, 1) == 0xc000000000000000, 1);
+        // `n` is taken modulo the bit width.
+        assert!(math::rotl_u64(0x8000000000000001, 65) == 3, 2);
+        assert!(math::rotl_u128(1, 127) == 0x80000000000000000000000000000000, 3);
+        assert!(math::rotr_u128(1, 1) == 0x80000000000000000000000000000000, 4);
+        assert!(math::rotl_u256(0xf, 254) == 0xc000000000000000000000000000000000000000000000000000000000000003, 5);
+        assert!(math::rotr_u256(0xf, 2) == 0xc000000000000000000000000000000000000000000000000000000000000003, 6);
+    }
+
+    #[test]
+    fun gcd_lcm_coprime() {
+        assert!(math::gcd_u128(9, 28) == 1, 0);
+        assert!(math::lcm_u128(9, 28) == 252, 1);
+        assert!(math::gcd_u128(12, 18) == 6, 2);
+        assert!(math::lcm_u128(12, 18) == 36, 3);
+    }
+
+    #[test]
+    fun gcd_lcm_with_zero() {
+        assert!(math::gcd_u128(0, 5) == 5, 0);
+        assert!(math::gcd_u128(5, 0) == 5, 1);
+        assert!(math::gcd_u128(0, 0) == 0, 2);
+        assert!(math::lcm_u128(0, 5) == 0, 3);
+        assert!(math::lcm_u128(0, 0) == 0, 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = math::EOVERFLOW)]
+    fun lcm_overflow() {
+        math::lcm_u128(340282366920938463463374607431768211455, 2);
+    }
+
+    #[test]
+    fun abs_diff_greater_first() {
+        assert!(math::abs_diff_u64(10, 3) == 7, 0);
+        assert!(math::abs_diff_u128(340282366920938463463374607431768211455, 0) == 340282366920938463463374607431768211455, 1);
+        assert!(math::abs_diff_u256(1000, 1) == 999, 2);
+    }
+
+    #[test]
+    fun abs_diff_smaller_first() {
+        assert!(math::abs_diff_u64(3, 10) == 7, 0);
+        assert!(math::abs_diff_u128(0, 340282366920938463463374607431768211455) == 340282366920938463463374607431768211455, 1);
+        assert!(math::abs_diff_u256(1, 1000) == 999, 2);
+    }
+
+    #[test]
+    fun abs_diff_equal() {
+        assert!(math::abs_diff_u64(18446744073709551615, 18446744073709551615) == 0, 0);
+        assert!(math::abs_diff_u128(5, 5) == 0, 1);
+        assert!(math::abs_diff_u256(0, 0) == 0, 2);
+    }
+
+    #[test]
+    fun sqrt_perfect_squares() {
+        assert!(math::sqrt_u128(4) == 2, 0);
+        assert!(math::sqrt_u128(144) == 12, 1);
+        assert!(math::sqrt_u128(18446744073709551616) == 4294967296, 2);
+    }
+
+    #[test]
+    fun sqrt_rounds_down() {
+        assert!(math::sqrt_u128(2) == 1, 0);
+        assert!(math::sqrt_u128(8) == 2, 1);
+        assert!(math::sqrt_u128(143) == 11, 2);
+        assert!(math::sqrt_u128(18446744073709551615) == 4294967295, 3);
+    }
+
+    #[test]
+    fun sqrt_zero_and_one() {
+        assert!(math::sqrt_u128(0) == 0, 0);
+        assert!(math::sqrt_u128(1) == 1, 1);
+    }
+
+    #[test]
+    fun sqrt_max() {
+        assert!(math::sqrt_u128(340282366920938463463374607431768211455) == 18446744073709551615, 0);
+    }
+
+    #[test]
+    fun pack_u64_pair_layout() {
+        assert!(math::pack_u64_pair(0, 0) == 0, 0);
+        assert!(math::pack_u64_pair(0, 1) == 1, 1);
+        assert!(math::pack_u64_pair(1, 0) == 18446744073709551616, 2);
+        assert!(math::pack_u64_pair(18446744073709551615, 18446744073709551615) == 340282366920938463463374607431768211455, 3);
+    }
+
+    #[test]
+    fun u64_pair_round_trip() {
+        let max = 18446744073709551615;
+        let (hi, lo) = math::unpack_u64_pair(math::pack_u64_pair(max, 0));
+        assert!(hi == max && lo == 0, 0);
+        let (hi, lo) = math::unpack_u64_pair(math::pack_u64_pair(0, max));
+        assert!(hi == 0 && lo == max, 1);
+        let (hi, lo) = math::unpack_u64_pair(math::pack_u64_pair(42, 7));
+        assert!(hi == 42 && lo == 7, 2);
+        let x = 340282366920938463463374607431768211455;
+        let (hi, lo) = math::unpack_u64_pair(x);
+        assert!(math::pack_u64_pair(hi, lo) == x, 3);
+    }
+
+    #[test]
+    fun log2_powers_of_two() {
+        assert!(math::log2_u128(1) == 0, 0);
+        assert!(math::log2_u128(2) == 1, 1);
+        assert!(math::log2_u128(1024) == 10, 2);
+        assert!(math::log2_u128(18446744073709551616) == 64, 3);
+        assert!(math::log2_u128(170141183460469231731687303715884105728) == 127, 4);
+    }
+
+    #[test]
+    fun log2_rounds_down() {
+        assert!(math::log2_u128(3) == 1, 0);
+        assert!(math::log2_u128(1023) == 9, 1);
+        assert!(math::log2_u128(1025) == 10, 2);
+        assert!(math::log2_u128(340282366920938463463374607431768211455) == 127, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = math::EZERO)]
+    fun log2_zero() {
+        math::log2_u128(0);
+    }
+
+    #[test]
+    fun next_power_of_two_small() {
+        assert!(math::next_power_of_two_u64(0) == 1, 0);
+        assert!(math::next_power_of_two_u64(1) == 1, 1);
+        assert!(math::next_power_of_two_u64(2) == 2, 2);
+    }
+
+    #[test]
+    fun next_power_of_two_rounds_up() {
+        assert!(math::next_power_of_two_u64(64) == 64, 0);
+        assert!(math::next_power_of_two_u64(65) == 128, 1);
+        assert!(math::next_power_of_two_u64(1000) == 1024, 2);
+        // 2^63 is the largest power of two which fits into a u64.
+        assert!(math::next_power_of_two_u64(9223372036854775808) == 9223372036854775808, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = math::EOVERFLOW)]
+    fun next_power_of_two_overflow() {
+        math::next_power_of_two_u64(9223372036854775809);
+    }
+
+    #[test]
+    fun bigint_add_equal_length() {
+        // 0x0201 + 0x0403
+        assert!(math::bigint_add(&x"0102", &x"0304") == x"0406", 0);
+        // 2^64 + 2^64 - 1
+        assert!(math::bigint_add(&x"000000000000000001", &x"ffffffffffffffff00") == x"ffffffffffffffff01", 1);
+    }
+
+    #[test]
+    fun bigint_add_carry_extends_result() {
+        assert!(math::bigint_add(&x"ff", &x"01") == x"0001", 0);
+        // The carry ripples through all bytes of the longer operand.
+        assert!(math::bigint_add(&x"ffffff", &x"01") == x"00000001", 1);
+        assert!(math::bigint_add(&x"01", &x"ffffff") == x"00000001", 2);
+    }
+
+    #[test]
+    fun bigint_add_zero() {
+        assert!(math::bigint_add(&x"3412", &x"") == x"3412", 0);
+        assert!(math::bigint_add(&x"", &x"3412") == x"3412", 1);
+        assert!(math::bigint_add(&x"", &x"") == x"", 2);
+        // Trailing zero bytes are dropped from the sum.
+        assert!(math::bigint_add(&x"341200", &x"0000") == x"3412", 3);
+        assert!(math::bigint_add(&x"00", &x"00") == x"", 4);
+    }
+
+    #[test]
+    fun div_exact() {
+        assert!(math::floor_div_u128(12, 4) == 3, 0);
+        assert!(math::ceil_div_u128(12, 4) == 3, 1);
+        assert!(math::floor_div_u128(0, 7) == 0, 2);
+        assert!(math::ceil_div_u128(0, 7) == 0, 3);
+    }
+
+    #[test]
+    fun div_rounding() {
+        assert!(math::floor_div_u128(13, 4) == 3, 0);
+        assert!(math::ceil_div_u128(13, 4) == 4, 1);
+        assert!(math::ceil_div_u128(1, 1000) == 1, 2);
+        // `a + b - 1` would overflow here.
+        let max = 340282366920938463463374607431768211455;
+        assert!(math::ceil_div_u128(max, 2) == 170141183460469231731687303715884105728, 3);
+        assert!(math::floor_div_u128(max, 2) == 170141183460469231731687303715884105727, 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = math::EZERO)]
+    fun floor_div_by_zero() {
+        math::floor_div_u128(1, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = math::EZERO)]
+    fun ceil_div_by_zero() {
+        math::ceil_div_u128(1, 0);
+    }
+
+    #[test]
+    fun mod_inverse_coprime() {
+        let inv = option::destroy_some(math::mod_inverse_u256(17, 3120));
+        assert!(inv == 2753, 0);
+        assert!(17 * inv % 3120 == 1, 1);
+        // `a` is reduced modulo `modulus` first.
+        assert!(math::mod_inverse_u256(17 + 3120, 3120) == option::some(2753), 2);
+        // Every value is the inverse of every other value modulo 1.
+        assert!(math::mod_inverse_u256(5, 1) == option::some(0), 3);
+    }
+
+    #[test]
+    fun mod_inverse_large_modulus() {
+        // The order of the scalar field of BN254.
+        let p = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+        let inv = option::destroy_some(math::mod_inverse_u256(3, p));
+        assert!(inv == 14592161914559516814830937163504850059032242933610689562465469457717205663745, 0);
+        assert!(3 * inv % p == 1, 1);
+        let inv = option::destroy_some(math::mod_inverse_u256(123456789, p));
+        assert!(inv == 20787191118935578199309178624525113022901363542456774658851782522558241254609, 2);
+    }
+
+    #[test]
+    fun mod_inverse_not_coprime() {
+        assert!(option::is_none(&math::mod_inverse_u256(6, 3120)), 0);
+        assert!(option::is_none(&math::mod_inverse_u256(0, 7)), 1);
+        assert!(option::is_none(&math::mod_inverse_u256(14, 7)), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = math::EZERO)]
+    fun mod_inverse_zero_modulus() {
+        math::mod_inverse_u256(3, 0);
+    }
+}
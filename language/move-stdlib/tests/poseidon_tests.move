@@ -0,0 +1,45 @@
+#[test_only]
+module std::poseidon_tests {
+    use std::poseidon;
+
+    // The expected hashes are computed off-chain with the same parameters, by an implementation
+    // which reproduces the test vectors of circomlibjs.
+    #[test]
+    fun poseidon_hash_reference_vectors() {
+        assert!(poseidon::poseidon_hash(&vector[1]) == 18586133768512220936620570745912940619677854269274689475585506675881198879027, 0);
+        assert!(poseidon::poseidon_hash(&vector[1, 2]) == 7853200120776062878684798364095072458815029376092732009249414926327459813530, 1);
+        assert!(poseidon::poseidon_hash(&vector[1, 2, 3]) == 6542985608222806190361240322586112750744169038454362455181422643027100751666, 2);
+        assert!(poseidon::poseidon_hash(&vector[1, 2, 3, 4]) == 18821383157269793795438455681495246036402687001665670618754263018637548127333, 3);
+    }
+
+    #[test]
+    fun poseidon_hash_merkle_zero() {
+        // The hash of two zero leaves, the first zero value of circuits such as Tornado Cash.
+        assert!(poseidon::poseidon_hash(&vector[0, 0]) == 0x2098f5fb9e239eab3ceac3f27b81e481dc3124d55ffed523a839ee8446b64864, 0);
+    }
+
+    #[test]
+    fun poseidon_hash_largest_field_element() {
+        let max = 21888242871839275222246405745257275088548364400416034343698204186575808495616;
+        assert!(poseidon::poseidon_hash(&vector[max, max]) == 20092309280547939997162506796691455192771288143174894022739895715370814071035, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = std::poseidon)] // EINVALID_NUM_INPUTS
+    fun poseidon_hash_no_inputs() {
+        poseidon::poseidon_hash(&vector[]);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = std::poseidon)] // EINVALID_NUM_INPUTS
+    fun poseidon_hash_too_many_inputs() {
+        poseidon::poseidon_hash(&vector[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2, location = std::poseidon)] // ENOT_A_FIELD_ELEMENT
+    fun poseidon_hash_input_not_in_field() {
+        let modulus = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+        poseidon::poseidon_hash(&vector[1, modulus]);
+    }
+}
@@ -0,0 +1,57 @@
+#[test_only]
+module std::rlp_tests {
+    use std::option;
+    use std::rlp;
+    use std::vector;
+
+    #[test]
+    fun decode_list_of_strings() {
+        // ["cat", "dog"]
+        let items = rlp::decode_list(&x"c88363617483646f67");
+        assert!(items == option::some(vector[b"cat", b"dog"]), 0);
+        // [0x0f, 0x0400, ""]
+        let items = rlp::decode_list(&x"c50f82040080");
+        assert!(items == option::some(vector[x"0f", x"0400", x""]), 1);
+    }
+
+    #[test]
+    fun decode_empty_list() {
+        assert!(rlp::decode_list(&x"c0") == option::some(vector[]), 0);
+    }
+
+    #[test]
+    fun decode_nested_lists() {
+        // [ [], [[]], [ [], [[]] ] ]
+        let items = rlp::decode_list(&x"c7c0c1c0c3c0c1c0");
+        assert!(items == option::some(vector[x"c0", x"c1c0", x"c3c0c1c0"]), 0);
+        let inner = rlp::decode_list(vector::borrow(option::borrow(&items), 2));
+        assert!(inner == option::some(vector[x"c0", x"c1c0"]), 1);
+    }
+
+    #[test]
+    fun decode_long_string() {
+        let lorem = b"Lorem ipsum dolor sit amet, consectetur adipisicing elit";
+        let bytes = x"f83ab838";
+        vector::append(&mut bytes, copy lorem);
+        assert!(rlp::decode_list(&bytes) == option::some(vector[lorem]), 0);
+    }
+
+    #[test]
+    fun decode_malformed() {
+        // Not a list.
+        assert!(option::is_none(&rlp::decode_list(&x"83646f67")), 0);
+        assert!(option::is_none(&rlp::decode_list(&x"")), 1);
+        // Truncated payload.
+        assert!(option::is_none(&rlp::decode_list(&x"c88363617483646f")), 2);
+        // Trailing bytes after the list.
+        assert!(option::is_none(&rlp::decode_list(&x"c000")), 3);
+        // Item running past the end of the list.
+        assert!(option::is_none(&rlp::decode_list(&x"c28363")), 4);
+        // Single byte below 0x80 encoded as a string.
+        assert!(option::is_none(&rlp::decode_list(&x"c28100")), 5);
+        // Long form used for a short payload.
+        assert!(option::is_none(&rlp::decode_list(&x"f80100")), 6);
+        // Length with a leading zero.
+        assert!(option::is_none(&rlp::decode_list(&x"f9003a")), 7);
+    }
+}
@@ -0,0 +1,62 @@
+#[test_only]
+module std::signature_tests {
+    use std::signature;
+    use std::vector;
+
+    // Test vectors 1 to 3 of RFC 8032, section 7.1.
+    fun signatures(): vector<vector<u8>> {
+        vector[
+            x"e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            x"92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            x"6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        ]
+    }
+
+    fun public_keys(): vector<vector<u8>> {
+        vector[
+            x"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            x"3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            x"fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        ]
+    }
+
+    fun messages(): vector<vector<u8>> {
+        vector[x"", x"72", x"af82"]
+    }
+
+    #[test]
+    fun ed25519_batch_verify_all_valid() {
+        assert!(signature::ed25519_batch_verify(&signatures(), &public_keys(), &messages()), 0);
+    }
+
+    #[test]
+    fun ed25519_batch_verify_one_invalid() {
+        // The second signature is checked against the wrong message.
+        let messages = messages();
+        *vector::borrow_mut(&mut messages, 1) = x"73";
+        assert!(!signature::ed25519_batch_verify(&signatures(), &public_keys(), &messages), 0);
+
+        // Signatures swapped between two of the keys.
+        let signatures = signatures();
+        vector::swap(&mut signatures, 0, 2);
+        assert!(!signature::ed25519_batch_verify(&signatures, &public_keys(), &messages()), 1);
+
+        // A malformed public key.
+        let public_keys = public_keys();
+        *vector::borrow_mut(&mut public_keys, 0) = x"d75a9801";
+        assert!(!signature::ed25519_batch_verify(&signatures(), &public_keys, &messages()), 2);
+    }
+
+    #[test]
+    fun ed25519_batch_verify_length_mismatch() {
+        let messages = messages();
+        vector::pop_back(&mut messages);
+        assert!(!signature::ed25519_batch_verify(&signatures(), &public_keys(), &messages), 0);
+        assert!(!signature::ed25519_batch_verify(&signatures(), &vector[], &messages()), 1);
+    }
+
+    #[test]
+    fun ed25519_batch_verify_empty() {
+        assert!(!signature::ed25519_batch_verify(&vector[], &vector[], &vector[]), 0);
+    }
+}
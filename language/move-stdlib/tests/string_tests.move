@@ -46,6 +46,26 @@ module std::string_tests {
         assert!(string::is_empty(&sub), 22)
     }
 
+    // The native aborts on its own on bad indices, without relying on the checks of `sub_string`.
+    #[test]
+    #[expected_failure(abort_code = 0x400, location = std::string)] // NFE_STRING_INVALID_ARG_FAILURE
+    fun test_internal_sub_string_past_end() {
+        string::internal_sub_string_for_testing(&b"abcd", 2, 5);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x400, location = std::string)] // NFE_STRING_INVALID_ARG_FAILURE
+    fun test_internal_sub_string_splits_multi_byte_char() {
+        let sparkle_heart = vector[97, 240, 159, 146, 150];
+        string::internal_sub_string_for_testing(&sparkle_heart, 0, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x400, location = std::string)] // NFE_STRING_INVALID_ARG_FAILURE
+    fun test_internal_sub_string_reversed_indices() {
+        string::internal_sub_string_for_testing(&b"abcd", 3, 1);
+    }
+
     #[test]
     fun test_index_of() {
         let s = string::utf8(b"abcd");
@@ -75,4 +95,82 @@ module std::string_tests {
         string::insert(&mut s, 1, string::utf8(b"xy"));
         assert!(s == string::utf8(b"axybcd"), 22)
     }
+
+    #[test]
+    fun test_char_count_ascii() {
+        let s = string::utf8(b"abcd");
+        assert!(string::char_count(&s) == string::length(&s), 22)
+    }
+
+    #[test]
+    fun test_char_count_multi_byte() {
+        // U+0068, U+00E9 and U+1F496, encoded in 1, 2 and 4 bytes respectively.
+        let s = string::utf8(vector[104, 195, 169, 240, 159, 146, 150]);
+        assert!(string::char_count(&s) == 3, 22);
+        assert!(string::length(&s) == 7, 22)
+    }
+
+    #[test]
+    fun test_char_count_empty() {
+        let s = string::utf8(b"");
+        assert!(string::char_count(&s) == 0, 22)
+    }
+
+    #[test]
+    fun test_split_lines_lf() {
+        let lines = string::split_lines(&string::utf8(b"key=1\nname=x\n"));
+        assert!(lines == vector[string::utf8(b"key=1"), string::utf8(b"name=x")], 22)
+    }
+
+    #[test]
+    fun test_split_lines_crlf() {
+        let lines = string::split_lines(&string::utf8(b"key=1\r\nname=x\r\n"));
+        assert!(lines == vector[string::utf8(b"key=1"), string::utf8(b"name=x")], 22);
+        // A `\r` which does not precede a `\n` is kept.
+        let lines = string::split_lines(&string::utf8(b"a\rb\r\nc\r"));
+        assert!(lines == vector[string::utf8(b"a\rb"), string::utf8(b"c\r")], 22)
+    }
+
+    #[test]
+    fun test_split_lines_no_trailing_newline() {
+        let lines = string::split_lines(&string::utf8(b"key=1\n\nname=x"));
+        assert!(lines == vector[string::utf8(b"key=1"), string::utf8(b""), string::utf8(b"name=x")], 22);
+        let lines = string::split_lines(&string::utf8(b"\n"));
+        assert!(lines == vector[string::utf8(b"")], 22)
+    }
+
+    #[test]
+    fun test_split_lines_empty() {
+        assert!(string::split_lines(&string::utf8(b"")) == vector[], 22)
+    }
+
+    #[test]
+    fun test_case_mapping_ascii() {
+        let s = string::utf8(b"Alice.Move-42");
+        assert!(string::to_lowercase(&s) == string::utf8(b"alice.move-42"), 22);
+        assert!(string::to_uppercase(&s) == string::utf8(b"ALICE.MOVE-42"), 22)
+    }
+
+    #[test]
+    fun test_case_mapping_multi_byte() {
+        // "Strasse" spelled with a sharp s (U+00DF, 2 bytes), which uppercases to "SS".
+        let s = string::utf8(x"53747261c39f65");
+        assert!(string::to_uppercase(&s) == string::utf8(b"STRASSE"), 22);
+        assert!(string::to_lowercase(&s) == string::utf8(x"73747261c39f65"), 22);
+        // The capital sharp s (U+1E9E, 3 bytes) lowercases to the sharp s (2 bytes).
+        let s = string::utf8(x"e1ba9e");
+        let lower = string::to_lowercase(&s);
+        assert!(lower == string::utf8(x"c39f"), 22);
+        assert!(string::length(&lower) == 2, 22);
+        // The dotless i (U+0131, 2 bytes) uppercases to `I`.
+        let upper = string::to_uppercase(&string::utf8(x"c4b1"));
+        assert!(upper == string::utf8(b"I"), 22)
+    }
+
+    #[test]
+    fun test_case_mapping_empty() {
+        let s = string::utf8(b"");
+        assert!(string::to_lowercase(&s) == s, 22);
+        assert!(string::to_uppercase(&s) == s, 22)
+    }
 }
@@ -0,0 +1,10 @@
+#[test_only]
+module std::timestamp_tests {
+    use std::timestamp;
+
+    #[test]
+    #[expected_failure(abort_code = timestamp::ETIMESTAMP_NOT_AVAILABLE, location = std::timestamp)]
+    fun now_microseconds_without_timestamp() {
+        timestamp::now_microseconds();
+    }
+}
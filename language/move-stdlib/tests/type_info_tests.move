@@ -0,0 +1,108 @@
+#[test_only]
+module std::type_info_tests {
+    use std::option;
+    use std::type_info;
+
+    struct Point has drop { x: u64, y: u64, z: u64 }
+
+    struct Wrapper<T> has drop { inner: T, tag: u8 }
+
+    struct Pair has copy, drop { a: u64, b: u64 }
+
+    struct Resource has key, store { value: u64 }
+
+    struct Box<T> has copy, drop, store { inner: T }
+
+    #[test]
+    fun num_fields_struct() {
+        assert!(type_info::num_fields<Point>() == 3, 0);
+        assert!(type_info::num_fields<Wrapper<Point>>() == 2, 1);
+        assert!(type_info::num_fields<std::option::Option<u64>>() == 1, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = type_info::ENOT_A_STRUCT, location = std::type_info)]
+    fun num_fields_not_a_struct() {
+        type_info::num_fields<u64>();
+    }
+
+    #[test]
+    #[expected_failure(abort_code = type_info::ENOT_A_STRUCT, location = std::type_info)]
+    fun num_fields_vector() {
+        type_info::num_fields<vector<Point>>();
+    }
+
+    #[test]
+    fun type_eq_identical_instantiations() {
+        assert!(type_info::type_eq<u64, u64>(), 0);
+        assert!(type_info::type_eq<Point, Point>(), 1);
+        assert!(type_info::type_eq<Wrapper<vector<u8>>, Wrapper<vector<u8>>>(), 2);
+    }
+
+    #[test]
+    fun type_eq_different_primitives() {
+        assert!(!type_info::type_eq<u64, u128>(), 0);
+        assert!(!type_info::type_eq<address, vector<u8>>(), 1);
+        assert!(!type_info::type_eq<u8, Point>(), 2);
+    }
+
+    #[test]
+    fun type_eq_different_type_arguments() {
+        assert!(!type_info::type_eq<Wrapper<u64>, Wrapper<u8>>(), 0);
+        assert!(!type_info::type_eq<Wrapper<Point>, Wrapper<Wrapper<Point>>>(), 1);
+    }
+
+    #[test]
+    fun module_hash_published_module() {
+        let hash = type_info::module_hash(@std, &b"type_info");
+        assert!(option::is_some(&hash), 0);
+        assert!(std::vector::length(option::borrow(&hash)) == 32, 1);
+    }
+
+    #[test]
+    fun module_hash_unpublished_module() {
+        assert!(option::is_none(&type_info::module_hash(@0x42, &b"type_info")), 0);
+        assert!(option::is_none(&type_info::module_hash(@std, &b"no_such_module")), 1);
+        // Not a valid identifier.
+        assert!(option::is_none(&type_info::module_hash(@std, &b"type info")), 2);
+    }
+
+    #[test]
+    fun has_ability_copy_drop_struct() {
+        assert!(type_info::has_ability<Pair>(type_info::ability_copy()), 0);
+        assert!(type_info::has_ability<Pair>(type_info::ability_drop()), 1);
+        assert!(!type_info::has_ability<Pair>(type_info::ability_store()), 2);
+        assert!(!type_info::has_ability<Pair>(type_info::ability_key()), 3);
+    }
+
+    #[test]
+    fun has_ability_key_store_resource() {
+        assert!(!type_info::has_ability<Resource>(type_info::ability_copy()), 0);
+        assert!(!type_info::has_ability<Resource>(type_info::ability_drop()), 1);
+        assert!(type_info::has_ability<Resource>(type_info::ability_store()), 2);
+        assert!(type_info::has_ability<Resource>(type_info::ability_key()), 3);
+    }
+
+    #[test]
+    fun has_ability_primitive() {
+        assert!(type_info::has_ability<u64>(type_info::ability_copy()), 0);
+        assert!(type_info::has_ability<u64>(type_info::ability_drop()), 1);
+        assert!(type_info::has_ability<u64>(type_info::ability_store()), 2);
+        assert!(!type_info::has_ability<u64>(type_info::ability_key()), 3);
+    }
+
+    #[test]
+    fun has_ability_instantiation() {
+        // A generic struct only has the abilities which all of its type arguments have.
+        assert!(type_info::has_ability<Box<u64>>(type_info::ability_copy()), 0);
+        assert!(!type_info::has_ability<Box<Resource>>(type_info::ability_copy()), 1);
+        assert!(type_info::has_ability<Box<Resource>>(type_info::ability_store()), 2);
+        assert!(!type_info::has_ability<vector<Resource>>(type_info::ability_drop()), 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = type_info::EINVALID_ABILITY, location = std::type_info)]
+    fun has_ability_invalid_encoding() {
+        type_info::has_ability<u64>(0x3);
+    }
+}
@@ -0,0 +1,29 @@
+#[test_only]
+module std::util_tests {
+    use std::util;
+
+    #[test]
+    fun assert_with_code_passes() {
+        util::assert_with_code(true, 42);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 42, location = std::util)]
+    fun assert_with_code_aborts() {
+        util::assert_with_code(false, 42);
+    }
+
+    #[test]
+    fun zeroize_clears_bytes() {
+        let secret = x"deadbeef00ff";
+        util::zeroize(&mut secret);
+        assert!(secret == x"000000000000", 0);
+    }
+
+    #[test]
+    fun zeroize_empty() {
+        let v = vector[];
+        util::zeroize(&mut v);
+        assert!(v == vector[], 0);
+    }
+}
@@ -5,6 +5,7 @@ module std::vector_tests {
     struct R has store { }
     struct Droppable has drop {}
     struct NotDroppable {}
+    struct Pair has copy, drop { a: u64, b: u64 }
 
     #[test]
     fun test_singleton_contains() {
@@ -437,6 +438,49 @@ module std::vector_tests {
         assert!(index == 1, 1);
     }
 
+    #[test]
+    fun native_index_of_front_and_back() {
+        let v = vector[3, 5, 7, 9];
+        let (has, index) = V::native_index_of(&v, &3);
+        assert!(has, 0);
+        assert!(index == 0, 1);
+        let (has, index) = V::native_index_of(&v, &9);
+        assert!(has, 2);
+        assert!(index == 3, 3);
+    }
+
+    #[test]
+    fun native_index_of_not_found() {
+        let (has, index) = V::native_index_of(&vector[3, 5, 7, 9], &4);
+        assert!(!has, 0);
+        assert!(index == 0, 1);
+        let (has, index) = V::native_index_of(&V::empty<u64>(), &3);
+        assert!(!has, 2);
+        assert!(index == 0, 3);
+    }
+
+    #[test]
+    fun native_index_of_compares_structs_by_value() {
+        let v = vector[Pair { a: 1, b: 2 }, Pair { a: 2, b: 1 }];
+        let (has, index) = V::native_index_of(&v, &Pair { a: 2, b: 1 });
+        assert!(has, 0);
+        assert!(index == 1, 1);
+        let (has, index) = V::native_index_of(&v, &Pair { a: 1, b: 1 });
+        assert!(!has, 2);
+        assert!(index == 0, 3);
+    }
+
+    #[test]
+    fun contains() {
+        let v = vector[3, 5, 7, 9];
+        assert!(V::contains(&v, &3), 0);
+        assert!(V::contains(&v, &9), 1);
+        assert!(!V::contains(&v, &4), 2);
+        assert!(!V::contains(&V::empty<u64>(), &3), 3);
+        assert!(V::contains(&vector[Pair { a: 1, b: 2 }], &Pair { a: 1, b: 2 }), 4);
+        assert!(!V::contains(&vector[Pair { a: 1, b: 2 }], &Pair { a: 2, b: 1 }), 5);
+    }
+
     #[test]
     fun length() {
         let empty = V::empty();
@@ -503,4 +547,433 @@ module std::vector_tests {
             NotDroppable {}
         );
     }
+
+    #[test]
+    fun dot_product_u64_equal_lengths() {
+        assert!(V::dot_product_u64(&vector[], &vector[]) == 0, 0);
+        assert!(V::dot_product_u64(&vector[1, 2, 3], &vector[4, 5, 6]) == 32, 1);
+    }
+
+    #[test]
+    fun dot_product_u64_does_not_overflow_u64() {
+        let max = 18446744073709551615u64;
+        assert!(
+            V::dot_product_u64(&vector[max, 2], &vector[max, 3]) == 340282366920938463426481119284349108231,
+            0
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 4, location = std::vector)] // VEC_LENGTH_MISMATCH
+    fun dot_product_u64_length_mismatch() {
+        V::dot_product_u64(&vector[1, 2], &vector[1]);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 7, location = std::vector)] // VEC_OVERFLOW
+    fun dot_product_u64_overflows_u128() {
+        let max = 18446744073709551615u64;
+        V::dot_product_u64(&vector[max, max, max], &vector[max, max, max]);
+    }
+
+    #[test]
+    fun truncate_below_length() {
+        let v = vector[1, 2, 3, 4];
+        V::truncate(&mut v, 2);
+        assert!(v == vector[1, 2], 0);
+        V::truncate(&mut v, 0);
+        assert!(V::is_empty(&v), 1);
+    }
+
+    #[test]
+    fun truncate_at_length() {
+        let v = vector[1, 2, 3];
+        V::truncate(&mut v, 3);
+        assert!(v == vector[1, 2, 3], 0);
+    }
+
+    #[test]
+    fun truncate_above_length() {
+        let v = vector[Droppable {}, Droppable {}];
+        V::truncate(&mut v, 5);
+        assert!(V::length(&v) == 2, 0);
+    }
+
+    #[test]
+    fun minmax_u64_single_element() {
+        let (min, max) = V::minmax_u64(&vector[7]);
+        assert!(min == 7 && max == 7, 0);
+    }
+
+    #[test]
+    fun minmax_u64_general() {
+        let (min, max) = V::minmax_u64(&vector[5, 18446744073709551615, 0, 42, 5]);
+        assert!(min == 0, 0);
+        assert!(max == 18446744073709551615, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = std::vector)] // VEC_EMPTY
+    fun minmax_u64_empty() {
+        V::minmax_u64(&vector[]);
+    }
+
+    #[test]
+    fun is_sorted_u64_sorted() {
+        assert!(V::is_sorted_u64(&vector[0, 1, 5, 18446744073709551615]), 0);
+    }
+
+    #[test]
+    fun is_sorted_u64_unsorted() {
+        assert!(!V::is_sorted_u64(&vector[0, 5, 1]), 0);
+        assert!(!V::is_sorted_u64(&vector[2, 1]), 1);
+    }
+
+    #[test]
+    fun is_sorted_u64_equal_elements() {
+        assert!(V::is_sorted_u64(&vector[3, 3, 3]), 0);
+    }
+
+    #[test]
+    fun is_sorted_u64_empty_and_single() {
+        assert!(V::is_sorted_u64(&vector[]), 0);
+        assert!(V::is_sorted_u64(&vector[7]), 1);
+    }
+
+    #[test]
+    fun prefix_sum_u64_empty() {
+        assert!(V::prefix_sum_u64(&vector[]) == vector[], 0);
+    }
+
+    #[test]
+    fun prefix_sum_u64_single_element() {
+        assert!(V::prefix_sum_u64(&vector[7]) == vector[7], 0);
+    }
+
+    #[test]
+    fun prefix_sum_u64_general() {
+        let max = 18446744073709551615;
+        assert!(V::prefix_sum_u64(&vector[1, 2, 3]) == vector[1, 3, 6], 0);
+        assert!(
+            V::prefix_sum_u64(&vector[max, max, 1]) ==
+                vector[18446744073709551615, 36893488147419103230, 36893488147419103231],
+            1
+        );
+    }
+
+    #[test]
+    fun distinct_u64_all_distinct() {
+        let v = vector[3, 1, 4, 5, 9];
+        assert!(V::count_distinct_u64(&v) == 5, 0);
+        assert!(!V::has_duplicates_u64(&v), 1);
+        assert!(V::count_distinct_u64(&vector[]) == 0, 2);
+        assert!(!V::has_duplicates_u64(&vector[]), 3);
+    }
+
+    #[test]
+    fun distinct_u64_all_same() {
+        let v = vector[7, 7, 7, 7];
+        assert!(V::count_distinct_u64(&v) == 1, 0);
+        assert!(V::has_duplicates_u64(&v), 1);
+    }
+
+    #[test]
+    fun distinct_u64_one_duplicate() {
+        let v = vector[1, 2, 3, 2, 5];
+        assert!(V::count_distinct_u64(&v) == 4, 0);
+        assert!(V::has_duplicates_u64(&v), 1);
+    }
+
+    #[test]
+    fun weighted_median_u64_unweighted() {
+        assert!(V::weighted_median_u64(&vector[9, 1, 5], &vector[1, 1, 1]) == 5, 0);
+        // With an even length, the lower median is returned.
+        assert!(V::weighted_median_u64(&vector[4, 1, 3, 2], &vector[1, 1, 1, 1]) == 2, 1);
+    }
+
+    #[test]
+    fun weighted_median_u64_weighted() {
+        assert!(V::weighted_median_u64(&vector[10, 20, 30], &vector[1, 1, 5]) == 30, 0);
+        assert!(V::weighted_median_u64(&vector[30, 10, 20], &vector[2, 2, 1]) == 20, 1);
+        // The values up to 10 carry exactly half of the weight.
+        assert!(V::weighted_median_u64(&vector[30, 10, 20], &vector[2, 3, 1]) == 10, 2);
+        // Zero weights are ignored.
+        assert!(V::weighted_median_u64(&vector[1, 2, 3], &vector[0, 0, 4]) == 3, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 4, location = std::vector)] // VEC_LENGTH_MISMATCH
+    fun weighted_median_u64_length_mismatch() {
+        V::weighted_median_u64(&vector[1, 2], &vector[1]);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = std::vector)] // VEC_EMPTY
+    fun weighted_median_u64_empty() {
+        V::weighted_median_u64(&vector[], &vector[]);
+    }
+
+    #[test]
+    fun weighted_sample_deterministic() {
+        // The points are sha2_256(seed) modulo the total weight: 3 for b"seed" and 6 for
+        // b"lottery-42" with a total weight of 10.
+        assert!(V::weighted_sample(&vector[1, 2, 3, 4], &b"seed") == 2, 0);
+        assert!(V::weighted_sample(&vector[1, 2, 3, 4], &b"seed") == 2, 1);
+        assert!(V::weighted_sample(&vector[1, 2, 3, 4], &b"lottery-42") == 3, 2);
+        assert!(V::weighted_sample(&vector[10, 0, 0, 90], &b"seed") == 3, 3);
+    }
+
+    #[test]
+    fun weighted_sample_in_bounds() {
+        let seeds = vector[b"", b"a", b"b", b"c", b"seed", b"lottery-42"];
+        let i = 0;
+        while (i < V::length(&seeds)) {
+            let seed = V::borrow(&seeds, i);
+            assert!(V::weighted_sample(&vector[1, 2, 3, 4], seed) < 4, i);
+            // Indices with zero weight are never selected.
+            assert!(V::weighted_sample(&vector[0, 5, 0], seed) == 1, i);
+            i = i + 1;
+        };
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = std::vector)] // VEC_EMPTY
+    fun weighted_sample_empty() {
+        V::weighted_sample(&vector[], &b"seed");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = std::vector)] // VEC_EMPTY
+    fun weighted_sample_zero_weights() {
+        V::weighted_sample(&vector[0, 0], &b"seed");
+    }
+
+    #[test]
+    fun get_2d_u64_valid_access() {
+        // 2 x 3
+        let m = vector[1, 2, 3, 4, 5, 6];
+        assert!(V::get_2d_u64(&m, 2, 3, 0, 0) == 1, 0);
+        assert!(V::get_2d_u64(&m, 2, 3, 0, 2) == 3, 1);
+        assert!(V::get_2d_u64(&m, 2, 3, 1, 0) == 4, 2);
+        assert!(V::get_2d_u64(&m, 2, 3, 1, 2) == 6, 3);
+        // The same vector read as 3 x 2
+        assert!(V::get_2d_u64(&m, 3, 2, 1, 0) == 3, 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = std::vector)] // INDEX_OUT_OF_BOUNDS
+    fun get_2d_u64_row_out_of_range() {
+        V::get_2d_u64(&vector[1, 2, 3, 4, 5, 6], 2, 3, 2, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = std::vector)] // INDEX_OUT_OF_BOUNDS
+    fun get_2d_u64_col_out_of_range() {
+        // The flat index 1 * 3 + 3 would still be in range of a longer matrix.
+        V::get_2d_u64(&vector[1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3, 1, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 4, location = std::vector)] // VEC_LENGTH_MISMATCH
+    fun get_2d_u64_dimension_mismatch() {
+        V::get_2d_u64(&vector[1, 2, 3, 4, 5], 2, 3, 0, 0);
+    }
+
+    #[test]
+    fun stable_sort_by_key_u64_distinct_keys() {
+        assert!(V::stable_sort_by_key_u64(&vector[30, 10, 20]) == vector[1, 2, 0], 0);
+        assert!(V::stable_sort_by_key_u64(&vector[]) == vector[], 1);
+        assert!(V::stable_sort_by_key_u64(&vector[7]) == vector[0], 2);
+    }
+
+    #[test]
+    fun stable_sort_by_key_u64_keeps_order_of_equal_keys() {
+        // The records at indices 0, 2 and 4 share key 2, and those at 1 and 3 share key 1.
+        let keys = vector[2, 1, 2, 1, 2, 0];
+        assert!(V::stable_sort_by_key_u64(&keys) == vector[5, 1, 3, 0, 2, 4], 0);
+        assert!(V::stable_sort_by_key_u64(&vector[5, 5, 5, 5]) == vector[0, 1, 2, 3], 1);
+    }
+
+    #[test]
+    fun invert_permutation_identity() {
+        assert!(V::invert_permutation(&vector[0, 1, 2, 3]) == vector[0, 1, 2, 3], 0);
+        assert!(V::invert_permutation(&vector[]) == vector[], 1);
+    }
+
+    #[test]
+    fun invert_permutation_reversal() {
+        assert!(V::invert_permutation(&vector[3, 2, 1, 0]) == vector[3, 2, 1, 0], 0);
+    }
+
+    #[test]
+    fun invert_permutation_of_sort() {
+        let keys = vector[30, 10, 20];
+        let perm = V::stable_sort_by_key_u64(&keys);
+        // Element i of the inverse is the rank of keys[i].
+        assert!(V::invert_permutation(&perm) == vector[2, 0, 1], 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 6, location = std::vector)] // VEC_NOT_A_PERMUTATION
+    fun invert_permutation_duplicate_index() {
+        V::invert_permutation(&vector[0, 1, 1]);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 6, location = std::vector)] // VEC_NOT_A_PERMUTATION
+    fun invert_permutation_index_out_of_range() {
+        V::invert_permutation(&vector[0, 3, 1]);
+    }
+
+    #[test]
+    fun apply_permutation_u64_identity() {
+        let v = vector[7, 3, 9];
+        assert!(V::apply_permutation_u64(&v, &vector[0, 1, 2]) == v, 0);
+        assert!(V::apply_permutation_u64(&vector[], &vector[]) == vector[], 1);
+    }
+
+    #[test]
+    fun apply_permutation_u64_reversal() {
+        let v = vector[7, 3, 9, 1];
+        assert!(V::apply_permutation_u64(&v, &vector[3, 2, 1, 0]) == vector[1, 9, 3, 7], 0);
+    }
+
+    #[test]
+    fun apply_permutation_u64_of_sort() {
+        let keys = vector[30, 10, 20];
+        let perm = V::stable_sort_by_key_u64(&keys);
+        assert!(V::apply_permutation_u64(&keys, &perm) == vector[10, 20, 30], 0);
+        // Applying the inverse undoes the permutation.
+        let sorted = V::apply_permutation_u64(&keys, &perm);
+        assert!(V::apply_permutation_u64(&sorted, &V::invert_permutation(&perm)) == keys, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 4, location = std::vector)] // VEC_LENGTH_MISMATCH
+    fun apply_permutation_u64_length_mismatch() {
+        V::apply_permutation_u64(&vector[7, 3, 9], &vector[1, 0]);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 6, location = std::vector)] // VEC_NOT_A_PERMUTATION
+    fun apply_permutation_u64_not_a_permutation() {
+        V::apply_permutation_u64(&vector[7, 3, 9], &vector[0, 2, 2]);
+    }
+
+    #[test]
+    fun percentile_u64_min_and_max() {
+        let v = vector[40, 10, 30, 20, 50];
+        assert!(V::percentile_u64(&v, 0) == 10, 0);
+        assert!(V::percentile_u64(&v, 100) == 50, 1);
+        assert!(V::percentile_u64(&vector[7], 0) == 7, 2);
+        assert!(V::percentile_u64(&vector[7], 100) == 7, 3);
+    }
+
+    #[test]
+    fun percentile_u64_median() {
+        assert!(V::percentile_u64(&vector[40, 10, 30, 20, 50], 50) == 30, 0);
+        // The lower median for an even length.
+        assert!(V::percentile_u64(&vector[40, 10, 30, 20], 50) == 20, 1);
+    }
+
+    #[test]
+    fun percentile_u64_nearest_rank() {
+        let v = vector[15, 20, 35, 40, 50];
+        // ceil(0.3 * 5) = 2, ceil(0.4 * 5) = 2, ceil(0.41 * 5) = 3
+        assert!(V::percentile_u64(&v, 30) == 20, 0);
+        assert!(V::percentile_u64(&v, 40) == 20, 1);
+        assert!(V::percentile_u64(&v, 41) == 35, 2);
+        assert!(V::percentile_u64(&v, 1) == 15, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = std::vector)] // VEC_EMPTY
+    fun percentile_u64_empty() {
+        V::percentile_u64(&vector[], 50);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = std::vector)] // INDEX_OUT_OF_BOUNDS
+    fun percentile_u64_above_100() {
+        V::percentile_u64(&vector[1, 2, 3], 101);
+    }
+
+    #[test]
+    fun slice_full_range() {
+        let v = vector[1, 2, 3, 4];
+        assert!(V::slice(&v, 0, 4) == v, 0);
+        let structs = vector[Pair { a: 1, b: 2 }, Pair { a: 3, b: 4 }];
+        assert!(V::slice(&structs, 0, 2) == structs, 1);
+    }
+
+    #[test]
+    fun slice_sub_range() {
+        let v = vector[1, 2, 3, 4];
+        assert!(V::slice(&v, 1, 3) == vector[2, 3], 0);
+        assert!(V::slice(&b"hello", 1, 4) == b"ell", 1);
+        let structs = vector[Pair { a: 1, b: 2 }, Pair { a: 3, b: 4 }, Pair { a: 5, b: 6 }];
+        assert!(V::slice(&structs, 1, 2) == vector[Pair { a: 3, b: 4 }], 2);
+        // The source vector is left unchanged.
+        assert!(V::length(&structs) == 3, 3);
+    }
+
+    #[test]
+    fun slice_empty() {
+        let v = vector[1, 2, 3, 4];
+        assert!(V::is_empty(&V::slice(&v, 2, 2)), 0);
+        assert!(V::is_empty(&V::slice(&v, 4, 4)), 1);
+        assert!(V::is_empty(&V::slice(&V::empty<u64>(), 0, 0)), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = std::vector)] // INDEX_OUT_OF_BOUNDS
+    fun slice_end_out_of_range() {
+        V::slice(&vector[1, 2, 3, 4], 2, 5);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = std::vector)] // INDEX_OUT_OF_BOUNDS
+    fun slice_start_after_end() {
+        V::slice(&vector[1, 2, 3, 4], 3, 2);
+    }
+
+    #[test]
+    fun shuffle_indices_is_deterministic() {
+        let shuffled = V::shuffle_indices(10, &b"seed");
+        assert!(shuffled == V::shuffle_indices(10, &b"seed"), 0);
+        assert!(shuffled == vector[1, 4, 9, 8, 0, 5, 2, 6, 7, 3], 1);
+    }
+
+    #[test]
+    fun shuffle_indices_is_a_permutation() {
+        let len = 100;
+        let shuffled = V::shuffle_indices(len, &b"permutation");
+        assert!(V::length(&shuffled) == len, 0);
+        let seen = V::empty();
+        let i = 0;
+        while (i < len) {
+            V::push_back(&mut seen, false);
+            i = i + 1;
+        };
+        let i = 0;
+        while (i < len) {
+            let index = *V::borrow(&shuffled, i);
+            assert!(index < len, 1);
+            assert!(!*V::borrow(&seen, index), 2);
+            *V::borrow_mut(&mut seen, index) = true;
+            i = i + 1;
+        };
+    }
+
+    #[test]
+    fun shuffle_indices_depends_on_seed() {
+        assert!(V::shuffle_indices(10, &b"seed") != V::shuffle_indices(10, &b"seee"), 0);
+        assert!(V::shuffle_indices(10, &b"seee") == vector[3, 4, 7, 1, 2, 5, 8, 0, 9, 6], 1);
+    }
+
+    #[test]
+    fun shuffle_indices_short() {
+        assert!(V::is_empty(&V::shuffle_indices(0, &b"seed")), 0);
+        assert!(V::shuffle_indices(1, &b"seed") == vector[0], 1);
+    }
 }
@@ -0,0 +1,91 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{account_address::AccountAddress, vm_status::StatusCode};
+use move_stdlib::natives::{all_natives, GasParameters};
+use move_vm_runtime::move_vm::MoveVM;
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+// The declaration of `bcs::from_bytes` in the standard library.
+const BCS: &str = r#"
+    module std::bcs {
+        native public fun from_bytes<MoveValue: copy + drop>(bytes: vector<u8>): MoveValue;
+    }
+"#;
+
+// The same declaration without the ability constraints.
+const UNCONSTRAINED_BCS: &str = r#"
+    module std::bcs {
+        native public fun from_bytes<MoveValue>(bytes: vector<u8>): MoveValue;
+    }
+"#;
+
+const COIN: &str = r#"
+    module {{ADDR}}::M {
+        use std::bcs;
+
+        struct Coin has store { value: u64 }
+
+        public fun forge(bytes: vector<u8>): Coin {
+            bcs::from_bytes<Coin>(bytes)
+        }
+    }
+"#;
+
+const ID: &str = r#"
+    module {{ADDR}}::M {
+        use std::bcs;
+
+        struct Id has copy, drop { value: u64 }
+
+        public fun id(bytes: vector<u8>): Id {
+            bcs::from_bytes<Id>(bytes)
+        }
+    }
+"#;
+
+// Compiles `M` against the declaration of `bcs` in `bcs_code`.
+fn compile_m(bcs_code: &str, m_code: &str) -> Vec<u8> {
+    let code = format!("{}{}", bcs_code, m_code).replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let module = compile_units(&code)
+        .unwrap()
+        .into_iter()
+        .map(as_module)
+        .find(|m| m.self_id().address() == &TEST_ADDR)
+        .unwrap();
+    let mut blob = vec![];
+    module.serialize(&mut blob).unwrap();
+    blob
+}
+
+// Bytecode compiled against a declaration of `bcs::from_bytes` without the ability constraints,
+// which the compiler would otherwise reject, does not link against the standard library.
+#[test]
+fn from_bytes_rejects_types_without_copy_and_drop() {
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units(BCS).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+    let vm = MoveVM::new(all_natives(AccountAddress::ONE, GasParameters::zeros())).unwrap();
+
+    let mut sess = vm.new_session(&storage);
+    let err = sess
+        .publish_module_bundle(
+            vec![compile_m(UNCONSTRAINED_BCS, COIN)],
+            TEST_ADDR,
+            &mut UnmeteredGasMeter,
+        )
+        .unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::TYPE_MISMATCH);
+
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module_bundle(vec![compile_m(BCS, ID)], TEST_ADDR, &mut UnmeteredGasMeter)
+        .unwrap();
+}
@@ -0,0 +1,87 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::ModuleId,
+    value::{MoveTypeLayout, MoveValue},
+    vm_status::StatusCode,
+};
+use move_stdlib::natives::chain::NativeGasUnitPriceContext;
+use move_vm_runtime::{move_vm::MoveVM, native_extensions::NativeContextExtensions};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+// Must match the constant in `chain.move`.
+const EGAS_UNIT_PRICE_NOT_AVAILABLE: u64 = 1;
+
+fn gas_unit_price(
+    context: Option<NativeGasUnitPriceContext>,
+) -> Result<u64, (StatusCode, Option<u64>)> {
+    let code = r#"
+        module std::chain {
+            native public fun gas_unit_price(): u64;
+        }
+
+        module {{ADDR}}::M {
+            public fun price(): u64 {
+                std::chain::gas_unit_price()
+            }
+        }
+    "#;
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units(&code).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+
+    let vm = MoveVM::new(move_stdlib::natives::all_natives(
+        AccountAddress::ONE,
+        move_stdlib::natives::GasParameters::zeros(),
+    ))
+    .unwrap();
+    let mut extensions = NativeContextExtensions::default();
+    if let Some(context) = context {
+        extensions.add(context);
+    }
+    let mut sess = vm.new_session_with_extensions(&storage, extensions);
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    let args: Vec<Vec<u8>> = vec![];
+    let return_values = sess
+        .execute_function_bypass_visibility(
+            &module_id,
+            &Identifier::new("price").unwrap(),
+            vec![],
+            args,
+            &mut UnmeteredGasMeter,
+        )
+        .map_err(|err| (err.major_status(), err.sub_status()))?
+        .return_values;
+    match MoveValue::simple_deserialize(&return_values[0].0, &MoveTypeLayout::U64).unwrap() {
+        MoveValue::U64(price) => Ok(price),
+        value => panic!("unexpected return value {:?}", value),
+    }
+}
+
+#[test]
+fn gas_unit_price_from_context() {
+    assert_eq!(
+        gas_unit_price(Some(NativeGasUnitPriceContext::new(150))),
+        Ok(150)
+    );
+}
+
+#[test]
+fn gas_unit_price_not_available() {
+    assert_eq!(
+        gas_unit_price(None),
+        Err((StatusCode::ABORTED, Some(EGAS_UNIT_PRICE_NOT_AVAILABLE)))
+    );
+}
@@ -0,0 +1,117 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::ModuleId,
+    value::{MoveTypeLayout, MoveValue},
+    vm_status::StatusCode,
+};
+use move_stdlib::natives::account::NativeCreateSignerPermission;
+use move_vm_runtime::{move_vm::MoveVM, native_extensions::NativeContextExtensions};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+const RESOURCE_ADDR: AccountAddress = AccountAddress::new([43; AccountAddress::LENGTH]);
+
+// Must match the constant in `account.move`.
+const ECREATE_SIGNER_NOT_AUTHORIZED: u64 = 1;
+
+fn setup() -> (MoveVM, InMemoryStorage) {
+    let code = r#"
+        module std::signer {
+            native public fun borrow_address(s: &signer): &address;
+        }
+
+        module std::account {
+            native public fun create_signer(addr: address): signer;
+        }
+
+        module {{ADDR}}::M {
+            use std::account;
+            use std::signer;
+
+            public fun signer_address(addr: address): address {
+                *signer::borrow_address(&account::create_signer(addr))
+            }
+        }
+    "#;
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units(&code).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+
+    let vm = MoveVM::new(move_stdlib::natives::all_natives(
+        AccountAddress::ONE,
+        move_stdlib::natives::GasParameters::zeros(),
+    ))
+    .unwrap();
+    (vm, storage)
+}
+
+fn signer_address(
+    permission: Option<NativeCreateSignerPermission>,
+    addr: AccountAddress,
+) -> Result<AccountAddress, (StatusCode, Option<u64>)> {
+    let (vm, storage) = setup();
+    let mut extensions = NativeContextExtensions::default();
+    if let Some(permission) = permission {
+        extensions.add(permission);
+    }
+    let mut sess = vm.new_session_with_extensions(&storage, extensions);
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    let return_values = sess
+        .execute_function_bypass_visibility(
+            &module_id,
+            &Identifier::new("signer_address").unwrap(),
+            vec![],
+            vec![MoveValue::Address(addr).simple_serialize().unwrap()],
+            &mut UnmeteredGasMeter,
+        )
+        .map_err(|err| (err.major_status(), err.sub_status()))?
+        .return_values;
+    match MoveValue::simple_deserialize(&return_values[0].0, &MoveTypeLayout::Address).unwrap() {
+        MoveValue::Address(addr) => Ok(addr),
+        value => panic!("unexpected return value {:?}", value),
+    }
+}
+
+#[test]
+fn create_signer_authorized() {
+    assert_eq!(
+        signer_address(
+            Some(NativeCreateSignerPermission::any_address()),
+            RESOURCE_ADDR
+        ),
+        Ok(RESOURCE_ADDR)
+    );
+    assert_eq!(
+        signer_address(
+            Some(NativeCreateSignerPermission::for_addresses([RESOURCE_ADDR])),
+            RESOURCE_ADDR
+        ),
+        Ok(RESOURCE_ADDR)
+    );
+}
+
+#[test]
+fn create_signer_unauthorized() {
+    assert_eq!(
+        signer_address(None, RESOURCE_ADDR),
+        Err((StatusCode::ABORTED, Some(ECREATE_SIGNER_NOT_AUTHORIZED)))
+    );
+    assert_eq!(
+        signer_address(
+            Some(NativeCreateSignerPermission::for_addresses([TEST_ADDR])),
+            RESOURCE_ADDR
+        ),
+        Err((StatusCode::ABORTED, Some(ECREATE_SIGNER_NOT_AUTHORIZED)))
+    );
+}
@@ -19,7 +19,7 @@ use move_vm_runtime::{config::VMConfig, move_vm::MoveVM};
 use move_vm_test_utils::InMemoryStorage;
 use move_vm_types::gas::UnmeteredGasMeter;
 
-use std::{path::PathBuf, sync::Arc, thread};
+use std::{collections::BTreeSet, path::PathBuf, sync::Arc, thread};
 
 const WORKING_ACCOUNT: AccountAddress = AccountAddress::TWO;
 
@@ -445,6 +445,79 @@ fn deep_friend_list_ok_1() {
     adapter.publish_modules(vec![module]);
 }
 
+#[test]
+fn loaded_modules_lists_called_module() {
+    let data_store = InMemoryStorage::new();
+    let mut adapter = Adapter::new(data_store);
+    let modules = get_modules();
+    adapter.publish_modules(modules);
+
+    let adapter = adapter.fresh();
+    let module_id = ModuleId::new(WORKING_ACCOUNT, Identifier::new("C").unwrap());
+    let mut session = adapter.vm.new_session(&adapter.store);
+    assert!(session.loaded_modules().is_empty());
+    session
+        .execute_function_bypass_visibility(
+            &module_id,
+            IdentStr::new("just_c").unwrap(),
+            vec![],
+            Vec::<Vec<u8>>::new(),
+            &mut UnmeteredGasMeter,
+        )
+        .unwrap();
+    assert_eq!(session.loaded_modules(), vec![module_id]);
+}
+
+#[test]
+fn invalidate_module_keeps_unrelated_modules_cached() {
+    let data_store = InMemoryStorage::new();
+    let mut adapter = Adapter::new(data_store);
+
+    // A0 <- A1 <- A2, and an unrelated module U
+    let mut modules = vec![];
+    dependency_chain(1, 3, &mut modules);
+    modules.push(leaf_module("U"));
+    adapter.publish_modules(modules);
+
+    let adapter = adapter.fresh();
+    let module_id = |name: &str| ModuleId::new(WORKING_ACCOUNT, Identifier::new(name).unwrap());
+    for name in ["A2", "U"] {
+        adapter
+            .vm
+            .load_module(&module_id(name), &adapter.store)
+            .unwrap();
+    }
+    adapter.vm.get_and_clear_module_cache_hits();
+
+    let evicted = adapter
+        .vm
+        .invalidate_loader_cache_for_module(&module_id("A1"));
+    assert_eq!(evicted, BTreeSet::from([module_id("A1"), module_id("A2")]));
+    assert!(!adapter.vm.is_loader_cache_invalidated());
+
+    // The dependency and the unrelated module are still served from the cache.
+    for name in ["A0", "U"] {
+        adapter
+            .vm
+            .load_module(&module_id(name), &adapter.store)
+            .unwrap();
+    }
+    assert_eq!(
+        adapter.vm.get_and_clear_module_cache_hits(),
+        BTreeSet::from([module_id("A0"), module_id("U")])
+    );
+
+    // The evicted modules are loaded again from storage.
+    adapter
+        .vm
+        .load_module(&module_id("A2"), &adapter.store)
+        .unwrap();
+    assert!(!adapter
+        .vm
+        .get_and_clear_module_cache_hits()
+        .contains(&module_id("A2")));
+}
+
 fn leaf_module(name: &str) -> CompiledModule {
     let mut module = empty_module();
     module.identifiers[0] = Identifier::new(name).unwrap();
@@ -4,10 +4,21 @@
 
 mod bad_entry_point_tests;
 mod bad_storage_tests;
+mod bcs_tests;
+mod chain_tests;
 mod binary_format_version;
+mod create_signer_tests;
 mod exec_func_effects_tests;
 mod function_arg_tests;
 mod loader_tests;
+mod module_load_observer_tests;
 mod mutated_accounts_tests;
+mod native_extensions_tests;
 mod nested_loop_tests;
+mod prefetch_tests;
+mod publish_tests;
 mod return_value_tests;
+mod simulation_tests;
+mod storage_delta_tests;
+mod string_gas_tests;
+mod vector_gas_tests;
@@ -0,0 +1,74 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{
+    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
+};
+use move_vm_runtime::{data_cache::ModuleLoadObserver, move_vm::MoveVM};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+use std::sync::{Arc, Mutex};
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+#[derive(Default)]
+struct RecordingObserver {
+    loaded: Mutex<Vec<ModuleId>>,
+}
+
+impl ModuleLoadObserver for RecordingObserver {
+    fn on_module_loaded(&self, module_id: &ModuleId) {
+        self.loaded.lock().unwrap().push(module_id.clone());
+    }
+}
+
+fn module_id(name: &str) -> ModuleId {
+    ModuleId::new(TEST_ADDR, Identifier::new(name).unwrap())
+}
+
+#[test]
+fn observer_sees_modules_in_dependency_order() {
+    let code = r#"
+        module {{ADDR}}::A {
+            public fun one(): u64 { 1 }
+        }
+
+        module {{ADDR}}::B {
+            public fun two(): u64 { {{ADDR}}::A::one() + 1 }
+        }
+    "#;
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units(&code).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+
+    let vm = MoveVM::new(vec![]).unwrap();
+    let call_two = |observer: Arc<RecordingObserver>| {
+        let mut sess = vm.new_session_with_module_load_observer(&storage, observer);
+        sess.execute_function_bypass_visibility(
+            &module_id("B"),
+            &Identifier::new("two").unwrap(),
+            vec![],
+            Vec::<Vec<u8>>::new(),
+            &mut UnmeteredGasMeter,
+        )
+        .unwrap();
+    };
+
+    let observer = Arc::new(RecordingObserver::default());
+    call_two(observer.clone());
+    assert_eq!(
+        *observer.loaded.lock().unwrap(),
+        vec![module_id("A"), module_id("B")]
+    );
+
+    // The modules are in the code cache of the VM now, so they are not loaded again.
+    let observer = Arc::new(RecordingObserver::default());
+    call_two(observer.clone());
+    assert!(observer.loaded.lock().unwrap().is_empty());
+}
@@ -0,0 +1,106 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use better_any::{Tid, TidAble};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::ModuleId,
+    value::{MoveTypeLayout, MoveValue},
+};
+use move_vm_runtime::{
+    move_vm::MoveVM,
+    native_extensions::NativeContextExtensions,
+    native_functions::{NativeContext, NativeFunction},
+};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::{
+    gas::UnmeteredGasMeter, loaded_data::runtime_types::Type, natives::function::NativeResult,
+    values::Value,
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+#[derive(Tid)]
+struct ChainIdExtension {
+    chain_id: u8,
+}
+
+#[derive(Tid)]
+struct TimestampExtension {
+    now_microseconds: u64,
+}
+
+fn native_chain_id_plus_timestamp(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let chain_id = context.extensions().get::<ChainIdExtension>().chain_id;
+    let now = context
+        .extensions()
+        .get::<TimestampExtension>()
+        .now_microseconds;
+    Ok(NativeResult::ok(
+        0.into(),
+        smallvec![Value::u64(chain_id as u64 + now)],
+    ))
+}
+
+#[test]
+fn test_native_reads_multiple_extensions() {
+    let code = r#"
+        module {{ADDR}}::M {
+            native fun chain_id_plus_timestamp(): u64;
+
+            public fun read_extensions(): u64 {
+                chain_id_plus_timestamp()
+            }
+        }
+    "#;
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let mut units = compile_units(&code).unwrap();
+    let m = as_module(units.pop().unwrap());
+    let mut blob = vec![];
+    m.serialize(&mut blob).unwrap();
+
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    let mut storage = InMemoryStorage::new();
+    storage.publish_or_overwrite_module(module_id.clone(), blob);
+
+    let native: NativeFunction = Arc::new(native_chain_id_plus_timestamp);
+    let vm = MoveVM::new(vec![(
+        TEST_ADDR,
+        Identifier::new("M").unwrap(),
+        Identifier::new("chain_id_plus_timestamp").unwrap(),
+        native,
+    )])
+    .unwrap();
+
+    let mut extensions = NativeContextExtensions::default();
+    extensions.add(ChainIdExtension { chain_id: 2 });
+    extensions.add(TimestampExtension {
+        now_microseconds: 40,
+    });
+    let mut sess = vm.new_session_with_extensions(&storage, extensions);
+    let args: Vec<Vec<u8>> = vec![];
+    let return_values = sess
+        .execute_function_bypass_visibility(
+            &module_id,
+            &Identifier::new("read_extensions").unwrap(),
+            vec![],
+            args,
+            &mut UnmeteredGasMeter,
+        )
+        .unwrap()
+        .return_values;
+    let (bytes, _layout) = &return_values[0];
+    assert_eq!(
+        MoveValue::simple_deserialize(bytes, &MoveTypeLayout::U64).unwrap(),
+        MoveValue::U64(42)
+    );
+}
@@ -0,0 +1,72 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, ResourceKey, StructTag},
+    resolver::{ModuleResolver, ResourceResolver},
+};
+use move_vm_runtime::move_vm::MoveVM;
+use move_vm_test_utils::InMemoryStorage;
+use std::cell::RefCell;
+
+/// Storage which records the keys it is asked to prefetch.
+struct RecordingStorage {
+    storage: InMemoryStorage,
+    prefetched: RefCell<Vec<Vec<ResourceKey>>>,
+}
+
+impl ModuleResolver for RecordingStorage {
+    type Error = ();
+
+    fn get_module(&self, module_id: &ModuleId) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.storage.get_module(module_id)
+    }
+}
+
+impl ResourceResolver for RecordingStorage {
+    type Error = ();
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.storage.get_resource(address, tag)
+    }
+
+    fn prefetch(&self, keys: &[ResourceKey]) {
+        self.prefetched.borrow_mut().push(keys.to_vec());
+    }
+}
+
+fn resource_key(address: AccountAddress, name: &str) -> ResourceKey {
+    ResourceKey::new(
+        address,
+        StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("M").unwrap(),
+            name: Identifier::new(name).unwrap(),
+            type_params: vec![],
+        },
+    )
+}
+
+#[test]
+fn session_passes_prefetch_hint_to_storage() {
+    let storage = RecordingStorage {
+        storage: InMemoryStorage::new(),
+        prefetched: RefCell::new(vec![]),
+    };
+    let vm = MoveVM::new(vec![]).unwrap();
+    let sess = vm.new_session(&storage);
+
+    let keys = vec![
+        resource_key(AccountAddress::ONE, "R"),
+        resource_key(AccountAddress::TWO, "S"),
+    ];
+    sess.prefetch_resources(&keys);
+    sess.prefetch_resources(&[]);
+    assert_eq!(*storage.prefetched.borrow(), vec![keys, vec![]]);
+}
@@ -0,0 +1,296 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::compile_modules;
+use move_binary_format::{errors::VMError, CompiledModule};
+use move_core_types::{
+    account_address::AccountAddress,
+    effects::{ChangeSet, ChangeSetDivergence, Op},
+    identifier::Identifier,
+    language_storage::ModuleId,
+    vm_status::StatusCode,
+};
+use move_vm_runtime::{config::PublishModuleBundleOption, move_vm::MoveVM};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+use std::collections::BTreeSet;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+const EXTERNAL_ADDR: AccountAddress = AccountAddress::new([43; AccountAddress::LENGTH]);
+
+fn compile(code: &str) -> Vec<CompiledModule> {
+    let code = code
+        .replace("{{ADDR}}", &TEST_ADDR.to_hex_literal())
+        .replace("{{EXTERNAL}}", &EXTERNAL_ADDR.to_hex_literal());
+    compile_modules(&code).unwrap()
+}
+
+fn serialize(modules: &[CompiledModule]) -> Vec<Vec<u8>> {
+    modules
+        .iter()
+        .map(|m| {
+            let mut blob = vec![];
+            m.serialize(&mut blob).unwrap();
+            blob
+        })
+        .collect()
+}
+
+fn module_id(addr: AccountAddress, name: &str) -> ModuleId {
+    ModuleId::new(addr, Identifier::new(name).unwrap())
+}
+
+#[test]
+fn bundle_dependencies_excludes_bundle_modules() {
+    let code = r#"
+        module {{EXTERNAL}}::Ext {
+            public fun one(): u64 { 1 }
+        }
+
+        module {{ADDR}}::A {
+            public fun two(): u64 { {{EXTERNAL}}::Ext::one() + 1 }
+        }
+
+        module {{ADDR}}::B {
+            public fun three(): u64 { {{ADDR}}::A::two() + 1 }
+        }
+    "#;
+    let modules = compile(code);
+    let bundle: Vec<_> = modules
+        .into_iter()
+        .filter(|m| m.self_id().address() == &TEST_ADDR)
+        .collect();
+    assert_eq!(bundle.len(), 2);
+
+    let storage = InMemoryStorage::new();
+    let vm = MoveVM::new(vec![]).unwrap();
+    let sess = vm.new_session(&storage);
+    let deps = sess.bundle_dependencies(&serialize(&bundle)).unwrap();
+
+    assert_eq!(
+        deps,
+        BTreeSet::from([module_id(EXTERNAL_ADDR, "Ext")]),
+        "only the module outside of the bundle should be reported"
+    );
+}
+
+#[test]
+fn publish_under_allowed_non_sender_address() {
+    let code = r#"
+        module {{EXTERNAL}}::Resource {
+            public fun value(): u64 { 42 }
+        }
+    "#;
+    let blobs = serialize(&compile(code));
+
+    let storage = InMemoryStorage::new();
+    let vm = MoveVM::new(vec![]).unwrap();
+
+    // By default, modules can only be published under the sender's address.
+    let mut sess = vm.new_session(&storage);
+    let err = sess
+        .publish_module_bundle(blobs.clone(), TEST_ADDR, &mut UnmeteredGasMeter)
+        .unwrap_err();
+    assert_eq!(
+        err.major_status(),
+        StatusCode::MODULE_ADDRESS_DOES_NOT_MATCH_SENDER
+    );
+
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module_bundle_with_allowed_addresses(
+        blobs,
+        TEST_ADDR,
+        &BTreeSet::from([EXTERNAL_ADDR]),
+        &mut UnmeteredGasMeter,
+    )
+    .unwrap();
+    assert!(sess
+        .exists_module(&module_id(EXTERNAL_ADDR, "Resource"))
+        .unwrap());
+}
+
+#[test]
+fn deserialization_error_identifies_bundle_index() {
+    let code = r#"
+        module {{ADDR}}::A {
+            public fun one(): u64 { 1 }
+        }
+
+        module {{ADDR}}::B {
+            public fun two(): u64 { 2 }
+        }
+    "#;
+    let mut blobs = serialize(&compile(code));
+    // Corrupt the magic number of the second module.
+    blobs[1][0] ^= 0xff;
+
+    let storage = InMemoryStorage::new();
+    let vm = MoveVM::new(vec![]).unwrap();
+    let mut sess = vm.new_session(&storage);
+    let err = sess
+        .publish_module_bundle(blobs, TEST_ADDR, &mut UnmeteredGasMeter)
+        .unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::BAD_MAGIC);
+    assert!(
+        err.message().unwrap().contains("bundle index 1,"),
+        "unexpected message: {:?}",
+        err.message()
+    );
+}
+
+#[test]
+fn publish_with_tightened_verifier_metering_limit() {
+    let code = r#"
+        module {{ADDR}}::M {
+            public fun sum(n: u64): u64 {
+                let i = 0;
+                let sum = 0;
+                while (i < n) {
+                    sum = sum + i;
+                    i = i + 1;
+                };
+                sum
+            }
+        }
+    "#;
+    let blobs = serialize(&compile(code));
+
+    let storage = InMemoryStorage::new();
+    let vm = MoveVM::new(vec![]).unwrap();
+
+    let mut sess = vm.new_session(&storage);
+    let err = sess
+        .publish_module_bundle_with_option(
+            blobs.clone(),
+            TEST_ADDR,
+            &mut UnmeteredGasMeter,
+            &PublishModuleBundleOption {
+                verifier_metering_limit: Some(10),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::CONSTRAINT_NOT_SATISFIED);
+
+    // The default limit of the VM accepts the module.
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module_bundle_with_option(
+        blobs,
+        TEST_ADDR,
+        &mut UnmeteredGasMeter,
+        &PublishModuleBundleOption::default(),
+    )
+    .unwrap();
+    assert!(sess.exists_module(&module_id(TEST_ADDR, "M")).unwrap());
+}
+
+#[test]
+fn apply_and_verify_detects_divergent_change_set() {
+    let code = r#"
+        module {{ADDR}}::M {
+            public fun one(): u64 { 1 }
+        }
+    "#;
+    let blobs = serialize(&compile(code));
+
+    let storage = InMemoryStorage::new();
+    let vm = MoveVM::new(vec![]).unwrap();
+    let publish = || {
+        let mut sess = vm.new_session(&storage);
+        sess.publish_module_bundle(blobs.clone(), TEST_ADDR, &mut UnmeteredGasMeter)
+            .unwrap();
+        sess
+    };
+
+    let mut expected = ChangeSet::new();
+    expected
+        .add_module_op(module_id(TEST_ADDR, "M"), Op::New(blobs[0].clone()))
+        .unwrap();
+    assert_eq!(publish().apply_and_verify(&expected).unwrap(), None);
+
+    let mut expected = ChangeSet::new();
+    expected
+        .add_module_op(module_id(TEST_ADDR, "M"), Op::Modify(blobs[0].clone()))
+        .unwrap();
+    assert_eq!(
+        publish().apply_and_verify(&expected).unwrap(),
+        Some(ChangeSetDivergence::Module {
+            module_id: module_id(TEST_ADDR, "M"),
+            actual: Some(Op::New(blobs[0].clone())),
+            expected: Some(Op::Modify(blobs[0].clone())),
+        })
+    );
+    assert_eq!(
+        publish().apply_and_verify(&ChangeSet::new()).unwrap(),
+        Some(ChangeSetDivergence::Module {
+            module_id: module_id(TEST_ADDR, "M"),
+            actual: Some(Op::New(blobs[0].clone())),
+            expected: None,
+        })
+    );
+}
+
+#[test]
+fn verify_module_bundle_collects_all_errors() {
+    let code = r#"
+        module {{ADDR}}::A {
+            public fun one(): u64 { 1 }
+        }
+
+        module {{EXTERNAL}}::B {
+            public fun two(): u64 { 2 }
+        }
+
+        module {{ADDR}}::C {
+            public fun three(): u64 { 3 }
+        }
+
+        module {{ADDR}}::D {
+            public fun four(): u64 { 4 }
+        }
+    "#;
+    let mut modules = compile(code);
+    modules.sort_by_key(|m| m.self_id().name().to_owned());
+    let mut blobs = serialize(&modules);
+    // `B` is published under the wrong address, and `C` cannot be deserialized.
+    blobs[2][0] ^= 0xff;
+
+    let storage = InMemoryStorage::new();
+    let vm = MoveVM::new(vec![]).unwrap();
+    let mut sess = vm.new_session(&storage);
+
+    let statuses = |errors: Vec<(usize, VMError)>| -> Vec<_> {
+        errors
+            .into_iter()
+            .map(|(idx, err)| (idx, err.major_status()))
+            .collect()
+    };
+
+    // By default, only the first failure is reported.
+    let errors =
+        sess.verify_module_bundle(&blobs, TEST_ADDR, &PublishModuleBundleOption::default());
+    assert_eq!(statuses(errors), vec![(2, StatusCode::BAD_MAGIC)]);
+
+    let errors = sess.verify_module_bundle(
+        &blobs,
+        TEST_ADDR,
+        &PublishModuleBundleOption {
+            collect_all_errors: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        statuses(errors),
+        vec![
+            (1, StatusCode::MODULE_ADDRESS_DOES_NOT_MATCH_SENDER),
+            (2, StatusCode::BAD_MAGIC),
+        ]
+    );
+
+    // A valid bundle passes and is not published.
+    let valid = vec![blobs[0].clone(), blobs[3].clone()];
+    assert!(sess
+        .verify_module_bundle(&valid, TEST_ADDR, &PublishModuleBundleOption::default())
+        .is_empty());
+    assert!(!sess.exists_module(&module_id(TEST_ADDR, "A")).unwrap());
+}
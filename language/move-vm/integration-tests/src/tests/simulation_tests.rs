@@ -0,0 +1,116 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::compile_modules;
+use move_core_types::{
+    account_address::AccountAddress,
+    effects::Op,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag, TypeTag},
+    resolver::ResourceResolver,
+    value::{serialize_values, MoveValue},
+    vm_status::VMStatus,
+};
+use move_stdlib::natives::event::{
+    make_native_write_to_event_store, WriteToEventStoreGasParameters,
+};
+use move_vm_runtime::move_vm::{MoveVM, SimulationResult};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+const SENDER: AccountAddress = AccountAddress::new([7; AccountAddress::LENGTH]);
+
+fn setup() -> (MoveVM, InMemoryStorage) {
+    let code = r#"
+        module {{ADDR}}::M {
+            struct R has key { v: u64 }
+            struct E has drop, store { v: u64 }
+
+            native fun write_to_event_store<T: drop + store>(guid: vector<u8>, count: u64, msg: T);
+
+            public entry fun run(s: signer, v: u64) {
+                assert!(v != 0, 1);
+                move_to(&s, R { v });
+                write_to_event_store(b"guid", 0, E { v });
+            }
+        }
+    "#;
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let module = compile_modules(&code).unwrap().pop().unwrap();
+    let mut blob = vec![];
+    module.serialize(&mut blob).unwrap();
+
+    let mut storage = InMemoryStorage::new();
+    storage.publish_or_overwrite_module(module.self_id(), blob);
+
+    let vm = MoveVM::new(vec![(
+        TEST_ADDR,
+        Identifier::new("M").unwrap(),
+        Identifier::new("write_to_event_store").unwrap(),
+        make_native_write_to_event_store(WriteToEventStoreGasParameters {
+            unit_cost: 0.into(),
+        }),
+    )])
+    .unwrap();
+    (vm, storage)
+}
+
+fn struct_tag(name: &str) -> StructTag {
+    StructTag {
+        address: TEST_ADDR,
+        module: Identifier::new("M").unwrap(),
+        name: Identifier::new(name).unwrap(),
+        type_params: vec![],
+    }
+}
+
+fn simulate_run(vm: &MoveVM, storage: &InMemoryStorage, v: u64) -> SimulationResult {
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    vm.simulate(
+        storage,
+        &module_id,
+        &Identifier::new("run").unwrap(),
+        vec![],
+        serialize_values(&vec![MoveValue::U64(v)]),
+        vec![SENDER],
+        &mut UnmeteredGasMeter,
+    )
+    .unwrap()
+}
+
+#[test]
+fn simulate_returns_changes_and_events() {
+    let (vm, storage) = setup();
+    let result = simulate_run(&vm, &storage, 5);
+    assert_eq!(result.status, VMStatus::Executed);
+
+    let resource = MoveValue::U64(5).simple_serialize().unwrap();
+    let resources: Vec<_> = result.changes.resources().collect();
+    assert_eq!(
+        resources,
+        vec![(SENDER, &struct_tag("R"), Op::New(resource.as_slice()))]
+    );
+
+    assert_eq!(result.events.len(), 1);
+    let (guid, seq_num, ty, msg) = &result.events[0];
+    assert_eq!(guid, b"guid");
+    assert_eq!(*seq_num, 0);
+    assert_eq!(ty, &TypeTag::Struct(Box::new(struct_tag("E"))));
+    assert_eq!(msg, &resource);
+
+    // Nothing has been committed.
+    assert!(storage
+        .get_resource(&SENDER, &struct_tag("R"))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn simulate_reports_abort_in_status() {
+    let (vm, storage) = setup();
+    let result = simulate_run(&vm, &storage, 0);
+    assert!(matches!(result.status, VMStatus::MoveAbort(_, 1)));
+    assert!(result.changes.accounts().is_empty());
+    assert!(result.events.is_empty());
+}
@@ -0,0 +1,128 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{
+    account_address::AccountAddress,
+    effects::StorageDelta,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag},
+    value::{serialize_values, MoveValue},
+};
+use move_vm_runtime::{move_vm::MoveVM, session::Session};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+fn call(
+    sess: &mut Session<InMemoryStorage>,
+    module_id: &ModuleId,
+    name: &str,
+    args: Vec<MoveValue>,
+) {
+    sess.execute_function_bypass_visibility(
+        module_id,
+        &Identifier::new(name).unwrap(),
+        vec![],
+        serialize_values(&args),
+        &mut UnmeteredGasMeter,
+    )
+    .unwrap();
+}
+
+// A `Foo` holding `n` bytes, which takes `n + 1` bytes with the length prefix.
+fn bytes(n: usize) -> MoveValue {
+    MoveValue::vector_u8(vec![7; n])
+}
+
+#[test]
+fn storage_delta_of_created_modified_and_deleted_resources() {
+    let code = r#"
+        module {{ADDR}}::M {
+            struct Foo has key { data: vector<u8> }
+            public fun publish(account: &signer, data: vector<u8>) {
+                move_to(account, Foo { data })
+            }
+            public fun set(addr: address, data: vector<u8>) acquires Foo {
+                borrow_global_mut<Foo>(addr).data = data;
+            }
+            public fun remove(addr: address) acquires Foo {
+                let Foo { data: _ } = move_from<Foo>(addr);
+            }
+        }
+    "#;
+
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let mut units = compile_units(&code).unwrap();
+    let m = as_module(units.pop().unwrap());
+    let mut blob = vec![];
+    m.serialize(&mut blob).unwrap();
+
+    let mut storage = InMemoryStorage::new();
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    storage.publish_or_overwrite_module(module_id.clone(), blob);
+
+    let foo = StructTag {
+        address: TEST_ADDR,
+        module: Identifier::new("M").unwrap(),
+        name: Identifier::new("Foo").unwrap(),
+        type_params: vec![],
+    };
+    let modified = AccountAddress::random();
+    let deleted = AccountAddress::random();
+    let created = AccountAddress::random();
+    storage.publish_or_overwrite_resource(
+        modified,
+        foo.clone(),
+        bytes(4).simple_serialize().unwrap(),
+    );
+    storage.publish_or_overwrite_resource(deleted, foo, bytes(10).simple_serialize().unwrap());
+
+    let vm = MoveVM::new(vec![]).unwrap();
+    let mut sess = vm.new_session(&storage);
+    call(
+        &mut sess,
+        &module_id,
+        "publish",
+        vec![MoveValue::Signer(created), bytes(2)],
+    );
+    call(
+        &mut sess,
+        &module_id,
+        "set",
+        vec![MoveValue::Address(modified), bytes(7)],
+    );
+    call(
+        &mut sess,
+        &module_id,
+        "remove",
+        vec![MoveValue::Address(deleted)],
+    );
+    assert_eq!(
+        sess.storage_delta().unwrap(),
+        StorageDelta {
+            bytes_added: 3 + 8,
+            bytes_removed: 5 + 11,
+            slots_created: 1,
+            slots_deleted: 1,
+        }
+    );
+
+    // Deleting the resource created in the session cancels out its creation.
+    call(
+        &mut sess,
+        &module_id,
+        "remove",
+        vec![MoveValue::Address(created)],
+    );
+    assert_eq!(
+        sess.storage_delta().unwrap(),
+        StorageDelta {
+            bytes_added: 8,
+            bytes_removed: 5 + 11,
+            slots_created: 0,
+            slots_deleted: 1,
+        }
+    );
+}
@@ -0,0 +1,157 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{
+    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
+    value::MoveValue,
+};
+use move_stdlib::natives::{all_natives, string, GasParameters};
+use move_vm_runtime::move_vm::MoveVM;
+use move_vm_test_utils::{
+    gas_schedule::{zero_cost_schedule, Gas, GasStatus},
+    InMemoryStorage,
+};
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+// One gas unit, so that per byte charges show in the remaining gas, which is rounded to units.
+const PER_BYTE: u64 = 1000;
+
+// Returns the gas charged for calling `fun` of the test module. Instructions are free under the
+// zero cost schedule, so this is the cost of the string native called by `fun`.
+fn charged_gas(fun: &str, args: Vec<MoveValue>) -> u64 {
+    let code = r#"
+        module std::string {
+            native public fun internal_check_utf8(v: &vector<u8>): bool;
+            native public fun internal_sub_string(v: &vector<u8>, i: u64, j: u64): vector<u8>;
+            native public fun internal_index_of(v: &vector<u8>, r: &vector<u8>): u64;
+            native public fun internal_to_lowercase(v: &vector<u8>): vector<u8>;
+            native public fun internal_to_uppercase(v: &vector<u8>): vector<u8>;
+        }
+
+        module {{ADDR}}::M {
+            use std::string;
+
+            public fun check_utf8(v: vector<u8>) {
+                string::internal_check_utf8(&v);
+            }
+
+            public fun sub_string(v: vector<u8>, i: u64, j: u64) {
+                string::internal_sub_string(&v, i, j);
+            }
+
+            public fun index_of(v: vector<u8>, r: vector<u8>) {
+                string::internal_index_of(&v, &r);
+            }
+
+            public fun to_lowercase(v: vector<u8>) {
+                string::internal_to_lowercase(&v);
+            }
+
+            public fun to_uppercase(v: vector<u8>) {
+                string::internal_to_uppercase(&v);
+            }
+        }
+    "#;
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units(&code).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+
+    let mut gas_params = GasParameters::zeros();
+    gas_params.string.check_utf8 = string::CheckUtf8GasParameters {
+        base: 0.into(),
+        per_byte: PER_BYTE.into(),
+    };
+    gas_params.string.sub_string = string::SubStringGasParameters {
+        base: 0.into(),
+        per_byte: PER_BYTE.into(),
+    };
+    gas_params.string.index_of = string::IndexOfGasParameters {
+        base: 0.into(),
+        per_byte_pattern: PER_BYTE.into(),
+        per_byte_searched: PER_BYTE.into(),
+    };
+    gas_params.string.to_lowercase = string::ToLowercaseGasParameters {
+        base: 0.into(),
+        per_byte: PER_BYTE.into(),
+    };
+    gas_params.string.to_uppercase = string::ToUppercaseGasParameters {
+        base: 0.into(),
+        per_byte: PER_BYTE.into(),
+    };
+    let vm = MoveVM::new(all_natives(AccountAddress::ONE, gas_params)).unwrap();
+
+    let cost_table = zero_cost_schedule();
+    let gas_budget = Gas::new(10_000_000);
+    let mut gas_status = GasStatus::new(&cost_table, gas_budget);
+    let mut sess = vm.new_session(&storage);
+    let args: Vec<_> = args
+        .into_iter()
+        .map(|arg| arg.simple_serialize().unwrap())
+        .collect();
+    sess.execute_function_bypass_visibility(
+        &ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap()),
+        &Identifier::new(fun).unwrap(),
+        vec![],
+        args,
+        &mut gas_status,
+    )
+    .unwrap();
+    u64::from(gas_budget) - u64::from(gas_status.remaining_gas())
+}
+
+fn bytes(len: usize) -> MoveValue {
+    MoveValue::vector_u8(vec![b'a'; len])
+}
+
+#[test]
+fn check_utf8_gas_scales_with_input() {
+    let small = charged_gas("check_utf8", vec![bytes(2)]);
+    let large = charged_gas("check_utf8", vec![bytes(1 << 20)]);
+    assert_eq!(small, 2);
+    assert_eq!(large, 1 << 20);
+}
+
+#[test]
+fn sub_string_gas_scales_with_sub_string() {
+    let input = || bytes(1 << 10);
+    let small = charged_gas(
+        "sub_string",
+        vec![input(), MoveValue::U64(0), MoveValue::U64(2)],
+    );
+    let large = charged_gas(
+        "sub_string",
+        vec![input(), MoveValue::U64(0), MoveValue::U64(1 << 10)],
+    );
+    assert_eq!(small, 2);
+    assert_eq!(large, 1 << 10);
+}
+
+#[test]
+fn index_of_gas_scales_with_haystack_and_needle() {
+    let needle = || MoveValue::vector_u8(b"needle".to_vec());
+    let small = charged_gas("index_of", vec![bytes(2), needle()]);
+    let large = charged_gas("index_of", vec![bytes(1 << 20), needle()]);
+    assert!(small < large, "{} >= {}", small, large);
+    // The needle is not found, so the whole haystack is searched.
+    assert_eq!(large, (1 << 20) + 6);
+
+    let long_needle = MoveValue::vector_u8(vec![b'b'; 1 << 10]);
+    assert!(charged_gas("index_of", vec![bytes(2), long_needle]) > small);
+}
+
+#[test]
+fn case_mapping_gas_covers_input_and_output() {
+    // `ẞ` (3 bytes) lowercases to `ß` (2 bytes), which uppercases to `SS` (2 bytes).
+    let repeated = |c: &str| MoveValue::vector_u8(c.repeat(1 << 10).into_bytes());
+    assert_eq!(charged_gas("to_lowercase", vec![repeated("ẞ")]), 3 << 10);
+    assert_eq!(charged_gas("to_uppercase", vec![repeated("ß")]), 2 << 10);
+    // `İ` (2 bytes) lowercases to `i̇` (3 bytes).
+    assert_eq!(charged_gas("to_lowercase", vec![repeated("İ")]), 3 << 10);
+}
@@ -0,0 +1,168 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_binary_format::errors::VMResult;
+use move_core_types::{
+    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
+    value::MoveValue, vm_status::StatusCode,
+};
+use move_stdlib::natives::{all_natives, vector, GasParameters};
+use move_vm_runtime::move_vm::MoveVM;
+use move_vm_test_utils::{
+    gas_schedule::{zero_cost_schedule, Gas, GasStatus},
+    InMemoryStorage,
+};
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+// One gas unit, so that per element charges show in the remaining gas, which is rounded to units.
+const PER_ELEMENT: u64 = 1000;
+
+// One gas unit, so that per abstract memory unit charges show in the remaining gas.
+const PER_ABSTRACT_MEMORY_UNIT: u64 = 1000;
+
+const GAS_BUDGET: u64 = 1_000_000;
+
+// Publishes `code` and calls `fun` of its module `M`, returning the gas charged. Instructions are
+// free under the zero cost schedule, so this is the cost of the natives called by `fun`.
+fn charged_gas(
+    code: &str,
+    gas_params: GasParameters,
+    fun: &str,
+    args: Vec<MoveValue>,
+) -> (VMResult<()>, u64) {
+    let code = code.replace("{{ADDR}}", &TEST_ADDR.to_hex_literal());
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units(&code).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+
+    let vm = MoveVM::new(all_natives(AccountAddress::ONE, gas_params)).unwrap();
+
+    let cost_table = zero_cost_schedule();
+    let mut gas_status = GasStatus::new(&cost_table, Gas::new(GAS_BUDGET));
+    let mut sess = vm.new_session(&storage);
+    let args = args
+        .into_iter()
+        .map(|arg| arg.simple_serialize().unwrap())
+        .collect();
+    let res = sess
+        .execute_function_bypass_visibility(
+            &ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap()),
+            &Identifier::new(fun).unwrap(),
+            vec![],
+            args,
+            &mut gas_status,
+        )
+        .map(|_| ());
+    (res, GAS_BUDGET - u64::from(gas_status.remaining_gas()))
+}
+
+// Calls `shuffle_indices(len, seed)` and returns the gas charged.
+fn shuffle_indices(len: u64, seed: &[u8]) -> (VMResult<()>, u64) {
+    let code = r#"
+        module std::vector {
+            native fun native_shuffle_indices(len: u64, seed: &vector<u8>): vector<u64>;
+
+            public fun shuffle_indices(len: u64, seed: &vector<u8>): vector<u64> {
+                native_shuffle_indices(len, seed)
+            }
+        }
+
+        module {{ADDR}}::M {
+            use std::vector;
+
+            public fun shuffle_indices(len: u64, seed: vector<u8>) {
+                vector::shuffle_indices(len, &seed);
+            }
+        }
+    "#;
+    let mut gas_params = GasParameters::zeros();
+    gas_params.vector.shuffle_indices = vector::ShuffleIndicesGasParameters {
+        base: 0.into(),
+        per_element: PER_ELEMENT.into(),
+        per_byte: 0.into(),
+    };
+    charged_gas(
+        code,
+        gas_params,
+        "shuffle_indices",
+        vec![MoveValue::U64(len), MoveValue::vector_u8(seed.to_vec())],
+    )
+}
+
+// Calls `fun`, which searches its first argument for its second with `native_index_of`, and
+// returns the gas charged. Only the size of the compared values is charged for.
+fn index_of(fun: &str, args: Vec<MoveValue>) -> u64 {
+    let code = r#"
+        module std::vector {
+            native public fun native_index_of<Element>(v: &vector<Element>, e: &Element): (bool, u64);
+        }
+
+        module {{ADDR}}::M {
+            use std::vector;
+
+            public fun index_of_u64(v: vector<u64>, e: u64) {
+                vector::native_index_of(&v, &e);
+            }
+
+            public fun index_of_vec(v: vector<vector<u64>>, e: vector<u64>) {
+                vector::native_index_of(&v, &e);
+            }
+        }
+    "#;
+    let mut gas_params = GasParameters::zeros();
+    gas_params.vector.index_of = vector::IndexOfGasParameters {
+        base: 0.into(),
+        per_comparison: 0.into(),
+        legacy_per_abstract_memory_unit: PER_ABSTRACT_MEMORY_UNIT.into(),
+    };
+    let (res, charged) = charged_gas(code, gas_params, fun, args);
+    res.unwrap();
+    charged
+}
+
+#[test]
+fn shuffle_indices_gas_scales_with_len() {
+    let (res, small) = shuffle_indices(10, b"seed");
+    res.unwrap();
+    let (res, large) = shuffle_indices(1000, b"seed");
+    res.unwrap();
+    assert_eq!(small, 10);
+    assert_eq!(large, 1000);
+}
+
+#[test]
+fn shuffle_indices_runs_out_of_gas_before_allocating() {
+    // The indices alone would not fit into memory.
+    let (res, charged) = shuffle_indices(u64::MAX, b"seed");
+    assert_eq!(res.unwrap_err().major_status(), StatusCode::OUT_OF_GAS);
+    assert_eq!(charged, GAS_BUDGET);
+}
+
+#[test]
+fn index_of_gas_scales_with_compared_elements() {
+    let u64s = |len: u64| MoveValue::Vector((0..len).map(MoveValue::U64).collect());
+    // Every element is compared against the missing value, a `u64` of 16 units on either side.
+    let small = index_of("index_of_u64", vec![u64s(4), MoveValue::U64(100)]);
+    assert_eq!(small, 4 * 32);
+    // Found at the front, after a single comparison.
+    assert_eq!(
+        index_of("index_of_u64", vec![u64s(4), MoveValue::U64(0)]),
+        32
+    );
+
+    // The same number of comparisons between large elements costs more.
+    let large = index_of(
+        "index_of_vec",
+        vec![
+            MoveValue::Vector(vec![u64s(1000); 4]),
+            MoveValue::Vector(vec![MoveValue::U64(1); 1000]),
+        ],
+    );
+    assert!(large > 100 * small, "{} <= 100 * {}", large, small);
+}
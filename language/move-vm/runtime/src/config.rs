@@ -33,3 +33,34 @@ impl Default for VMConfig {
         }
     }
 }
+
+/// Options for publishing a single module bundle, see
+/// `Session::publish_module_bundle_with_option`.
+#[derive(Debug, Clone, Default)]
+pub struct PublishModuleBundleOption {
+    /// Overrides the verifier's metering budget per module for this bundle, e.g. to apply a
+    /// stricter limit to permissionless publishing, and caps the budget per function at it.
+    /// `None` keeps the budgets of the `VMConfig`.
+    pub verifier_metering_limit: Option<u64>,
+    /// Check all modules of the bundle and report every failure, instead of stopping at the first
+    /// one. See `Session::verify_module_bundle`.
+    pub collect_all_errors: bool,
+}
+
+impl PublishModuleBundleOption {
+    /// The verifier config to check the bundle with, derived from the VM's `config`.
+    pub(crate) fn verifier_config(&self, config: &VerifierConfig) -> VerifierConfig {
+        let mut config = config.clone();
+        if let Some(limit) = self.verifier_metering_limit {
+            let limit = limit as u128;
+            config.max_per_mod_meter_units = Some(limit);
+            // No function can use more than the budget of its module.
+            config.max_per_fun_meter_units = Some(
+                config
+                    .max_per_fun_meter_units
+                    .map_or(limit, |max| max.min(limit)),
+            );
+        }
+        config
+    }
+}
@@ -13,10 +13,10 @@ use move_binary_format::CompiledModule;
 use move_core_types::language_storage::StructTag;
 use move_core_types::{
     account_address::AccountAddress,
-    effects::{AccountChangeSet, ChangeSet, Event, Op},
+    effects::{AccountChangeSet, ChangeSet, Event, Op, StorageDelta},
     gas_algebra::NumBytes,
     identifier::Identifier,
-    language_storage::{ModuleId, TypeTag},
+    language_storage::{ModuleId, ResourceKey, TypeTag},
     resolver::MoveResolver,
     value::MoveTypeLayout,
     vm_status::StatusCode,
@@ -69,6 +69,16 @@ fn load_module_impl<'r, S: MoveResolver>(
     }
 }
 
+/// Observes the modules loaded by the loader during a session, e.g. to trace the dependencies of
+/// the executed code in load order. See `MoveVM::new_session_with_module_load_observer`.
+///
+/// Only modules which are not in the code cache of the VM yet are loaded, so a module which was
+/// loaded by an earlier session of the same VM is not observed again.
+pub trait ModuleLoadObserver {
+    /// Called with the id of each loaded module, after the modules it depends on.
+    fn on_module_loaded(&self, module_id: &ModuleId);
+}
+
 /// Transaction data cache. Keep updates within a transaction so they can all be published at
 /// once when the transaction succeeds.
 ///
@@ -91,6 +101,8 @@ pub(crate) struct TransactionDataCache<'r, 'l, S> {
     // Caches to help avoid duplicate deserialization calls.
     compiled_scripts: BTreeMap<[u8; 32], Arc<CompiledScript>>,
     compiled_modules: BTreeMap<ModuleId, (Arc<CompiledModule>, usize, [u8; 32])>,
+
+    module_load_observer: Option<Arc<dyn ModuleLoadObserver>>,
 }
 
 impl<'r, 'l, S: MoveResolver> TransactionDataCache<'r, 'l, S> {
@@ -105,9 +117,20 @@ impl<'r, 'l, S: MoveResolver> TransactionDataCache<'r, 'l, S> {
 
             compiled_scripts: BTreeMap::new(),
             compiled_modules: BTreeMap::new(),
+
+            module_load_observer: None,
         }
     }
 
+    /// Notify `observer` of the modules loaded with this data cache.
+    pub(crate) fn with_module_load_observer(
+        mut self,
+        observer: Arc<dyn ModuleLoadObserver>,
+    ) -> Self {
+        self.module_load_observer = Some(observer);
+        self
+    }
+
     /// Make a write set from the updated (dirty, deleted) global resources along with
     /// published modules.
     ///
@@ -171,6 +194,69 @@ impl<'r, 'l, S: MoveResolver> TransactionDataCache<'r, 'l, S> {
         Ok((change_set, events))
     }
 
+    /// Computes the change in storage usage of the effects `into_effects` would produce, relative
+    /// to the remote storage.
+    pub(crate) fn storage_delta(&self) -> PartialVMResult<StorageDelta> {
+        fn prior_size<E: std::fmt::Debug>(
+            blob: Result<Option<Vec<u8>>, E>,
+        ) -> PartialVMResult<usize> {
+            match blob {
+                Ok(blob) => Ok(blob.map_or(0, |blob| blob.len())),
+                Err(err) => {
+                    let msg = format!("Unexpected storage error: {:?}", err);
+                    Err(
+                        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                            .with_message(msg),
+                    )
+                }
+            }
+        }
+
+        let mut delta = StorageDelta::default();
+        for (addr, account_data_cache) in &self.account_map {
+            for (module_name, (module_blob, is_republishing)) in &account_data_cache.module_map {
+                if *is_republishing {
+                    let module_id = ModuleId::new(*addr, module_name.clone());
+                    let prior = prior_size(self.remote.get_module(&module_id))?;
+                    delta.record(&Op::Modify(module_blob), prior);
+                } else {
+                    delta.record(&Op::New(module_blob), 0);
+                }
+            }
+
+            for (struct_tag, (layout, gv)) in &account_data_cache.data_map {
+                let op = match gv.effect()? {
+                    Some(op) => op,
+                    None => continue,
+                };
+                let op = match op {
+                    Op::New(val) => Op::New(
+                        val.simple_serialize(layout)
+                            .ok_or_else(|| PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR))?,
+                    ),
+                    Op::Modify(val) => Op::Modify(
+                        val.simple_serialize(layout)
+                            .ok_or_else(|| PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR))?,
+                    ),
+                    Op::Delete => Op::Delete,
+                };
+                let prior = match op {
+                    Op::New(_) => 0,
+                    Op::Modify(_) | Op::Delete => {
+                        prior_size(self.remote.get_resource(addr, struct_tag))?
+                    }
+                };
+                delta.record(&op, prior);
+            }
+        }
+        Ok(delta)
+    }
+
+    /// Passes the prefetch hint for `keys` on to the remote storage.
+    pub(crate) fn prefetch(&self, keys: &[ResourceKey]) {
+        self.remote.prefetch(keys)
+    }
+
     pub(crate) fn num_mutated_accounts(&self, sender: &AccountAddress) -> u64 {
         // The sender's account will always be mutated.
         let mut total_mutated_accounts: u64 = 1;
@@ -338,6 +424,12 @@ impl<'r, 'l, S: MoveResolver> DataStore for TransactionDataCache<'r, 'l, S> {
             .map_err(|pve| pve.finish(Location::Undefined))
     }
 
+    fn on_module_loaded(&self, module_id: &ModuleId) {
+        if let Some(observer) = &self.module_load_observer {
+            observer.on_module_loaded(module_id);
+        }
+    }
+
     fn publish_module(
         &mut self,
         module_id: &ModuleId,
@@ -13,7 +13,7 @@ use crate::{
 use move_binary_format::{
     access::{ModuleAccess, ScriptAccess},
     binary_views::BinaryIndexedView,
-    errors::{verification_error, Location, PartialVMError, PartialVMResult, VMResult},
+    errors::{verification_error, Location, PartialVMError, PartialVMResult, VMError, VMResult},
     file_format::{
         AbilitySet, Bytecode, CompiledModule, CompiledScript, Constant, ConstantPoolIndex,
         FieldHandleIndex, FieldInstantiationIndex, FunctionDefinition, FunctionDefinitionIndex,
@@ -23,7 +23,7 @@ use move_binary_format::{
     },
     IndexKind,
 };
-use move_bytecode_verifier::{self, cyclic_dependencies, dependencies};
+use move_bytecode_verifier::{self, cyclic_dependencies, dependencies, VerifierConfig};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::gas_algebra::NumBytes;
 use move_core_types::resolver::MoveResolver;
@@ -84,6 +84,18 @@ where
     fn get(&self, key: &K) -> Option<&Arc<V>> {
         self.id_map.get(key).and_then(|idx| self.binaries.get(*idx))
     }
+
+    // Removes a binary, moving the last binary into its slot.
+    fn remove(&mut self, key: &K) -> Option<Arc<V>> {
+        let idx = self.id_map.remove(key)?;
+        let last_idx = self.binaries.len() - 1;
+        if idx != last_idx {
+            if let Some(moved_idx) = self.id_map.values_mut().find(|i| **i == last_idx) {
+                *moved_idx = idx;
+            }
+        }
+        Some(self.binaries.swap_remove(idx))
+    }
 }
 
 // A script cache is a map from the hash value of a script and the `Script` itself.
@@ -168,6 +180,29 @@ impl ModuleCache {
         Arc::clone(&self.structs[idx.0])
     }
 
+    // Remove a module and all the cached modules which depend on it, directly or transitively.
+    // The structs and functions of the removed modules stay in the global tables, as cached
+    // types may still refer to them by index, but they are no longer reachable from a module.
+    // Returns the ids of the removed modules.
+    fn remove_module_and_dependents(&mut self, id: &ModuleId) -> BTreeSet<ModuleId> {
+        let mut removed = BTreeSet::new();
+        let mut pending = vec![id.clone()];
+        while let Some(id) = pending.pop() {
+            if self.modules.remove(&id).is_none() {
+                continue;
+            }
+            pending.extend(
+                self.modules
+                    .binaries
+                    .iter()
+                    .filter(|module| module.module.immediate_dependencies().contains(&id))
+                    .map(|module| module.module.self_id()),
+            );
+            removed.insert(id);
+        }
+        removed
+    }
+
     //
     // Insertion is under lock and it's a pretty heavy operation.
     // The VM is pretty much stopped waiting for this to finish
@@ -689,6 +724,20 @@ impl Loader {
         }
     }
 
+    /// Evict a module, and all the modules depending on it, from this cache. Unlike
+    /// `flush_if_invalidated`, unrelated modules stay cached. Scripts are always evicted as
+    /// they may depend on the module. Returns the ids of the evicted modules.
+    pub(crate) fn invalidate_module(&self, id: &ModuleId) -> BTreeSet<ModuleId> {
+        let evicted = self.module_cache.write().remove_module_and_dependents(id);
+        if !evicted.is_empty() {
+            *self.scripts.write() = ScriptCache::new();
+            self.module_cache_hits
+                .write()
+                .retain(|hit| !evicted.contains(hit));
+        }
+        evicted
+    }
+
     /// Mark this cache as invalidated.
     pub(crate) fn mark_as_invalid(&self) {
         *self.invalidated.write() = true;
@@ -699,6 +748,20 @@ impl Loader {
         *self.invalidated.read()
     }
 
+    /// Returns the ids of all the modules currently held in this cache, in order.
+    pub(crate) fn loaded_modules(&self) -> Vec<ModuleId> {
+        let mut ids: Vec<_> = self
+            .module_cache
+            .read()
+            .modules
+            .id_map
+            .keys()
+            .cloned()
+            .collect();
+        ids.sort();
+        ids
+    }
+
     /// Copies metadata out of a modules bytecode if available.
     pub(crate) fn get_metadata(&self, module: ModuleId, key: &[u8]) -> Option<Metadata> {
         let cache = self.module_cache.read();
@@ -1020,23 +1083,57 @@ impl Loader {
         modules: &[CompiledModule],
         data_store: &mut impl DataStore,
     ) -> VMResult<()> {
+        self.verify_module_bundle_for_publication_with_config(
+            modules,
+            data_store,
+            &self.vm_config.verifier,
+            /* collect_all_errors */ false,
+        )
+        .map_err(|mut errors| errors.swap_remove(0).1)
+    }
+
+    // Same as `verify_module_bundle_for_publication`, but verifies the bytecode of the bundle with
+    // `verifier_config` instead of the config of the VM.
+    //
+    // The failures are returned with the index of the module in `modules`. Verification stops at
+    // the first failure, unless `collect_all_errors` is set. In that case a module which fails is
+    // skipped: the modules after it are verified as if it was not part of the bundle.
+    pub(crate) fn verify_module_bundle_for_publication_with_config(
+        &self,
+        modules: &[CompiledModule],
+        data_store: &mut impl DataStore,
+        verifier_config: &VerifierConfig,
+        collect_all_errors: bool,
+    ) -> Result<(), Vec<(usize, VMError)>> {
         fail::fail_point!("verifier-failpoint-1", |_| { Ok(()) });
 
+        let mut errors = vec![];
         let mut bundle_unverified: BTreeSet<_> = modules.iter().map(|m| m.self_id()).collect();
         let mut bundle_verified = BTreeMap::new();
-        for module in modules {
+        for (idx, module) in modules.iter().enumerate() {
             let module_id = module.self_id();
             bundle_unverified.remove(&module_id);
 
-            self.verify_module_for_publication(
+            if let Err(err) = self.verify_module_for_publication(
                 module,
                 &bundle_verified,
                 &bundle_unverified,
                 data_store,
-            )?;
+                verifier_config,
+            ) {
+                errors.push((idx, err));
+                if !collect_all_errors {
+                    break;
+                }
+                continue;
+            }
             bundle_verified.insert(module_id.clone(), module.clone());
         }
-        Ok(())
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
     }
 
     // A module to be published must be loadable.
@@ -1056,12 +1153,13 @@ impl Loader {
         bundle_verified: &BTreeMap<ModuleId, CompiledModule>,
         bundle_unverified: &BTreeSet<ModuleId>,
         data_store: &impl DataStore,
+        verifier_config: &VerifierConfig,
     ) -> VMResult<()> {
         // Performs all verification steps to load the module without loading it, i.e., the new
         // module will NOT show up in `module_cache`. In the module republishing case, it means
         // that the old module is still in the `module_cache`, unless a new Loader is created,
         // which means that a new MoveVM instance needs to be created.
-        move_bytecode_verifier::verify_module_with_config(&self.vm_config.verifier, module)?;
+        move_bytecode_verifier::verify_module_with_config(verifier_config, module)?;
         self.check_natives(module)?;
 
         let mut visited = BTreeSet::new();
@@ -1356,6 +1454,7 @@ impl Loader {
         let mut locked_cache = self.module_cache.write();
         let module_ref = locked_cache.insert(&self.natives, id.clone(), module_size, module)?;
         drop(locked_cache); // explicit unlock
+        data_store.on_module_loaded(id);
 
         Ok(module_ref)
     }
@@ -2,11 +2,15 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::BTreeSet, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeSet, sync::Arc};
 
 use crate::{
-    config::VMConfig, data_cache::TransactionDataCache, native_extensions::NativeContextExtensions,
-    native_functions::NativeFunction, runtime::VMRuntime, session::Session,
+    config::VMConfig,
+    data_cache::{ModuleLoadObserver, TransactionDataCache},
+    native_extensions::NativeContextExtensions,
+    native_functions::NativeFunction,
+    runtime::VMRuntime,
+    session::Session,
 };
 use move_binary_format::errors::PartialVMResult;
 use move_binary_format::{
@@ -14,14 +18,35 @@ use move_binary_format::{
     CompiledModule,
 };
 use move_core_types::{
-    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
-    metadata::Metadata, resolver::MoveResolver,
+    account_address::AccountAddress,
+    effects::{ChangeSet, Event},
+    gas_algebra::InternalGas,
+    identifier::{IdentStr, Identifier},
+    language_storage::{ModuleId, TypeTag},
+    metadata::Metadata,
+    resolver::MoveResolver,
+    value::MoveValue,
+    vm_status::VMStatus,
 };
+use move_vm_types::gas::GasMeter;
 
 pub struct MoveVM {
     runtime: VMRuntime,
 }
 
+/// The outcome of a simulated entry function call, see `MoveVM::simulate`.
+#[derive(Debug)]
+pub struct SimulationResult {
+    /// Gas charged to the gas meter during execution.
+    pub gas_used: InternalGas,
+    /// The changes the call would make to storage. Empty if execution failed.
+    pub changes: ChangeSet,
+    /// The events the call would emit. Empty if execution failed.
+    pub events: Vec<Event>,
+    /// `VMStatus::Executed` if the call succeeded, or the status of the error it failed with.
+    pub status: VMStatus,
+}
+
 impl MoveVM {
     pub fn new(
         natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
@@ -66,6 +91,17 @@ impl MoveVM {
         self.runtime.new_session_with_extensions(remote, extensions)
     }
 
+    /// Create a new session, as in `new_session`, which notifies `observer` of each module the
+    /// loader loads during the session, in load order.
+    pub fn new_session_with_module_load_observer<'r, S: MoveResolver>(
+        &self,
+        remote: &'r S,
+        observer: Arc<dyn ModuleLoadObserver>,
+    ) -> Session<'r, '_, S> {
+        self.runtime
+            .new_session_with_module_load_observer(remote, observer)
+    }
+
     /// Load a module into VM's code cache
     pub fn load_module<'r, S: MoveResolver>(
         &self,
@@ -104,6 +140,15 @@ impl MoveVM {
         self.runtime.loader().flush_if_invalidated()
     }
 
+    /// Evicts a module from the loader cache, together with all the cached modules which depend
+    /// on it, so that they are loaded again from the data store on next use. Other modules stay
+    /// cached. This is a cheaper alternative to flushing the whole cache after upgrading a few
+    /// modules. Like the above, it should only be called if there are no outstanding sessions
+    /// created from this VM. Returns the ids of the evicted modules.
+    pub fn invalidate_loader_cache_for_module(&self, module_id: &ModuleId) -> BTreeSet<ModuleId> {
+        self.runtime.loader().invalidate_module(module_id)
+    }
+
     /// Gets and clears module cache hits. This is hack which allows the adapter to see module
     /// reads if executing multiple transactions in a VM. Without this, the adapter only sees
     /// the first load of a module.
@@ -129,6 +174,58 @@ impl MoveVM {
         self.runtime.loader().get_metadata(module, key)
     }
 
+    /// Executes the entry function `module::function` on behalf of `senders` in a fresh session
+    /// and returns the full effects of the call, without committing them anywhere.
+    ///
+    /// The senders are passed as the leading `signer` arguments, followed by `args`. Failures of
+    /// the call itself are reported in the `status` of the result, an `Err` is only returned if
+    /// the effects of a successful call could not be produced.
+    pub fn simulate<S: MoveResolver>(
+        &self,
+        remote: &S,
+        module: &ModuleId,
+        function: &IdentStr,
+        ty_args: Vec<TypeTag>,
+        args: Vec<impl Borrow<[u8]>>,
+        senders: Vec<AccountAddress>,
+        gas_meter: &mut impl GasMeter,
+    ) -> VMResult<SimulationResult> {
+        let args: Vec<Vec<u8>> = senders
+            .into_iter()
+            .map(|sender| {
+                MoveValue::Signer(sender)
+                    .simple_serialize()
+                    .expect("transaction arguments must serialize")
+            })
+            .chain(args.iter().map(|arg| arg.borrow().to_vec()))
+            .collect();
+
+        let balance_before = gas_meter.balance_internal();
+        let mut session = self.new_session(remote);
+        let result = session.execute_entry_function(module, function, ty_args, args, gas_meter);
+        let gas_used = balance_before
+            .checked_sub(gas_meter.balance_internal())
+            .unwrap_or(0.into());
+
+        match result {
+            Ok(_) => {
+                let (changes, events) = session.finish()?;
+                Ok(SimulationResult {
+                    gas_used,
+                    changes,
+                    events,
+                    status: VMStatus::Executed,
+                })
+            }
+            Err(err) => Ok(SimulationResult {
+                gas_used,
+                changes: ChangeSet::new(),
+                events: vec![],
+                status: err.into_vm_status(),
+            }),
+        }
+    }
+
     pub fn update_native_functions(
         &mut self,
         natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
@@ -5,12 +5,15 @@
 use crate::{
     interpreter::Interpreter, loader::Resolver, native_extensions::NativeContextExtensions,
 };
-use move_binary_format::errors::{ExecutionState, PartialVMError, PartialVMResult};
+use move_binary_format::{
+    errors::{ExecutionState, PartialVMError, PartialVMResult},
+    file_format::AbilitySet,
+};
 use move_core_types::{
     account_address::AccountAddress,
     gas_algebra::InternalGas,
     identifier::Identifier,
-    language_storage::TypeTag,
+    language_storage::{ModuleId, TypeTag},
     value::MoveTypeLayout,
     vm_status::{StatusCode, StatusType},
 };
@@ -140,10 +143,30 @@ impl<'a, 'b> NativeContext<'a, 'b> {
         self.data_store.events()
     }
 
+    /// Returns the serialized bytes of the module `module_id`, or `None` if it is not published.
+    pub fn load_module_bytes(&self, module_id: &ModuleId) -> PartialVMResult<Option<Vec<u8>>> {
+        if !self
+            .data_store
+            .exists_module(module_id)
+            .map_err(|e| e.to_partial())?
+        {
+            return Ok(None);
+        }
+        self.data_store
+            .load_module(module_id)
+            .map(Some)
+            .map_err(|e| e.to_partial())
+    }
+
     pub fn type_to_type_tag(&self, ty: &Type) -> PartialVMResult<TypeTag> {
         self.resolver.loader().type_to_type_tag(ty)
     }
 
+    /// Returns the abilities of `ty` at its instantiation.
+    pub fn type_abilities(&self, ty: &Type) -> PartialVMResult<AbilitySet> {
+        self.resolver.loader().abilities(ty)
+    }
+
     pub fn type_to_type_layout(&self, ty: &Type) -> PartialVMResult<Option<MoveTypeLayout>> {
         match self.resolver.type_to_type_layout(ty) {
             Ok(ty_layout) => Ok(Some(ty_layout)),
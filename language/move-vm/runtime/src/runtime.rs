@@ -4,8 +4,8 @@
 
 use crate::loader::Module;
 use crate::{
-    config::VMConfig,
-    data_cache::TransactionDataCache,
+    config::{PublishModuleBundleOption, VMConfig},
+    data_cache::{ModuleLoadObserver, TransactionDataCache},
     interpreter::Interpreter,
     loader::{Function, Loader},
     native_extensions::NativeContextExtensions,
@@ -14,9 +14,10 @@ use crate::{
 };
 use move_binary_format::{
     access::ModuleAccess,
+    binary_views::BinaryIndexedView,
     compatibility::Compatibility,
-    errors::{verification_error, Location, PartialVMError, PartialVMResult, VMResult},
-    file_format::LocalIndex,
+    errors::{verification_error, Location, PartialVMError, PartialVMResult, VMError, VMResult},
+    file_format::{LocalIndex, SignatureIndex},
     normalized, CompiledModule, IndexKind,
 };
 use move_bytecode_verifier::script_signature;
@@ -34,6 +35,7 @@ use move_vm_types::{
     loaded_data::runtime_types::Type,
     values::{Locals, Reference, VMValueCast, Value},
 };
+use sha3::{Digest, Sha3_256};
 use std::{borrow::Borrow, collections::BTreeSet, sync::Arc};
 use tracing::warn;
 
@@ -68,76 +70,82 @@ impl VMRuntime {
         }
     }
 
+    pub fn new_session_with_module_load_observer<'r, S: MoveResolver>(
+        &self,
+        remote: &'r S,
+        observer: Arc<dyn ModuleLoadObserver>,
+    ) -> Session<'r, '_, S> {
+        Session {
+            runtime: self,
+            data_cache: TransactionDataCache::new(remote, &self.loader)
+                .with_module_load_observer(observer),
+            native_extensions: NativeContextExtensions::default(),
+        }
+    }
+
+    /// Deserialize the modules. Perform bounds check. After this indexes can be
+    /// used with the `[]` operator
+    fn deserialize_module_bundle(&self, modules: &[Vec<u8>]) -> VMResult<Vec<CompiledModule>> {
+        modules
+            .iter()
+            .enumerate()
+            .map(|(idx, blob)| self.deserialize_bundle_module(idx, blob))
+            .collect()
+    }
+
+    /// Deserialize the module at index `idx` of a bundle.
+    fn deserialize_bundle_module(&self, idx: usize, blob: &[u8]) -> VMResult<CompiledModule> {
+        CompiledModule::deserialize_with_max_version(
+            blob,
+            self.loader.vm_config().max_binary_format_version,
+        )
+        .map_err(|err| {
+            // Identify the offending module, as the error itself carries no location.
+            let hash = hex::encode(Sha3_256::digest(blob));
+            warn!(
+                "[VM] deserialization of module at bundle index {} (sha3-256 {}) failed {:?}",
+                idx, hash, err
+            );
+            err.append_message_with_separator(
+                ' ',
+                format!("(bundle index {}, module blob sha3-256 {})", idx, hash),
+            )
+            .finish(Location::Undefined)
+        })
+    }
+
+    /// Returns the ids of all modules the bundle depends on which are not part of the bundle
+    /// itself.
+    pub(crate) fn bundle_dependencies(&self, modules: &[Vec<u8>]) -> VMResult<BTreeSet<ModuleId>> {
+        let compiled_modules = self.deserialize_module_bundle(modules)?;
+        let bundle: BTreeSet<_> = compiled_modules.iter().map(|m| m.self_id()).collect();
+        Ok(compiled_modules
+            .iter()
+            .flat_map(|m| m.immediate_dependencies())
+            .filter(|dep| !bundle.contains(dep))
+            .collect())
+    }
+
     pub(crate) fn publish_module_bundle(
         &self,
         modules: Vec<Vec<u8>>,
         sender: AccountAddress,
+        allow_addresses: &BTreeSet<AccountAddress>,
         data_store: &mut impl DataStore,
         _gas_meter: &mut impl GasMeter,
         compat: Compatibility,
+        option: &PublishModuleBundleOption,
     ) -> VMResult<()> {
-        // deserialize the modules. Perform bounds check. After this indexes can be
-        // used with the `[]` operator
-        let compiled_modules = match modules
-            .iter()
-            .map(|blob| {
-                CompiledModule::deserialize_with_max_version(
-                    blob,
-                    self.loader.vm_config().max_binary_format_version,
-                )
-            })
-            .collect::<PartialVMResult<Vec<_>>>()
-        {
-            Ok(modules) => modules,
-            Err(err) => {
-                warn!("[VM] module deserialization failed {:?}", err);
-                return Err(err.finish(Location::Undefined));
-            }
-        };
-
-        // Make sure all modules' self addresses matches the transaction sender. The self address is
-        // where the module will actually be published. If we did not check this, the sender could
-        // publish a module under anyone's account.
-        for module in &compiled_modules {
-            if module.address() != &sender {
-                return Err(verification_error(
-                    StatusCode::MODULE_ADDRESS_DOES_NOT_MATCH_SENDER,
-                    IndexKind::AddressIdentifier,
-                    module.self_handle_idx().0,
-                )
-                .finish(Location::Undefined));
-            }
-        }
-
-        // Collect ids for modules that are published together
-        let mut bundle_unverified = BTreeSet::new();
-
-        // For now, we assume that all modules can be republished, as long as the new module is
-        // backward compatible with the old module.
-        //
-        // TODO: in the future, we may want to add restrictions on module republishing, possibly by
-        // changing the bytecode format to include an `is_upgradable` flag in the CompiledModule.
-        for module in &compiled_modules {
-            let module_id = module.self_id();
-
-            if data_store.exists_module(&module_id)? && compat.need_check_compat() {
-                let old_module_ref = self.loader.load_module(&module_id, data_store)?;
-                let old_module = old_module_ref.module();
-                let old_m = normalized::Module::new(old_module);
-                let new_m = normalized::Module::new(module);
-                compat
-                    .check(&old_m, &new_m)
-                    .map_err(|e| e.finish(Location::Undefined))?;
-            }
-            if !bundle_unverified.insert(module_id) {
-                return Err(PartialVMError::new(StatusCode::DUPLICATE_MODULE_NAME)
-                    .finish(Location::Undefined));
-            }
-        }
-
-        // Perform bytecode and loading verification. Modules must be sorted in topological order.
-        self.loader
-            .verify_module_bundle_for_publication(&compiled_modules, data_store)?;
+        let compiled_modules = self
+            .verify_module_bundle(
+                &modules,
+                sender,
+                allow_addresses,
+                data_store,
+                compat,
+                option,
+            )
+            .map_err(|mut errors| errors.swap_remove(0).1)?;
 
         // NOTE: we want to (informally) argue that all modules pass the linking check before being
         // published to the data store.
@@ -205,6 +213,112 @@ impl VMRuntime {
         Ok(())
     }
 
+    /// Performs all checks to publish the bundle `modules`, without publishing it, and returns
+    /// the deserialized modules.
+    ///
+    /// The failures are returned together with the index of the offending module in the bundle.
+    /// Unless `option.collect_all_errors` is set, the checks stop at the first failure. Otherwise
+    /// all modules are checked, skipping a module in the remaining checks once it failed one.
+    pub(crate) fn verify_module_bundle(
+        &self,
+        modules: &[Vec<u8>],
+        sender: AccountAddress,
+        allow_addresses: &BTreeSet<AccountAddress>,
+        data_store: &mut impl DataStore,
+        compat: Compatibility,
+        option: &PublishModuleBundleOption,
+    ) -> Result<Vec<CompiledModule>, Vec<(usize, VMError)>> {
+        let collect_all_errors = option.collect_all_errors;
+        let mut errors = vec![];
+
+        let candidates = check_bundle_modules(
+            modules.iter().enumerate().collect(),
+            &mut errors,
+            collect_all_errors,
+            |idx, blob| self.deserialize_bundle_module(idx, blob),
+        );
+        if !errors.is_empty() && !collect_all_errors {
+            return Err(errors);
+        }
+
+        // Make sure all modules' self addresses matches the transaction sender. The self address is
+        // where the module will actually be published. If we did not check this, the sender could
+        // publish a module under anyone's account. Addresses in `allow_addresses` are exempt from
+        // this check, e.g. resource accounts the sender publishes on behalf of.
+        let candidates =
+            check_bundle_modules(candidates, &mut errors, collect_all_errors, |_, module| {
+                if module.address() != &sender && !allow_addresses.contains(module.address()) {
+                    return Err(verification_error(
+                        StatusCode::MODULE_ADDRESS_DOES_NOT_MATCH_SENDER,
+                        IndexKind::AddressIdentifier,
+                        module.self_handle_idx().0,
+                    )
+                    .finish(Location::Undefined));
+                }
+                Ok(module)
+            });
+        if !errors.is_empty() && !collect_all_errors {
+            return Err(errors);
+        }
+
+        // Collect ids for modules that are published together
+        let mut bundle_unverified = BTreeSet::new();
+
+        // For now, we assume that all modules can be republished, as long as the new module is
+        // backward compatible with the old module.
+        //
+        // TODO: in the future, we may want to add restrictions on module republishing, possibly by
+        // changing the bytecode format to include an `is_upgradable` flag in the CompiledModule.
+        let candidates =
+            check_bundle_modules(candidates, &mut errors, collect_all_errors, |_, module| {
+                let module_id = module.self_id();
+
+                if data_store.exists_module(&module_id)? && compat.need_check_compat() {
+                    let old_module_ref = self.loader.load_module(&module_id, data_store)?;
+                    let old_module = old_module_ref.module();
+                    let old_m = normalized::Module::new(old_module);
+                    let new_m = normalized::Module::new(&module);
+                    compat
+                        .check(&old_m, &new_m)
+                        .map_err(|e| e.finish(Location::Undefined))?;
+                }
+                if !bundle_unverified.insert(module_id) {
+                    return Err(PartialVMError::new(StatusCode::DUPLICATE_MODULE_NAME)
+                        .finish(Location::Undefined));
+                }
+                Ok(module)
+            });
+        if !errors.is_empty() && !collect_all_errors {
+            return Err(errors);
+        }
+
+        // Perform bytecode and loading verification. Modules must be sorted in topological order.
+        let (indices, compiled_modules): (Vec<_>, Vec<_>) = candidates.into_iter().unzip();
+        let verifier_config = option.verifier_config(&self.loader.vm_config().verifier);
+        if let Err(verification_errors) = self
+            .loader
+            .verify_module_bundle_for_publication_with_config(
+                &compiled_modules,
+                data_store,
+                &verifier_config,
+                collect_all_errors,
+            )
+        {
+            errors.extend(
+                verification_errors
+                    .into_iter()
+                    .map(|(pos, err)| (indices[pos], err)),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(compiled_modules)
+        } else {
+            errors.sort_by_key(|(idx, _)| *idx);
+            Err(errors)
+        }
+    }
+
     fn deserialize_value(&self, ty: &Type, arg: impl Borrow<[u8]>) -> PartialVMResult<Value> {
         let layout = match self.loader.type_to_type_layout(ty) {
             Ok(layout) => layout,
@@ -422,22 +536,6 @@ impl VMRuntime {
         extensions: &mut NativeContextExtensions,
         bypass_declared_entry_check: bool,
     ) -> VMResult<SerializedReturnValues> {
-        use move_binary_format::{binary_views::BinaryIndexedView, file_format::SignatureIndex};
-        fn check_is_entry(
-            _resolver: &BinaryIndexedView,
-            is_entry: bool,
-            _parameters_idx: SignatureIndex,
-            _return_idx: Option<SignatureIndex>,
-        ) -> PartialVMResult<()> {
-            if is_entry {
-                Ok(())
-            } else {
-                Err(PartialVMError::new(
-                    StatusCode::EXECUTE_ENTRY_FUNCTION_CALLED_ON_NON_ENTRY_FUNCTION,
-                ))
-            }
-        }
-
         let additional_signature_checks = if bypass_declared_entry_check {
             move_bytecode_verifier::no_additional_script_signature_checks
         } else {
@@ -503,7 +601,133 @@ impl VMRuntime {
         )
     }
 
+    // See Session::verify_script_args.
+    pub(crate) fn verify_script_args(
+        &self,
+        script: impl Borrow<[u8]>,
+        ty_args: Vec<TypeTag>,
+        args: Vec<impl Borrow<[u8]>>,
+        senders: Vec<AccountAddress>,
+        data_store: &mut impl DataStore,
+    ) -> VMResult<()> {
+        let (_, loaded_script) = self
+            .loader
+            .load_script(script.borrow(), &ty_args, data_store)?;
+        self.verify_signers_and_args(loaded_script, args, senders)
+    }
+
+    // See Session::verify_script_function_args.
+    pub(crate) fn verify_script_function_args(
+        &self,
+        module: &ModuleId,
+        function_name: &IdentStr,
+        ty_args: Vec<TypeTag>,
+        args: Vec<impl Borrow<[u8]>>,
+        senders: Vec<AccountAddress>,
+        data_store: &mut impl DataStore,
+    ) -> VMResult<()> {
+        let (module, _, loaded_func) =
+            self.loader
+                .load_function(module, function_name, &ty_args, data_store)?;
+        script_signature::verify_module_function_signature_by_name(
+            module.module(),
+            function_name,
+            check_is_entry,
+        )?;
+        self.verify_signers_and_args(loaded_func, args, senders)
+    }
+
+    // Checks that the leading signer parameters of a loaded script or function match `senders`,
+    // and that `args` deserialize to the types of the remaining parameters.
+    fn verify_signers_and_args(
+        &self,
+        loaded_func: LoadedFunctionInstantiation,
+        args: Vec<impl Borrow<[u8]>>,
+        senders: Vec<AccountAddress>,
+    ) -> VMResult<()> {
+        let LoadedFunctionInstantiation {
+            type_arguments,
+            parameters,
+            ..
+        } = loaded_func;
+        let arg_types = parameters
+            .into_iter()
+            .map(|ty| ty.subst(&type_arguments))
+            .collect::<PartialVMResult<Vec<_>>>()
+            .map_err(|err| err.finish(Location::Undefined))?;
+
+        // The signers come first, passed by value or by reference.
+        let num_signers = arg_types
+            .iter()
+            .take_while(|ty| match ty {
+                Type::Signer => true,
+                Type::Reference(inner) => matches!(**inner, Type::Signer),
+                _ => false,
+            })
+            .count();
+        if num_signers != senders.len() {
+            return Err(
+                PartialVMError::new(StatusCode::NUMBER_OF_SIGNER_ARGUMENTS_MISMATCH)
+                    .with_message(format!(
+                        "signer length mismatch: expected {} got {}",
+                        num_signers,
+                        senders.len()
+                    ))
+                    .finish(Location::Undefined),
+            );
+        }
+
+        let serialized_args: Vec<Vec<u8>> = senders
+            .into_iter()
+            .map(|sender| sender.to_vec())
+            .chain(args.iter().map(|arg| arg.borrow().to_vec()))
+            .collect();
+        self.deserialize_args(arg_types, serialized_args)
+            .map(|_| ())
+            .map_err(|e| e.finish(Location::Undefined))
+    }
+
     pub(crate) fn loader(&self) -> &Loader {
         &self.loader
     }
 }
+
+// The additional script signature check for calls which must target an entry function.
+fn check_is_entry(
+    _resolver: &BinaryIndexedView,
+    is_entry: bool,
+    _parameters_idx: SignatureIndex,
+    _return_idx: Option<SignatureIndex>,
+) -> PartialVMResult<()> {
+    if is_entry {
+        Ok(())
+    } else {
+        Err(PartialVMError::new(
+            StatusCode::EXECUTE_ENTRY_FUNCTION_CALLED_ON_NON_ENTRY_FUNCTION,
+        ))
+    }
+}
+
+// Runs `check` on the modules of a bundle, given with their index in the bundle, and returns those
+// which pass. The failures are added to `errors`; unless `collect_all_errors` is set, the first one
+// ends the check.
+fn check_bundle_modules<T, U>(
+    modules: Vec<(usize, T)>,
+    errors: &mut Vec<(usize, VMError)>,
+    collect_all_errors: bool,
+    mut check: impl FnMut(usize, T) -> VMResult<U>,
+) -> Vec<(usize, U)> {
+    let mut passed = vec![];
+    for (idx, module) in modules {
+        match check(idx, module) {
+            Ok(module) => passed.push((idx, module)),
+            Err(err) => {
+                errors.push((idx, err));
+                if !collect_all_errors {
+                    break;
+                }
+            }
+        }
+    }
+    passed
+}
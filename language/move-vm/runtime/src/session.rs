@@ -5,8 +5,8 @@
 use crate::loader::{Function, Module};
 use crate::module_traversal::TraversalContext;
 use crate::{
-    data_cache::TransactionDataCache, native_extensions::NativeContextExtensions,
-    runtime::VMRuntime,
+    config::PublishModuleBundleOption, data_cache::TransactionDataCache,
+    native_extensions::NativeContextExtensions, runtime::VMRuntime,
 };
 use move_binary_format::{
     compatibility::Compatibility,
@@ -16,9 +16,9 @@ use move_binary_format::{
 };
 use move_core_types::{
     account_address::AccountAddress,
-    effects::{ChangeSet, Event},
+    effects::{ChangeSet, ChangeSetDivergence, Event, StorageDelta},
     identifier::IdentStr,
-    language_storage::{ModuleId, TypeTag},
+    language_storage::{ModuleId, ResourceKey, TypeTag},
     resolver::MoveResolver,
     value::MoveTypeLayout,
 };
@@ -28,7 +28,7 @@ use move_vm_types::{
     gas::GasMeter,
     loaded_data::runtime_types::{CachedStructIndex, StructType, Type},
 };
-use std::{borrow::Borrow, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeSet, sync::Arc};
 
 pub struct Session<'r, 'l, S> {
     pub(crate) runtime: &'l VMRuntime,
@@ -169,6 +169,48 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
         )
     }
 
+    /// Check that a transaction script could be called with the given arguments, without executing
+    /// it.
+    ///
+    /// The script is loaded and verified like in `execute_script`. The leading signer parameters
+    /// of the script must match `senders`, and `args` must deserialize to the types of the
+    /// remaining parameters. Returns a `NUMBER_OF_SIGNER_ARGUMENTS_MISMATCH`,
+    /// `NUMBER_OF_ARGUMENTS_MISMATCH` or `FAILED_TO_DESERIALIZE_ARGUMENT` error otherwise.
+    pub fn verify_script_args(
+        &mut self,
+        script: impl Borrow<[u8]>,
+        ty_args: Vec<TypeTag>,
+        args: Vec<impl Borrow<[u8]>>,
+        senders: Vec<AccountAddress>,
+    ) -> VMResult<()> {
+        self.runtime
+            .verify_script_args(script, ty_args, args, senders, &mut self.data_cache)
+    }
+
+    /// Check that the entry function `function_name` of `module` could be called with the given
+    /// arguments, without executing it.
+    ///
+    /// This is the counterpart of `verify_script_args` for entry functions. The function is
+    /// loaded with `ty_args`, and must be an entry function with a valid script signature. The
+    /// senders and arguments are checked like in `verify_script_args`.
+    pub fn verify_script_function_args(
+        &mut self,
+        module: &ModuleId,
+        function_name: &IdentStr,
+        ty_args: Vec<TypeTag>,
+        args: Vec<impl Borrow<[u8]>>,
+        senders: Vec<AccountAddress>,
+    ) -> VMResult<()> {
+        self.runtime.verify_script_function_args(
+            module,
+            function_name,
+            ty_args,
+            args,
+            senders,
+            &mut self.data_cache,
+        )
+    }
+
     /// Publish the given module.
     ///
     /// The Move VM MUST return a user error, i.e., an error that's not an invariant violation, if
@@ -215,9 +257,11 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
         self.runtime.publish_module_bundle(
             modules,
             sender,
+            &BTreeSet::new(),
             &mut self.data_cache,
             gas_meter,
             Compatibility::full_check(),
+            &PublishModuleBundleOption::default(),
         )
     }
 
@@ -232,9 +276,51 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
         self.runtime.publish_module_bundle(
             modules,
             sender,
+            &BTreeSet::new(),
             &mut self.data_cache,
             gas_meter,
             compat_config,
+            &PublishModuleBundleOption::default(),
+        )
+    }
+
+    /// Same like `publish_module_bundle` but also allows publishing modules under any address in
+    /// `allow_addresses`, not just under the sender's address.
+    pub fn publish_module_bundle_with_allowed_addresses(
+        &mut self,
+        modules: Vec<Vec<u8>>,
+        sender: AccountAddress,
+        allow_addresses: &BTreeSet<AccountAddress>,
+        gas_meter: &mut impl GasMeter,
+    ) -> VMResult<()> {
+        self.runtime.publish_module_bundle(
+            modules,
+            sender,
+            allow_addresses,
+            &mut self.data_cache,
+            gas_meter,
+            Compatibility::full_check(),
+            &PublishModuleBundleOption::default(),
+        )
+    }
+
+    /// Same like `publish_module_bundle` but with the given publishing options, e.g. a tighter
+    /// verifier metering limit for untrusted publishers.
+    pub fn publish_module_bundle_with_option(
+        &mut self,
+        modules: Vec<Vec<u8>>,
+        sender: AccountAddress,
+        gas_meter: &mut impl GasMeter,
+        option: &PublishModuleBundleOption,
+    ) -> VMResult<()> {
+        self.runtime.publish_module_bundle(
+            modules,
+            sender,
+            &BTreeSet::new(),
+            &mut self.data_cache,
+            gas_meter,
+            Compatibility::full_check(),
+            option,
         )
     }
 
@@ -247,12 +333,38 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
         self.runtime.publish_module_bundle(
             modules,
             sender,
+            &BTreeSet::new(),
             &mut self.data_cache,
             gas_meter,
             Compatibility::no_check(),
+            &PublishModuleBundleOption::default(),
         )
     }
 
+    /// Performs the checks of `publish_module_bundle_with_option` without publishing the bundle,
+    /// and returns the failures together with the index of the offending module in the bundle.
+    ///
+    /// If `option.collect_all_errors` is set, every module is checked, so that all problems of a
+    /// bundle are reported in one pass. Otherwise at most the first failure is returned.
+    pub fn verify_module_bundle(
+        &mut self,
+        modules: &[Vec<u8>],
+        sender: AccountAddress,
+        option: &PublishModuleBundleOption,
+    ) -> Vec<(usize, VMError)> {
+        self.runtime
+            .verify_module_bundle(
+                modules,
+                sender,
+                &BTreeSet::new(),
+                &mut self.data_cache,
+                Compatibility::full_check(),
+                option,
+            )
+            .err()
+            .unwrap_or_default()
+    }
+
     /// Verify the compiled module for publishing
     pub fn verify_module_bundle_for_publication(
         &mut self,
@@ -263,10 +375,27 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
             .verify_module_bundle_for_publication(compiled_modules, &mut self.data_cache)
     }
 
+    /// Returns the ids of the modules the given bundle depends on, excluding the modules in the
+    /// bundle itself.
+    ///
+    /// This can be used to confirm that all dependencies are present before publishing the bundle.
+    pub fn bundle_dependencies(&self, modules: &[Vec<u8>]) -> VMResult<BTreeSet<ModuleId>> {
+        self.runtime.bundle_dependencies(modules)
+    }
+
     pub fn num_mutated_accounts(&self, sender: &AccountAddress) -> u64 {
         self.data_cache.num_mutated_accounts(sender)
     }
 
+    /// Returns the change in storage usage the effects of the session would cause so far: the
+    /// bytes written and overwritten or removed, and the number of slots created and deleted,
+    /// relative to the state of the storage of the session. The session can continue afterwards.
+    pub fn storage_delta(&self) -> VMResult<StorageDelta> {
+        self.data_cache
+            .storage_delta()
+            .map_err(|e| e.finish(Location::Undefined))
+    }
+
     /// Finish up the session and produce the side effects.
     ///
     /// This function should always succeed with no user errors returned, barring invariant violations.
@@ -278,6 +407,14 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
             .map_err(|e| e.finish(Location::Undefined))
     }
 
+    /// Finish up the session and compare its side effects against `expected`, e.g. the recorded
+    /// change set of a transaction which is re-executed. Returns the first divergence between
+    /// both, or `None` if they match.
+    pub fn apply_and_verify(self, expected: &ChangeSet) -> VMResult<Option<ChangeSetDivergence>> {
+        let (change_set, _events) = self.finish()?;
+        Ok(change_set.first_divergence(expected))
+    }
+
     /// Same like `finish`, but also extracts the native context extensions from the session.
     pub fn finish_with_extensions(
         self,
@@ -396,6 +533,19 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
         self.runtime.loader.flush_if_invalidated()
     }
 
+    /// Returns the ids of all the modules currently held in the loader cache.
+    pub fn loaded_modules(&self) -> Vec<ModuleId> {
+        self.runtime.loader.loaded_modules()
+    }
+
+    /// Hints the storage that the resources at `keys` are about to be read, so that it can
+    /// start loading them in the background. Adapters which know the access set of a
+    /// transaction, e.g. because the transaction declares it, call this before executing it.
+    /// See `ResourceResolver::prefetch`.
+    pub fn prefetch_resources(&self, keys: &[ResourceKey]) {
+        self.data_cache.prefetch(keys)
+    }
+
     /// Check if the module exists in data_cache
     pub fn exists_module(&self, id: &ModuleId) -> VMResult<bool> {
         self.data_cache.exists_module(id)
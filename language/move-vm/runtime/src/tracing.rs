@@ -11,6 +11,7 @@ use ::{
     move_vm_types::values::Locals,
     once_cell::sync::Lazy,
     std::{
+        cell::RefCell,
         env,
         fs::{File, OpenOptions},
         io::Write,
@@ -59,6 +60,29 @@ static LOGGING_FILE: Lazy<Mutex<File>> = Lazy::new(|| {
 #[cfg(any(debug_assertions, feature = "debugging"))]
 static DEBUG_CONTEXT: Lazy<Mutex<DebugContext>> = Lazy::new(|| Mutex::new(DebugContext::new()));
 
+#[cfg(any(debug_assertions, feature = "debugging"))]
+thread_local! {
+    // The instructions executed on this thread inside of `capture_trace`.
+    static CAPTURED_TRACE: RefCell<Option<Vec<String>>> = RefCell::new(None);
+}
+
+/// Runs `f` and returns the instructions it executed on the current thread, one
+/// `function,pc,instruction` line per instruction. Instructions are only traced in debug builds
+/// or with the `debugging` feature; otherwise the trace is `None`.
+pub fn capture_trace<R>(f: impl FnOnce() -> R) -> (R, Option<Vec<String>>) {
+    #[cfg(any(debug_assertions, feature = "debugging"))]
+    {
+        let outer = CAPTURED_TRACE.with(|trace| trace.replace(Some(vec![])));
+        let result = f();
+        let captured = CAPTURED_TRACE.with(|trace| trace.replace(outer));
+        (result, captured)
+    }
+    #[cfg(not(any(debug_assertions, feature = "debugging")))]
+    {
+        (f(), None)
+    }
+}
+
 // Only include in debug builds
 #[cfg(any(debug_assertions, feature = "debugging"))]
 pub(crate) fn trace(
@@ -82,6 +106,16 @@ pub(crate) fn trace(
         )
         .unwrap();
     }
+    CAPTURED_TRACE.with(|trace| {
+        if let Some(trace) = trace.borrow_mut().as_mut() {
+            trace.push(format!(
+                "{},{},{:?}",
+                function_desc.pretty_string(),
+                pc,
+                instr
+            ));
+        }
+    });
     if *DEBUGGING_ENABLED {
         DEBUG_CONTEXT
             .lock()
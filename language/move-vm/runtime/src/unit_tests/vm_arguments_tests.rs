@@ -837,3 +837,105 @@ fn call_missing_item() {
     );
     assert_eq!(error.status_type(), StatusType::Verification);
 }
+
+#[test]
+fn verify_script_args() {
+    let script = make_script(Signature(vec![
+        SignatureToken::Signer,
+        SignatureToken::U64,
+        SignatureToken::Address,
+    ]));
+    let move_vm = MoveVM::new(vec![]).unwrap();
+    let remote_view = RemoteStore::new();
+    let mut session = move_vm.new_session(&remote_view);
+    let mut verify = |args: Vec<MoveValue>, senders: Vec<AccountAddress>| {
+        session.verify_script_args(script.clone(), vec![], serialize_values(&args), senders)
+    };
+
+    // The script body aborts, so success means that it was not executed.
+    verify(
+        vec![MoveValue::U64(1), MoveValue::Address(AccountAddress::ONE)],
+        vec![AccountAddress::random()],
+    )
+    .unwrap();
+
+    let status = |res: VMResult<()>| res.unwrap_err().major_status();
+    assert_eq!(
+        status(verify(
+            vec![MoveValue::U64(1)],
+            vec![AccountAddress::random()]
+        )),
+        StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH
+    );
+    assert_eq!(
+        status(verify(
+            vec![
+                MoveValue::Bool(true),
+                MoveValue::Address(AccountAddress::ONE)
+            ],
+            vec![AccountAddress::random()],
+        )),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+    assert_eq!(
+        status(verify(
+            vec![MoveValue::U64(1), MoveValue::Address(AccountAddress::ONE)],
+            vec![AccountAddress::random(), AccountAddress::random()],
+        )),
+        StatusCode::NUMBER_OF_SIGNER_ARGUMENTS_MISMATCH
+    );
+    assert_eq!(
+        status(verify(
+            vec![MoveValue::U64(1), MoveValue::Address(AccountAddress::ONE)],
+            vec![],
+        )),
+        StatusCode::NUMBER_OF_SIGNER_ARGUMENTS_MISMATCH
+    );
+}
+
+#[test]
+fn verify_script_function_args() {
+    let verify = |is_entry: bool, ty_args: Vec<TypeTag>, args: Vec<MoveValue>| {
+        // `foo<T>(s: signer, x: u64)`
+        let (module, function_name) = make_module_with_function(
+            Visibility::Public,
+            is_entry,
+            Signature(vec![SignatureToken::Signer, SignatureToken::U64]),
+            Signature(vec![]),
+            vec![AbilitySet::EMPTY],
+        );
+        let move_vm = MoveVM::new(vec![]).unwrap();
+        let mut remote_view = RemoteStore::new();
+        let id = module.self_id();
+        remote_view.add_module(module);
+        let mut session = move_vm.new_session(&remote_view);
+        session.verify_script_function_args(
+            &id,
+            &function_name,
+            ty_args,
+            serialize_values(&args),
+            vec![AccountAddress::random()],
+        )
+    };
+
+    // The function body aborts, so success means that it was not executed.
+    verify(true, vec![TypeTag::U64], vec![MoveValue::U64(1)]).unwrap();
+
+    let status = |res: VMResult<()>| res.unwrap_err().major_status();
+    assert_eq!(
+        status(verify(true, vec![], vec![MoveValue::U64(1)])),
+        StatusCode::NUMBER_OF_TYPE_ARGUMENTS_MISMATCH
+    );
+    assert_eq!(
+        status(verify(
+            true,
+            vec![TypeTag::U64],
+            vec![MoveValue::Bool(true)]
+        )),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+    assert_eq!(
+        status(verify(false, vec![TypeTag::U64], vec![MoveValue::U64(1)])),
+        StatusCode::EXECUTE_ENTRY_FUNCTION_CALLED_ON_NON_ENTRY_FUNCTION
+    );
+}
@@ -7,7 +7,7 @@ use move_core_types::{
     account_address::AccountAddress,
     effects::{AccountChangeSet, ChangeSet, Op},
     identifier::Identifier,
-    language_storage::{ModuleId, StructTag},
+    language_storage::{ModuleId, ResourceKey, StructTag},
     resolver::{ModuleResolver, MoveResolver, ResourceResolver},
 };
 use std::{
@@ -100,6 +100,10 @@ impl<'a, 'b, S: ResourceResolver> ResourceResolver for DeltaStorage<'a, 'b, S> {
 
         self.base.get_resource(address, tag)
     }
+
+    fn prefetch(&self, keys: &[ResourceKey]) {
+        self.base.prefetch(keys)
+    }
 }
 
 #[cfg(feature = "table-extension")]
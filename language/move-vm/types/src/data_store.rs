@@ -45,6 +45,10 @@ pub trait DataStore {
     /// Check if this module exists.
     fn exists_module(&self, module_id: &ModuleId) -> VMResult<bool>;
 
+    /// Called by the loader once the module `module_id` has been loaded into the code cache, which
+    /// happens after all of its dependencies have been loaded.
+    fn on_module_loaded(&self, _module_id: &ModuleId) {}
+
     // ---
     // EventStore operations
     // ---
@@ -1940,6 +1940,10 @@ impl IntegerValue {
 pub const INDEX_OUT_OF_BOUNDS: u64 = NFE_VECTOR_ERROR_BASE + 1;
 pub const POP_EMPTY_VEC: u64 = NFE_VECTOR_ERROR_BASE + 2;
 pub const VEC_UNPACK_PARITY_MISMATCH: u64 = NFE_VECTOR_ERROR_BASE + 3;
+pub const VEC_LENGTH_MISMATCH: u64 = NFE_VECTOR_ERROR_BASE + 4;
+pub const VEC_EMPTY: u64 = NFE_VECTOR_ERROR_BASE + 5;
+pub const VEC_NOT_A_PERMUTATION: u64 = NFE_VECTOR_ERROR_BASE + 6;
+pub const VEC_OVERFLOW: u64 = NFE_VECTOR_ERROR_BASE + 7;
 
 fn check_elem_layout(ty: &Type, v: &Container) -> PartialVMResult<()> {
     match (ty, v) {
@@ -2045,6 +2049,16 @@ impl VectorRef {
         }
     }
 
+    /// Returns a mutable RefCell reference to the underlying vector of a `&mut vector<u8>` value.
+    pub fn as_bytes_mut(&self) -> std::cell::RefMut<'_, Vec<u8>> {
+        let c = self.0.container();
+        self.0.mark_dirty();
+        match c {
+            Container::VecU8(r) => r.borrow_mut(),
+            _ => panic!("can only be called on vector<u8>"),
+        }
+    }
+
     pub fn pop(&self, type_param: &Type) -> PartialVMResult<Value> {
         let c = self.0.container();
         check_elem_layout(type_param, c)?;
@@ -2163,6 +2177,37 @@ impl VectorRef {
         Ok(ret)
     }
 
+    /// Shortens the vector to `len` elements, dropping the rest. Does nothing if the vector is
+    /// not longer than `len`. Returns the number of dropped elements.
+    pub fn truncate(&self, len: usize, type_param: &Type) -> PartialVMResult<usize> {
+        let c = self.0.container();
+        check_elem_layout(type_param, c)?;
+
+        let dropped = c.len().saturating_sub(len);
+        macro_rules! truncate {
+            ($v: ident) => {{
+                $v.borrow_mut().truncate(len);
+            }};
+        }
+        match c {
+            Container::VecU8(r) => truncate!(r),
+            Container::VecU64(r) => truncate!(r),
+            Container::VecU128(r) => truncate!(r),
+            Container::VecBool(r) => truncate!(r),
+            Container::VecAddress(r) => truncate!(r),
+            Container::VecU16(r) => truncate!(r),
+            Container::VecU32(r) => truncate!(r),
+            Container::VecU256(r) => truncate!(r),
+            Container::Vec(r) => truncate!(r),
+
+            Container::Locals(_) | Container::Struct(_) => unreachable!(),
+        }
+        if dropped > 0 {
+            self.0.mark_dirty();
+        }
+        Ok(dropped)
+    }
+
     pub fn reverse(&self, memory_cost: &mut u64, type_param: &Type) -> PartialVMResult<()> {
         let c = self.0.container();
         check_elem_layout(type_param, c)?;
@@ -2282,6 +2327,58 @@ impl VectorRef {
         *memory_cost = c.legacy_size();
         Ok(container)
     }
+
+    /// Returns a copy of the elements in `start..end` as a new vector. Unlike `spawn_from`, this
+    /// works for vectors of any element type. `memory_cost` is set to the size of the copy.
+    pub fn slice(
+        &self,
+        memory_cost: &mut AbstractMemorySize,
+        start: usize,
+        end: usize,
+        type_param: &Type,
+    ) -> PartialVMResult<Value> {
+        let c = self.0.container();
+        check_elem_layout(type_param, c)?;
+
+        macro_rules! slice {
+            ($v: ident, $variant: ident) => {{
+                match $v.borrow().get(start..end) {
+                    Some(elems) => Container::$variant(Rc::new(RefCell::new(elems.to_vec()))),
+                    None => {
+                        return Err(PartialVMError::new(StatusCode::VECTOR_OPERATION_ERROR)
+                            .with_sub_status(INDEX_OUT_OF_BOUNDS))
+                    }
+                }
+            }};
+        }
+        let container = match c {
+            Container::VecU8(r) => slice!(r, VecU8),
+            Container::VecU16(r) => slice!(r, VecU16),
+            Container::VecU32(r) => slice!(r, VecU32),
+            Container::VecU64(r) => slice!(r, VecU64),
+            Container::VecU128(r) => slice!(r, VecU128),
+            Container::VecU256(r) => slice!(r, VecU256),
+            Container::VecBool(r) => slice!(r, VecBool),
+            Container::VecAddress(r) => slice!(r, VecAddress),
+            Container::Vec(r) => match r.borrow().get(start..end) {
+                Some(elems) => Container::Vec(Rc::new(RefCell::new(
+                    elems
+                        .iter()
+                        .map(|v| v.copy_value())
+                        .collect::<PartialVMResult<_>>()?,
+                ))),
+                None => {
+                    return Err(PartialVMError::new(StatusCode::VECTOR_OPERATION_ERROR)
+                        .with_sub_status(INDEX_OUT_OF_BOUNDS))
+                }
+            },
+
+            Container::Locals(_) | Container::Struct(_) => unreachable!(),
+        };
+
+        *memory_cost = container.legacy_size();
+        Ok(Value(ValueImpl::Container(container)))
+    }
 }
 
 impl Vector {
@@ -2671,6 +2768,23 @@ impl GlobalValueImpl {
         }
     }
 
+    fn effect(&self) -> PartialVMResult<Option<Op<ValueImpl>>> {
+        let copy_fields = |fields: &Rc<RefCell<Vec<ValueImpl>>>| {
+            Ok(ValueImpl::Container(
+                Container::Struct(Rc::clone(fields)).copy_value()?,
+            ))
+        };
+        Ok(match self {
+            Self::None => None,
+            Self::Deleted => Some(Op::Delete),
+            Self::Fresh { fields } => Some(Op::New(copy_fields(fields)?)),
+            Self::Cached { fields, status } => match &*status.borrow() {
+                GlobalDataStatus::Dirty => Some(Op::Modify(copy_fields(fields)?)),
+                GlobalDataStatus::Clean => None,
+            },
+        })
+    }
+
     fn is_mutated(&self) -> bool {
         match self {
             Self::None => false,
@@ -2717,6 +2831,11 @@ impl GlobalValue {
         self.0.into_effect().map(|op| op.map(Value))
     }
 
+    /// Returns a copy of the effect `into_effect` would return, leaving the global value as is.
+    pub fn effect(&self) -> PartialVMResult<Option<Op<Value>>> {
+        Ok(self.0.effect()?.map(|op| op.map(Value)))
+    }
+
     pub fn is_mutated(&self) -> bool {
         self.0.is_mutated()
     }
@@ -3038,6 +3157,15 @@ impl Value {
         })
         .ok()
     }
+
+    /// Returns the length of `simple_serialize(layout)`, without allocating the serialization.
+    pub fn simple_serialized_size(&self, layout: &MoveTypeLayout) -> Option<usize> {
+        bcs::serialized_size(&AnnotatedValue {
+            layout,
+            val: &self.0,
+        })
+        .ok()
+    }
 }
 
 impl Struct {
@@ -5,8 +5,9 @@
 #![forbid(unsafe_code)]
 
 use crate::tasks::{
-    taskify, InitCommand, PrintBytecodeCommand, PrintBytecodeInputChoice, PublishCommand,
-    RunCommand, SyntaxChoice, TaskCommand, TaskInput, ViewCommand,
+    taskify, AssertExistsCommand, InitCommand, PrintBytecodeCommand, PrintBytecodeInputChoice,
+    PublishCommand, RunCommand, SetTimeCommand, SetVersionCommand, SkipIfVersion, SyntaxChoice,
+    TaskCommand, TaskInput, ViewCommand,
 };
 use anyhow::{anyhow, Result};
 use clap::Parser;
@@ -14,7 +15,7 @@ use move_binary_format::{
     binary_views::BinaryIndexedView,
     file_format::{CompiledModule, CompiledScript},
 };
-use move_bytecode_source_map::mapping::SourceMapping;
+use move_bytecode_source_map::{mapping::SourceMapping, source_map::SourceMap};
 use move_command_line_common::{
     address::ParsedAddress,
     env::read_bool_env_var,
@@ -39,12 +40,14 @@ use move_ir_types::location::Spanned;
 use move_symbol_pool::Symbol;
 use move_vm_runtime::session::SerializedReturnValues;
 use rayon::iter::Either;
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::{
     collections::{BTreeMap, BTreeSet, VecDeque},
     fmt::{Debug, Write as FmtWrite},
     io::Write,
-    path::Path,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 use tempfile::NamedTempFile;
 
@@ -60,6 +63,13 @@ pub struct CompiledState<'a> {
     pub named_address_mapping: BTreeMap<String, NumericalAddress>,
     default_named_address_mapping: Option<NumericalAddress>,
     modules: BTreeMap<ModuleId, ProcessedModule>,
+    /// The named address mapping set by `init`, saved while a task overrides some of its entries.
+    saved_named_address_mapping: Option<BTreeMap<String, NumericalAddress>>,
+    /// Interface files for all published modules, used as dependencies instead of their sources
+    /// while named addresses are overridden.
+    override_interface_files: Vec<(String, NamedTempFile)>,
+    /// Fail `publish` and `run` tasks whose sources compile with warnings.
+    pub deny_warnings: bool,
 }
 
 impl<'a> CompiledState<'a> {
@@ -169,11 +179,48 @@ pub trait MoveTestAdapter<'a>: Sized {
         type_args: Vec<TypeTag>,
     ) -> Result<(String, Value)>;
 
+    /// Returns whether the resource is published under `address`. Adapters which cannot check
+    /// this reject the `assert-exists` command.
+    fn resource_exists(
+        &mut self,
+        _address: AccountAddress,
+        _module: &ModuleId,
+        _resource: &IdentStr,
+        _type_args: Vec<TypeTag>,
+    ) -> Result<bool> {
+        Err(anyhow!("assert-exists is not supported by this adapter"))
+    }
+
+    /// Sets the block timestamp seen by subsequent tasks. Adapters without a notion of time
+    /// reject the `set-time` command.
+    fn set_time(&mut self, _micros: u64) -> Result<Option<String>> {
+        Err(anyhow!("set-time is not supported by this adapter"))
+    }
+
+    /// Called by the `set-version` command, which switches the active version for subsequent
+    /// tasks. Adapters whose execution depends on the version, e.g. through a versioned gas
+    /// schedule, switch to it here. Others only need the runner to track the version.
+    fn set_version(&mut self, _version: u64) -> Result<Option<String>> {
+        Ok(None)
+    }
+
     fn handle_subcommand(
         &mut self,
         subcommand: TaskInput<Self::Subcommand>,
     ) -> Result<(Option<String>, Option<Value>)>;
 
+    /// Returns the gas used by the commands handled since the last call, for adapters which
+    /// meter execution. This is reported in the JSON output.
+    fn take_gas_used(&mut self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the number of events emitted by the last successful execution, for adapters which
+    /// capture events. The `--no-events` flag of `run` is rejected by other adapters.
+    fn take_events_emitted(&mut self) -> Option<usize> {
+        None
+    }
+
     fn handle_command(
         &mut self,
         task: TaskInput<
@@ -186,6 +233,20 @@ pub trait MoveTestAdapter<'a>: Sized {
             >,
         >,
     ) -> Result<(Option<String>, Option<Value>)> {
+        let mut task = task;
+        let named_address_overrides = match &mut task.command {
+            TaskCommand::Publish(command, _) => std::mem::take(&mut command.named_addresses),
+            TaskCommand::Run(command, _) => std::mem::take(&mut command.named_addresses),
+            _ => vec![],
+        };
+        if !named_address_overrides.is_empty() {
+            self.compiled_state()
+                .override_named_addresses(named_address_overrides);
+            let result = self.handle_command(task);
+            self.compiled_state().clear_named_address_overrides();
+            return result;
+        }
+
         let TaskInput {
             command,
             name,
@@ -194,12 +255,17 @@ pub trait MoveTestAdapter<'a>: Sized {
             command_lines_stop,
             stop_line,
             data,
+            expected_output,
+            skip_if_version,
         } = task;
         match command {
             TaskCommand::Init { .. } => {
                 panic!("The 'init' command is optional. But if used, it must be the first command")
             }
-            TaskCommand::PrintBytecode(PrintBytecodeCommand { input }) => {
+            TaskCommand::PrintBytecode(PrintBytecodeCommand {
+                input,
+                with_source_map,
+            }) => {
                 let state = self.compiled_state();
                 let data = match data {
                     Some(f) => f,
@@ -209,26 +275,42 @@ pub trait MoveTestAdapter<'a>: Sized {
                     ),
                 };
                 let data_path = data.path().to_str().unwrap();
-                let compiled = match input {
+                let (compiled, source_map) = match input {
                     PrintBytecodeInputChoice::Script => {
-                        Either::Left(compile_ir_script(state.dep_modules(), data_path)?)
+                        let (script, source_map) =
+                            compile_ir_script_with_source_map(state.dep_modules(), data_path)?;
+                        (Either::Left(script), source_map)
                     }
                     PrintBytecodeInputChoice::Module => {
-                        Either::Right(compile_ir_module(state.dep_modules(), data_path)?)
+                        let (module, source_map) =
+                            compile_ir_module_with_source_map(state.dep_modules(), data_path)?;
+                        (Either::Right(module), source_map)
                     }
                 };
-                let source_mapping = SourceMapping::new_from_view(
-                    match &compiled {
-                        Either::Left(script) => BinaryIndexedView::Script(script),
-                        Either::Right(module) => BinaryIndexedView::Module(module),
-                    },
-                    Spanned::unsafe_no_loc(()).loc,
-                )
-                .expect("Unable to build dummy source mapping");
-                let disassembler = Disassembler::new(source_mapping, DisassemblerOptions::new());
+                let view = match &compiled {
+                    Either::Left(script) => BinaryIndexedView::Script(script),
+                    Either::Right(module) => BinaryIndexedView::Module(module),
+                };
+                let mut options = DisassemblerOptions::new();
+                let source_mapping = if with_source_map {
+                    let mut source_mapping = SourceMapping::new(source_map, view);
+                    let code = std::fs::read_to_string(data_path).unwrap();
+                    source_mapping.with_source_code((data_path.to_owned(), code));
+                    options.print_source_lines = true;
+                    source_mapping
+                } else {
+                    SourceMapping::new_from_view(view, Spanned::unsafe_no_loc(()).loc)
+                        .expect("Unable to build dummy source mapping")
+                };
+                let disassembler = Disassembler::new(source_mapping, options);
                 Ok((Some(disassembler.disassemble()?), None))
             }
-            TaskCommand::Publish(PublishCommand { gas_budget, syntax }, extra_args) => {
+            TaskCommand::Publish(
+                PublishCommand {
+                    gas_budget, syntax, ..
+                },
+                extra_args,
+            ) => {
                 let syntax = syntax.unwrap_or_else(|| self.default_syntax());
                 let data = match data {
                     Some(f) => f,
@@ -247,6 +329,7 @@ pub trait MoveTestAdapter<'a>: Sized {
                             &state.source_files().cloned().collect::<Vec<_>>(),
                             data_path.to_owned(),
                         )?;
+                        check_warnings(state.deny_warnings, &warnings_opt)?;
                         let (named_addr_opt, module) = match unit {
                             AnnotatedCompiledUnit::Module(annot_module) => {
                                 let (named_addr_opt, _id) = annot_module.module_id();
@@ -275,6 +358,12 @@ pub trait MoveTestAdapter<'a>: Sized {
                     extra_args,
                 )?;
                 match syntax {
+                    // The source refers to overridden named addresses, so it cannot be used
+                    // to compile later tasks against this module.
+                    SyntaxChoice::Source if self.compiled_state().has_named_address_overrides() => {
+                        self.compiled_state()
+                            .add_and_generate_interface_file(module);
+                    }
                     SyntaxChoice::Source => self.compiled_state().add_with_source_file(
                         named_addr_opt,
                         module,
@@ -294,7 +383,10 @@ pub trait MoveTestAdapter<'a>: Sized {
                     type_args,
                     gas_budget,
                     syntax,
+                    trace,
+                    no_events,
                     name: None,
+                    ..
                 },
                 extra_args,
             ) => {
@@ -316,6 +408,7 @@ pub trait MoveTestAdapter<'a>: Sized {
                             &state.source_files().cloned().collect::<Vec<_>>(),
                             data_path.to_owned(),
                         )?;
+                        check_warnings(state.deny_warnings, &warning_opt)?;
                         match unit {
                         AnnotatedCompiledUnit::Script(annot_script) => (annot_script.named_script.script, warning_opt),
                         AnnotatedCompiledUnit::Module(_) => panic!(
@@ -328,11 +421,19 @@ pub trait MoveTestAdapter<'a>: Sized {
                 };
                 let args = self.compiled_state().resolve_args(args)?;
                 let type_args = self.compiled_state().resolve_type_args(type_args)?;
-                let (output, return_values, cmd_var_ctx) =
-                    self.execute_script(script, type_args, signers, args, gas_budget, extra_args)?;
+                let ((output, return_values, cmd_var_ctx), trace_output) =
+                    run_with_trace(trace, || {
+                        self.execute_script(
+                            script, type_args, signers, args, gas_budget, extra_args,
+                        )
+                    })?;
+                check_no_events(self, no_events)?;
                 let rendered_return_value = display_return_values(return_values);
                 Ok((
-                    merge_output(warning_opt, merge_output(output, rendered_return_value)),
+                    merge_output(
+                        warning_opt,
+                        merge_output(output, append_trace(rendered_return_value, trace_output)),
+                    ),
                     cmd_var_ctx,
                 ))
             }
@@ -343,7 +444,10 @@ pub trait MoveTestAdapter<'a>: Sized {
                     type_args,
                     gas_budget,
                     syntax,
+                    trace,
+                    no_events,
                     name: Some((raw_addr, module_name, name)),
+                    ..
                 },
                 extra_args,
             ) => {
@@ -355,17 +459,28 @@ pub trait MoveTestAdapter<'a>: Sized {
                 let module_id = ModuleId::new(addr, module_name);
                 let type_args = self.compiled_state().resolve_type_args(type_args)?;
                 let args = self.compiled_state().resolve_args(args)?;
-                let (output, return_values, cmd_var_ctx) = self.call_function(
-                    &module_id,
-                    name.as_ident_str(),
-                    type_args,
-                    signers,
-                    args,
-                    gas_budget,
-                    extra_args,
-                )?;
+                let ((output, return_values, cmd_var_ctx), trace_output) =
+                    run_with_trace(trace, || {
+                        self.call_function(
+                            &module_id,
+                            name.as_ident_str(),
+                            type_args,
+                            signers,
+                            args,
+                            gas_budget,
+                            extra_args,
+                        )
+                    })?;
+                check_no_events(self, no_events)?;
                 let rendered_return_value = display_return_values(return_values);
-                Ok((merge_output(output, rendered_return_value), cmd_var_ctx))
+                Ok((
+                    merge_output(output, append_trace(rendered_return_value, trace_output)),
+                    cmd_var_ctx,
+                ))
+            }
+            TaskCommand::SetTime(SetTimeCommand { micros }) => Ok((self.set_time(micros)?, None)),
+            TaskCommand::SetVersion(SetVersionCommand { version }) => {
+                Ok((self.set_version(version)?, None))
             }
             TaskCommand::View(ViewCommand { address, resource }) => {
                 let state: &CompiledState = self.compiled_state();
@@ -383,6 +498,33 @@ pub trait MoveTestAdapter<'a>: Sized {
                     self.view_data(address, &module_id, name.as_ident_str(), type_arguments)?;
                 Ok((Some(output), Some(cmd_var_ctx)))
             }
+            TaskCommand::AssertExists(AssertExistsCommand {
+                address,
+                resource,
+                absent,
+            }) => {
+                let state: &CompiledState = self.compiled_state();
+                let tag = resource
+                    .into_struct_tag(&|s| Some(state.resolve_named_address(s)))
+                    .unwrap();
+                let module_id = ModuleId::new(tag.address, tag.module.clone());
+                let address = self.compiled_state().resolve_address(&address);
+                let exists = self.resource_exists(
+                    address,
+                    &module_id,
+                    tag.name.as_ident_str(),
+                    tag.type_params.clone(),
+                )?;
+                if exists == absent {
+                    return Err(anyhow!(
+                        "Expected resource {} to be {} at {}",
+                        tag,
+                        if absent { "absent" } else { "present" },
+                        address
+                    ));
+                }
+                Ok((None, None))
+            }
             TaskCommand::Subcommand(c) => self.handle_subcommand(TaskInput {
                 command: c,
                 name,
@@ -391,11 +533,55 @@ pub trait MoveTestAdapter<'a>: Sized {
                 command_lines_stop,
                 stop_line,
                 data,
+                expected_output,
+                skip_if_version,
             }),
         }
     }
 }
 
+/// Fails if `no_events` is set and the last execution of `adapter` emitted events.
+fn check_no_events<'a, Adapter: MoveTestAdapter<'a>>(
+    adapter: &mut Adapter,
+    no_events: bool,
+) -> Result<()> {
+    if !no_events {
+        return Ok(());
+    }
+    match adapter.take_events_emitted() {
+        None => Err(anyhow!("--no-events is not supported by this adapter")),
+        Some(0) => Ok(()),
+        Some(num_events) => Err(anyhow!(
+            "Expected no events to be emitted, found {}",
+            num_events
+        )),
+    }
+}
+
+/// Runs `f`, also returning the instructions it executed if `trace` is set. On failure, the
+/// trace is added to the error, as it usually shows why the execution failed.
+fn run_with_trace<R>(trace: bool, f: impl FnOnce() -> Result<R>) -> Result<(R, Option<String>)> {
+    if !trace {
+        return Ok((f()?, None));
+    }
+    let (result, instructions) = move_vm_runtime::tracing::capture_trace(f);
+    let instructions = instructions.ok_or_else(|| {
+        anyhow!("--trace requires a debug build or the `debugging` feature of the VM")
+    })?;
+    let trace_output = format!("trace:\n{}", instructions.join("\n"));
+    match result {
+        Ok(result) => Ok((result, Some(trace_output))),
+        Err(e) => Err(anyhow!("{}\n{}", e, trace_output)),
+    }
+}
+
+fn append_trace(output: Option<String>, trace_output: Option<String>) -> Option<String> {
+    match (output, trace_output) {
+        (Some(output), Some(trace_output)) => Some(format!("{}\n{}", output, trace_output)),
+        (output, trace_output) => output.or(trace_output),
+    }
+}
+
 fn display_return_values(return_values: SerializedReturnValues) -> Option<String> {
     let SerializedReturnValues {
         mutable_reference_outputs,
@@ -474,6 +660,9 @@ impl<'a> CompiledState<'a> {
             compiled_module_named_address_mapping: BTreeMap::new(),
             named_address_mapping,
             default_named_address_mapping,
+            saved_named_address_mapping: None,
+            override_interface_files: vec![],
+            deny_warnings: false,
         };
         if let Some(pcd) = pre_compiled_deps {
             for unit in &pcd.compiled {
@@ -494,9 +683,62 @@ impl<'a> CompiledState<'a> {
     }
 
     pub fn source_files(&self) -> impl Iterator<Item = &String> {
-        self.modules
+        let overriding = self.has_named_address_overrides();
+        let published = self
+            .modules
+            .iter()
+            .filter(move |_| !overriding)
+            .filter_map(|(_, pmod)| Some(&pmod.source_file.as_ref()?.0));
+        self.override_interface_files
             .iter()
-            .filter_map(|(_, pmod)| Some(&pmod.source_file.as_ref()?.0))
+            .map(|(path, _)| path)
+            .chain(published)
+    }
+
+    /// Shadows entries of the named address mapping with `overrides` until
+    /// `clear_named_address_overrides` is called. Task-level overrides take precedence over the
+    /// named addresses set by `init`.
+    ///
+    /// Sources of published modules may refer to the overridden names, so while the overrides are
+    /// active, dependencies are compiled from interface files which spell out the addresses of
+    /// these modules instead.
+    pub fn override_named_addresses(&mut self, overrides: Vec<(String, NumericalAddress)>) {
+        self.clear_named_address_overrides();
+        let overridden: BTreeSet<Symbol> = overrides
+            .iter()
+            .map(|(name, _)| Symbol::from(name.as_str()))
+            .collect();
+        let named_address_mapping = self
+            .compiled_module_named_address_mapping
+            .iter()
+            .filter(|(_, name)| !overridden.contains(name))
+            .map(|(id, name)| (id.clone(), *name))
+            .collect();
+        self.override_interface_files = self
+            .modules
+            .values()
+            .filter(|pmod| pmod.source_file.is_some())
+            .map(|pmod| write_interface_file(&named_address_mapping, &pmod.module))
+            .collect();
+
+        let mut named_address_mapping = self.named_address_mapping.clone();
+        named_address_mapping.extend(overrides);
+        self.saved_named_address_mapping = Some(std::mem::replace(
+            &mut self.named_address_mapping,
+            named_address_mapping,
+        ));
+    }
+
+    /// Restores the named address mapping set by `init`.
+    pub fn clear_named_address_overrides(&mut self) {
+        if let Some(named_address_mapping) = self.saved_named_address_mapping.take() {
+            self.named_address_mapping = named_address_mapping;
+        }
+        self.override_interface_files.clear();
+    }
+
+    pub fn has_named_address_overrides(&self) -> bool {
+        self.saved_named_address_mapping.is_some()
     }
 
     pub fn add_with_source_file(
@@ -522,19 +764,10 @@ impl<'a> CompiledState<'a> {
     pub fn add_and_generate_interface_file(&mut self, module: CompiledModule) {
         let id = module.self_id();
         self.check_not_precompiled(&id);
-        let interface_file = NamedTempFile::new().unwrap();
-        let path = interface_file.path().to_str().unwrap().to_owned();
-        let (_id, interface_text) = move_compiler::interface_generator::write_module_to_string(
+        let source_file = Some(write_interface_file(
             &self.compiled_module_named_address_mapping,
             &module,
-        )
-        .unwrap();
-        interface_file
-            .reopen()
-            .unwrap()
-            .write_all(interface_text.as_bytes())
-            .unwrap();
-        let source_file = Some((path, interface_file));
+        ));
         let processed = ProcessedModule {
             module,
             source_file,
@@ -571,6 +804,23 @@ impl<'a> CompiledState<'a> {
     }
 }
 
+fn write_interface_file(
+    named_address_mapping: &BTreeMap<ModuleId, Symbol>,
+    module: &CompiledModule,
+) -> (String, NamedTempFile) {
+    let interface_file = NamedTempFile::new().unwrap();
+    let path = interface_file.path().to_str().unwrap().to_owned();
+    let (_id, interface_text) =
+        move_compiler::interface_generator::write_module_to_string(named_address_mapping, module)
+            .unwrap();
+    interface_file
+        .reopen()
+        .unwrap()
+        .write_all(interface_text.as_bytes())
+        .unwrap();
+    (path, interface_file)
+}
+
 fn compile_source_unit(
     pre_compiled_deps: Option<&FullyCompiledProgram>,
     named_address_mapping: BTreeMap<String, NumericalAddress>,
@@ -624,29 +874,135 @@ fn compile_source_unit(
     }
 }
 
+// Fails a task whose source compiled with warnings if warnings are denied.
+fn check_warnings(deny_warnings: bool, warnings_opt: &Option<String>) -> Result<()> {
+    match warnings_opt {
+        Some(warnings) if deny_warnings => Err(anyhow!(
+            "Compilation produced warnings, which are denied by --deny-warnings\n{}",
+            warnings
+        )),
+        _ => Ok(()),
+    }
+}
+
 fn compile_ir_module<'a>(
     deps: impl Iterator<Item = &'a CompiledModule>,
     file_name: &str,
 ) -> Result<CompiledModule> {
+    let (module, _) = compile_ir_module_with_source_map(deps, file_name)?;
+    Ok(module)
+}
+
+fn compile_ir_module_with_source_map<'a>(
+    deps: impl Iterator<Item = &'a CompiledModule>,
+    file_name: &str,
+) -> Result<(CompiledModule, SourceMap)> {
     use move_ir_compiler::Compiler as IRCompiler;
     let code = std::fs::read_to_string(file_name).unwrap();
-    IRCompiler::new(deps.collect()).into_compiled_module(&code)
+    IRCompiler::new(deps.collect()).into_compiled_module_and_source_map(&code)
 }
 
 fn compile_ir_script<'a>(
     deps: impl Iterator<Item = &'a CompiledModule>,
     file_name: &str,
 ) -> Result<CompiledScript> {
+    let (script, _) = compile_ir_script_with_source_map(deps, file_name)?;
+    Ok(script)
+}
+
+fn compile_ir_script_with_source_map<'a>(
+    deps: impl Iterator<Item = &'a CompiledModule>,
+    file_name: &str,
+) -> Result<(CompiledScript, SourceMap)> {
     use move_ir_compiler::Compiler as IRCompiler;
     let code = std::fs::read_to_string(file_name).unwrap();
-    let (script, _) = IRCompiler::new(deps.collect()).into_compiled_script_and_source_map(&code)?;
-    Ok(script)
+    IRCompiler::new(deps.collect()).into_compiled_script_and_source_map(&code)
+}
+
+/// Options for running a transactional test file.
+#[derive(Debug, Default, Clone, Parser)]
+pub struct RunTestOptions {
+    /// Do not abort the run when a task fails to parse or panics. The failure is recorded in the
+    /// output of the task, the remaining tasks are run, and all failures are reported at the end.
+    #[clap(long = "keep-going")]
+    pub keep_going: bool,
+    /// The active version, which decides whether tasks annotated with `skip-if-version` run.
+    /// Without it, all tasks run until a `set-version` task switches the version.
+    #[clap(long = "vm-version")]
+    pub vm_version: Option<u64>,
+    /// The format of the output which is compared against the expected output: `text` or `json`.
+    #[clap(long = "output-format", default_value = "text")]
+    pub output_format: OutputFormat,
+    /// Fail `publish` and `run` tasks whose sources compile with warnings. The warnings are
+    /// reported in the output of the task.
+    #[clap(long = "deny-warnings")]
+    pub deny_warnings: bool,
+}
+
+/// The format of the output of a test file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// A human-readable stream with the output of each task.
+    #[default]
+    Text,
+    /// A JSON array with one `{name, number, status, gas_used, output}` object per task, where
+    /// `status` is one of `success`, `error` (the command failed), `failed` (the task could not
+    /// be run) or `skipped`.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(anyhow!("Invalid output format. Expected 'text' or 'json'")),
+        }
+    }
+}
+
+fn task_result(
+    number: usize,
+    name: &str,
+    status: &str,
+    gas_used: Option<u64>,
+    output: Option<&str>,
+) -> Value {
+    json!({
+        "name": name,
+        "number": number,
+        "status": status,
+        "gas_used": gas_used,
+        "output": output,
+    })
 }
 
 pub fn run_test_impl<'a, Adapter>(
     path: &Path,
     fully_compiled_program_opt: Option<&'a FullyCompiledProgram>,
 ) -> Result<(), Box<dyn std::error::Error>>
+where
+    Adapter: MoveTestAdapter<'a>,
+    Adapter::ExtraInitArgs: Debug,
+    Adapter::ExtraPublishArgs: Debug,
+    Adapter::ExtraValueArgs: Debug,
+    Adapter::ExtraRunArgs: Debug,
+    Adapter::Subcommand: Debug,
+{
+    run_test_impl_with_options::<Adapter>(
+        path,
+        fully_compiled_program_opt,
+        &RunTestOptions::default(),
+    )
+}
+
+pub fn run_test_impl_with_options<'a, Adapter>(
+    path: &Path,
+    fully_compiled_program_opt: Option<&'a FullyCompiledProgram>,
+    options: &RunTestOptions,
+) -> Result<(), Box<dyn std::error::Error>>
 where
     Adapter: MoveTestAdapter<'a>,
     Adapter::ExtraInitArgs: Debug,
@@ -697,29 +1053,300 @@ where
         ),
         _ => (Some(first_task), None),
     };
+    let init_number = init_opt.as_ref().map(|init| init.number);
     let (mut adapter, result_opt) =
         Adapter::init(default_syntax, fully_compiled_program_opt, init_opt);
-    if let Some(result) = result_opt {
+    adapter.compiled_state().deny_warnings = options.deny_warnings;
+    if let Some(result) = &result_opt {
         writeln!(output, "\ninit:\n{}", result)?;
     }
+    let mut results = vec![];
+    if let Some(number) = init_number {
+        results.push(task_result(
+            number,
+            "init",
+            "success",
+            adapter.take_gas_used(),
+            result_opt.as_deref(),
+        ));
+    }
 
+    // The active version, which `set-version` tasks switch.
+    let mut vm_version = options.vm_version;
+    let mut inline_mismatches = vec![];
+    let mut failures = vec![];
+    let first_task = first_task.filter(|task| {
+        !skip_for_version(
+            &mut output,
+            &mut results,
+            vm_version,
+            task.skip_if_version,
+            (task.number, &task.name),
+            &task_label(task.number, &task.name, task.start_line, task.stop_line),
+        )
+    });
     if let Some(first_task) = first_task {
-        handle_known_task(&mut output, &mut adapter, &mut ctx, first_task);
+        if options.keep_going {
+            let label = task_label(
+                first_task.number,
+                &first_task.name,
+                first_task.start_line,
+                first_task.stop_line,
+            );
+            let (number, name) = (first_task.number, first_task.name.clone());
+            match catch_task_failure(&mut adapter, |adapter| {
+                Ok(handle_known_task(
+                    &mut output,
+                    &mut results,
+                    adapter,
+                    &mut ctx,
+                    &mut vm_version,
+                    first_task,
+                ))
+            }) {
+                Ok(mismatch) => inline_mismatches.extend(mismatch),
+                Err(msg) => {
+                    results.push(task_result(number, &name, "failed", None, Some(&msg)));
+                    record_task_failure(&mut output, &mut failures, label, msg)
+                }
+            }
+        } else {
+            inline_mismatches.extend(handle_known_task(
+                &mut output,
+                &mut results,
+                &mut adapter,
+                &mut ctx,
+                &mut vm_version,
+                first_task,
+            ));
+        }
     }
 
     for task in tasks {
-        let task = task.parse(&ctx)?;
-        handle_known_task(&mut output, &mut adapter, &mut ctx, task);
+        let label = task_label(task.number, &task.name, task.start_line, task.stop_line);
+        if skip_for_version(
+            &mut output,
+            &mut results,
+            vm_version,
+            task.skip_if_version,
+            (task.number, &task.name),
+            &label,
+        ) {
+            continue;
+        }
+        if options.keep_going {
+            let (number, name) = (task.number, task.name.clone());
+            match catch_task_failure(&mut adapter, |adapter| {
+                let task = task.parse(&ctx)?;
+                Ok(handle_known_task(
+                    &mut output,
+                    &mut results,
+                    adapter,
+                    &mut ctx,
+                    &mut vm_version,
+                    task,
+                ))
+            }) {
+                Ok(mismatch) => inline_mismatches.extend(mismatch),
+                Err(msg) => {
+                    results.push(task_result(number, &name, "failed", None, Some(&msg)));
+                    record_task_failure(&mut output, &mut failures, label, msg)
+                }
+            }
+        } else {
+            let task = task.parse(&ctx)?;
+            inline_mismatches.extend(handle_known_task(
+                &mut output,
+                &mut results,
+                &mut adapter,
+                &mut ctx,
+                &mut vm_version,
+                task,
+            ));
+        }
+    }
+    if !failures.is_empty() {
+        return Err(anyhow!(
+            "{} task{} failed:\n{}",
+            failures.len(),
+            if failures.len() > 1 { "s" } else { "" },
+            failures.join("\n")
+        )
+        .into());
+    }
+    if !inline_mismatches.is_empty() {
+        return Err(anyhow!(
+            "Inline expected output differs from actual output:\n{}",
+            inline_mismatches.join("\n")
+        )
+        .into());
+    }
+    if options.output_format == OutputFormat::Json {
+        output = serde_json::to_string_pretty(&results)? + "\n";
     }
     handle_expected_output(path, output)?;
     Ok(())
 }
 
+/// The outcome of running the tasks of a single test file.
+#[derive(Debug)]
+pub struct FileResult {
+    pub path: PathBuf,
+    /// Why the file failed, or `None` if it passed.
+    pub error: Option<String>,
+}
+
+impl FileResult {
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Runs every `.move` and `.mvir` file under `dir`, in path order, and returns the result of
+/// each file instead of stopping at the first failure. A file passes if all of its tasks match
+/// their expected output, as with `run_test_impl_with_options`.
+pub fn run_directory_impl<'a, Adapter>(
+    dir: &Path,
+    fully_compiled_program_opt: Option<&'a FullyCompiledProgram>,
+    options: &RunTestOptions,
+) -> Vec<FileResult>
+where
+    Adapter: MoveTestAdapter<'a>,
+    Adapter::ExtraInitArgs: Debug,
+    Adapter::ExtraPublishArgs: Debug,
+    Adapter::ExtraValueArgs: Debug,
+    Adapter::ExtraRunArgs: Debug,
+    Adapter::Subcommand: Debug,
+{
+    let mut results = vec![];
+    let mut paths = vec![];
+    collect_test_files(dir, &mut paths, &mut results);
+    paths.sort();
+    for path in paths {
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            run_test_impl_with_options::<Adapter>(&path, fully_compiled_program_opt, options)
+        }));
+        let error = match outcome {
+            Ok(Ok(())) => None,
+            Ok(Err(e)) => Some(e.to_string()),
+            Err(payload) => Some(panic_message(payload, "test panicked")),
+        };
+        results.push(FileResult { path, error });
+    }
+    results
+}
+
+/// Collects the test files under `dir`. Directories which cannot be read are reported as failed
+/// results.
+fn collect_test_files(dir: &Path, paths: &mut Vec<PathBuf>, results: &mut Vec<FileResult>) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            results.push(FileResult {
+                path: dir.to_owned(),
+                error: Some(e.to_string()),
+            });
+            return;
+        }
+    };
+    for entry in entries {
+        let path = match entry {
+            Ok(entry) => entry.path(),
+            Err(e) => {
+                results.push(FileResult {
+                    path: dir.to_owned(),
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+        if path.is_dir() {
+            collect_test_files(&path, paths, results);
+        } else if matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some(MOVE_EXTENSION | MOVE_IR_EXTENSION)
+        ) {
+            paths.push(path);
+        }
+    }
+}
+
+fn task_label(number: usize, name: &str, start_line: usize, stop_line: usize) -> String {
+    format!(
+        "task {} '{}'. lines {}-{}",
+        number, name, start_line, stop_line
+    )
+}
+
+/// Returns true if the task must be skipped under the active version, in which case the skip is
+/// recorded in the output.
+fn skip_for_version(
+    output: &mut String,
+    results: &mut Vec<Value>,
+    vm_version: Option<u64>,
+    skip_if_version: Option<SkipIfVersion>,
+    (number, name): (usize, &str),
+    label: &str,
+) -> bool {
+    match (skip_if_version, vm_version) {
+        (Some(condition), Some(version)) if condition.skips(version) => {
+            let reason = format!("{}, active version {}", condition, version);
+            write!(output, "\n{}:\nskipped ({})\n", label, reason)
+                .expect("write to string should not fail");
+            results.push(task_result(number, name, "skipped", None, Some(&reason)));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Runs `f`, which parses and handles a single task, turning both errors and panics into a
+/// failure message.
+///
+/// On failure, the named address overrides of the task are cleared, as a panic skips the cleanup
+/// in `handle_command`. Nothing else needs to be rolled back: a task only updates the template
+/// context and writes its output once it has been handled, and adapters only commit the effects
+/// of commands which succeed.
+fn catch_task_failure<'a, Adapter: MoveTestAdapter<'a>>(
+    adapter: &mut Adapter,
+    f: impl FnOnce(&mut Adapter) -> Result<Option<String>>,
+) -> Result<Option<String>, String> {
+    let result = match panic::catch_unwind(AssertUnwindSafe(|| f(&mut *adapter))) {
+        Ok(Ok(mismatch)) => return Ok(mismatch),
+        Ok(Err(e)) => e.to_string(),
+        Err(payload) => panic_message(payload, "task panicked"),
+    };
+    adapter.compiled_state().clear_named_address_overrides();
+    Err(result)
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>, default: &str) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| default.to_owned())
+}
+
+fn record_task_failure(
+    output: &mut String,
+    failures: &mut Vec<String>,
+    label: String,
+    msg: String,
+) {
+    write!(output, "\n{}:\nError: {}\n", label, msg).expect("write to string should not fail");
+    failures.push(format!("{}: {}", label, msg));
+}
+
+/// Runs the task and appends its result to `output`. If the task has an inline expected output
+/// that differs from its result, returns a message describing the difference.
 fn handle_known_task<'a, Adapter: MoveTestAdapter<'a>>(
     output: &mut String,
+    results: &mut Vec<Value>,
     adapter: &mut Adapter,
     ctx: &mut jpst::TemplateContext,
-    task: TaskInput<
+    vm_version: &mut Option<u64>,
+    mut task: TaskInput<
         TaskCommand<
             Adapter::ExtraInitArgs,
             Adapter::ExtraPublishArgs,
@@ -728,20 +1355,51 @@ fn handle_known_task<'a, Adapter: MoveTestAdapter<'a>>(
             Adapter::Subcommand,
         >,
     >,
-) {
+) -> Option<String> {
     let task_number = task.number;
     let task_name = task.name.to_owned();
     let start_line = task.start_line;
     let stop_line = task.stop_line;
-    let (result_string, cmd_var_ctx) = match adapter.handle_command(task) {
+    let expected_output = task.expected_output.take();
+    let new_version = match &task.command {
+        TaskCommand::SetVersion(SetVersionCommand { version }) => Some(*version),
+        _ => None,
+    };
+    let (result_string, cmd_var_ctx, status) = match adapter.handle_command(task) {
         Ok((result_string, cmd_var_ctx)) => {
+            if new_version.is_some() {
+                *vm_version = new_version;
+            }
             if let Some(s) = result_string.as_ref() {
                 assert!(!s.is_empty());
             }
-            (result_string, cmd_var_ctx)
+            (result_string, cmd_var_ctx, "success")
         }
-        Err(e) => (Some(format!("Error: {}", e)), None),
+        Err(e) => (Some(format!("Error: {}", e)), None, "error"),
     };
+    results.push(task_result(
+        task_number,
+        &task_name,
+        status,
+        adapter.take_gas_used(),
+        result_string.as_deref(),
+    ));
+
+    let mismatch = expected_output.and_then(|expected| {
+        let actual = result_string.as_deref().unwrap_or("");
+        if expected.trim_end() == actual.trim_end() {
+            None
+        } else {
+            Some(format!(
+                "task {} '{}'. lines {}-{}:\n{}",
+                task_number,
+                task_name,
+                start_line,
+                stop_line,
+                format_diff(expected.trim_end(), actual.trim_end())
+            ))
+        }
+    });
 
     if let Some(s) = result_string {
         write!(
@@ -754,6 +1412,7 @@ fn handle_known_task<'a, Adapter: MoveTestAdapter<'a>>(
     if let Some(cmd_var_ctx) = cmd_var_ctx {
         ctx.entry(task_name).append(cmd_var_ctx);
     }
+    mismatch
 }
 
 fn handle_expected_output(test_path: &Path, output: impl AsRef<str>) -> Result<()> {
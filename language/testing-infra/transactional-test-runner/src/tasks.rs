@@ -14,7 +14,12 @@ use move_command_line_common::{
 };
 use move_compiler::shared::NumericalAddress;
 use move_core_types::identifier::Identifier;
-use std::{convert::TryInto, fmt::Debug, path::Path, str::FromStr};
+use std::{
+    convert::TryInto,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 use tempfile::NamedTempFile;
 
 #[derive(Debug)]
@@ -74,6 +79,8 @@ pub struct LazyParseTaskInput<Command> {
     pub command_lines_stop: usize,
     pub stop_line: usize,
     pub data: Option<NamedTempFile>,
+    pub expected_output: Option<String>,
+    pub skip_if_version: Option<SkipIfVersion>,
 }
 
 impl<Command> LazyParseTaskInput<Command>
@@ -88,6 +95,8 @@ where
         let command_lines_stop = self.command_lines_stop;
         let stop_line = self.stop_line;
         let data = self.data;
+        let expected_output = self.expected_output;
+        let skip_if_version = self.skip_if_version;
         Ok(TaskInput {
             command,
             name,
@@ -96,6 +105,8 @@ where
             command_lines_stop,
             stop_line,
             data,
+            expected_output,
+            skip_if_version,
         })
     }
 }
@@ -109,10 +120,109 @@ pub struct TaskInput<Command> {
     pub command_lines_stop: usize,
     pub stop_line: usize,
     pub data: Option<NamedTempFile>,
+    /// Output expected from the task, given inline in the test file with `//*` lines.
+    pub expected_output: Option<String>,
+    /// Condition on the active version under which the task is skipped.
+    pub skip_if_version: Option<SkipIfVersion>,
+}
+
+/// How the active version is compared against the version of a `skip-if-version` annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionComparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+/// A `(skip-if-version <op> N)` annotation at the end of a task command, e.g.
+/// `//# run 0x1::M::f (skip-if-version < 3)`. The task is skipped when the active version
+/// satisfies the comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkipIfVersion {
+    pub comparison: VersionComparison,
+    pub version: u64,
+}
+
+impl SkipIfVersion {
+    /// Splits a trailing `skip-if-version` annotation off the command text, if there is one.
+    fn strip_from_command(command_text: &str) -> Result<(String, Option<Self>)> {
+        use regex::Regex;
+        let re_annotation =
+            Regex::new(r"^(.*?)\s*\(\s*skip-if-version\s*(<=|>=|==|!=|<|>)\s*(\d+)\s*\)\s*$")
+                .unwrap();
+        let captures = match re_annotation.captures(command_text) {
+            Some(captures) => captures,
+            None if command_text.contains("skip-if-version") => bail!(
+                "Invalid annotation in command {}. Expected '(skip-if-version <op> <version>)' \
+                at the end of the command, where <op> is one of <, <=, >, >=, ==, !=",
+                command_text
+            ),
+            None => return Ok((command_text.to_owned(), None)),
+        };
+        let comparison = match &captures[2] {
+            "<" => VersionComparison::Lt,
+            "<=" => VersionComparison::Le,
+            ">" => VersionComparison::Gt,
+            ">=" => VersionComparison::Ge,
+            "==" => VersionComparison::Eq,
+            "!=" => VersionComparison::Ne,
+            _ => unreachable!(),
+        };
+        let version = captures[3]
+            .parse()
+            .map_err(|e| anyhow!("Invalid version in command {}: {}", command_text, e))?;
+        Ok((
+            captures[1].to_owned(),
+            Some(Self {
+                comparison,
+                version,
+            }),
+        ))
+    }
+
+    /// Returns true if a task with this annotation must be skipped under `active_version`.
+    pub fn skips(&self, active_version: u64) -> bool {
+        match self.comparison {
+            VersionComparison::Lt => active_version < self.version,
+            VersionComparison::Le => active_version <= self.version,
+            VersionComparison::Gt => active_version > self.version,
+            VersionComparison::Ge => active_version >= self.version,
+            VersionComparison::Eq => active_version == self.version,
+            VersionComparison::Ne => active_version != self.version,
+        }
+    }
 }
 
+impl std::fmt::Display for SkipIfVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self.comparison {
+            VersionComparison::Lt => "<",
+            VersionComparison::Le => "<=",
+            VersionComparison::Gt => ">",
+            VersionComparison::Ge => ">=",
+            VersionComparison::Eq => "==",
+            VersionComparison::Ne => "!=",
+        };
+        write!(f, "skip-if-version {} {}", op, self.version)
+    }
+}
+
+/// The default maximum number of tasks a single test file may contain.
+pub const DEFAULT_MAX_TASKS: usize = 10_000;
+
 pub fn taskify<Command: Debug + Parser>(
     filename: &Path,
+) -> Result<Vec<LazyParseTaskInput<Command>>> {
+    taskify_with_max_tasks(filename, DEFAULT_MAX_TASKS)
+}
+
+/// Like `taskify`, but fails as soon as the file is found to contain more than `max_tasks` tasks.
+pub fn taskify_with_max_tasks<Command: Debug + Parser>(
+    filename: &Path,
+    max_tasks: usize,
 ) -> Result<Vec<LazyParseTaskInput<Command>>> {
     use regex::Regex;
     use std::{
@@ -128,27 +238,51 @@ pub fn taskify<Command: Debug + Parser>(
     // cutting leading/trailing whitespace
     // capturing the command text
     let re_command_text = Regex::new(r"^\s*//#\s*(.*)\s*$").unwrap();
+    // checks for lines that start with //* inline expected output of the preceding task
+    // capturing the output text
+    let re_expected_output = Regex::new(r"^\s*//\*\s?(.*)$").unwrap();
 
+    // lines are read lazily, so that a file with too many tasks is rejected without reading it all
     let file = File::open(filename).unwrap();
-    let lines: Vec<String> = io::BufReader::new(file)
+    let lines_iter = io::BufReader::new(file)
         .lines()
         .map(|ln| ln.expect("Could not parse line"))
-        .collect();
-
-    let lines_iter = lines.into_iter().enumerate().map(|(idx, l)| (idx + 1, l));
+        .enumerate()
+        .map(|(idx, l)| (idx + 1, l));
     let skipped_whitespace = lines_iter.skip_while(|(_line_number, line)| {
         re_whitespace.is_match(line) || re_comment.is_match(line)
     });
     let mut bucketed_lines = vec![];
     let mut cur_commands = vec![];
     let mut cur_text = vec![];
+    let mut cur_expected_output: Option<Vec<String>> = None;
     let mut in_command = true;
+    let mut num_tasks = 0;
+    let admit_task = |num_tasks: usize| -> Result<()> {
+        if num_tasks >= max_tasks {
+            bail!(
+                "Too many tasks in {}, the maximum is {}",
+                filename.display(),
+                max_tasks
+            )
+        }
+        Ok(())
+    };
     for (line_number, line) in skipped_whitespace {
+        if num_tasks == 0 {
+            // the first line that is not skipped starts the first task
+            admit_task(num_tasks)?;
+            num_tasks += 1;
+        }
         if let Some(captures) = re_command_text.captures(&line) {
             if !in_command {
-                bucketed_lines.push((cur_commands, cur_text));
+                // this command starts a new task after the bucketed ones and the current one
+                admit_task(num_tasks)?;
+                num_tasks += 1;
+                bucketed_lines.push((cur_commands, cur_text, cur_expected_output));
                 cur_commands = vec![];
                 cur_text = vec![];
+                cur_expected_output = None;
                 in_command = true;
             }
             let command_text = match captures.len() {
@@ -160,6 +294,11 @@ pub fn taskify<Command: Debug + Parser>(
                 continue;
             }
             cur_commands.push((line_number, command_text))
+        } else if let Some(captures) = re_expected_output.captures(&line) {
+            in_command = false;
+            cur_expected_output
+                .get_or_insert_with(Vec::new)
+                .push(captures.get(1).unwrap().as_str().to_string());
         } else if re_whitespace.is_match(&line) {
             in_command = false;
             continue;
@@ -168,14 +307,14 @@ pub fn taskify<Command: Debug + Parser>(
             cur_text.push((line_number, line))
         }
     }
-    bucketed_lines.push((cur_commands, cur_text));
+    bucketed_lines.push((cur_commands, cur_text, cur_expected_output));
 
     if bucketed_lines.is_empty() {
         return Ok(vec![]);
     }
 
     let mut tasks = vec![];
-    for (number, (commands, text)) in bucketed_lines.into_iter().enumerate() {
+    for (number, (commands, text, expected_output)) in bucketed_lines.into_iter().enumerate() {
         if commands.is_empty() {
             assert!(number == 0);
             bail!("No initial command")
@@ -188,6 +327,7 @@ pub fn taskify<Command: Debug + Parser>(
             assert!(!text.is_empty(), "{}: {}", line_number, text);
             command_text = format!("{} {}", command_text, text);
         }
+        let (command_text, skip_if_version) = SkipIfVersion::strip_from_command(&command_text)?;
         let command_split = command_text.split_ascii_whitespace().collect::<Vec<_>>();
         let name = command_split
             .get(1)
@@ -231,6 +371,8 @@ pub fn taskify<Command: Debug + Parser>(
             command_lines_stop,
             stop_line,
             data,
+            expected_output: expected_output.map(|lines| lines.join("\n")),
+            skip_if_version,
         })
     }
     Ok(tasks)
@@ -246,6 +388,8 @@ impl<T> TaskInput<T> {
             command_lines_stop,
             stop_line,
             data,
+            expected_output,
+            skip_if_version,
         } = self;
         TaskInput {
             command: f(command),
@@ -255,6 +399,8 @@ impl<T> TaskInput<T> {
             command_lines_stop,
             stop_line,
             data,
+            expected_output,
+            skip_if_version,
         }
     }
 }
@@ -279,6 +425,9 @@ pub struct PrintBytecodeCommand {
     /// The kind of input: either a script, or a module.
     #[clap(long = "input", ignore_case = true, default_value = "script")]
     pub input: PrintBytecodeInputChoice,
+    /// Print the source line ahead of the instructions compiled from it.
+    #[clap(long = "with-source-map")]
+    pub with_source_map: bool,
 }
 
 #[derive(Debug, Parser)]
@@ -291,6 +440,10 @@ pub struct InitCommand {
         multiple_occurrences(true)
     )]
     pub named_addresses: Vec<(String, NumericalAddress)>,
+    /// A BCS-serialized `ChangeSet` to start from, e.g. a dump of production state. It is
+    /// applied after the standard library is published.
+    #[clap(long = "state-snapshot")]
+    pub state_snapshot: Option<PathBuf>,
 }
 
 #[derive(Debug, Parser)]
@@ -299,6 +452,15 @@ pub struct PublishCommand {
     pub gas_budget: Option<u64>,
     #[clap(long = "syntax")]
     pub syntax: Option<SyntaxChoice>,
+    /// Named addresses for this task only. They take precedence over the ones set by `init`.
+    #[clap(
+        long = "addresses",
+        parse(try_from_str = move_compiler::shared::parse_named_address),
+        takes_value(true),
+        multiple_values(true),
+        multiple_occurrences(true)
+    )]
+    pub named_addresses: Vec<(String, NumericalAddress)>,
 }
 
 #[derive(Debug, Parser)]
@@ -331,6 +493,22 @@ pub struct RunCommand<ExtraValueArgs: ParsableValue> {
     pub gas_budget: Option<u64>,
     #[clap(long = "syntax")]
     pub syntax: Option<SyntaxChoice>,
+    /// Named addresses for this task only. They take precedence over the ones set by `init`.
+    #[clap(
+        long = "addresses",
+        parse(try_from_str = move_compiler::shared::parse_named_address),
+        takes_value(true),
+        multiple_values(true),
+        multiple_occurrences(true)
+    )]
+    pub named_addresses: Vec<(String, NumericalAddress)>,
+    /// Prints the executed instructions, as `function,pc,instruction` lines, after the output.
+    /// Only available in debug builds of the VM.
+    #[clap(long = "trace")]
+    pub trace: bool,
+    /// Fails the task if the execution emitted any events.
+    #[clap(long = "no-events")]
+    pub no_events: bool,
     #[clap(name = "NAME", parse(try_from_str = parse_qualified_module_access))]
     pub name: Option<(ParsedAddress, Identifier, Identifier)>,
 }
@@ -343,6 +521,34 @@ pub struct ViewCommand {
     pub resource: ParsedStructType,
 }
 
+/// Checks that a resource is published under an address, or with `--absent` that it is not,
+/// without printing its value.
+#[derive(Debug, Parser)]
+pub struct AssertExistsCommand {
+    #[clap(long = "address", parse(try_from_str = ParsedAddress::parse))]
+    pub address: ParsedAddress,
+    #[clap(long = "resource", parse(try_from_str = ParsedStructType::parse))]
+    pub resource: ParsedStructType,
+    #[clap(long = "absent")]
+    pub absent: bool,
+}
+
+/// Fast-forwards the block timestamp seen by subsequent tasks.
+#[derive(Debug, Parser)]
+pub struct SetTimeCommand {
+    /// The new block timestamp, in microseconds since the Unix epoch.
+    #[clap(long = "micros")]
+    pub micros: u64,
+}
+
+/// Switches the active version for subsequent tasks, e.g. to exercise the behavior of several
+/// versions in one file. It decides which `skip-if-version` tasks run, and is passed on to the
+/// adapter, which may change how it executes.
+#[derive(Debug, Parser)]
+pub struct SetVersionCommand {
+    pub version: u64,
+}
+
 #[derive(Debug)]
 pub enum TaskCommand<
     ExtraInitArgs: Parser,
@@ -355,7 +561,10 @@ pub enum TaskCommand<
     PrintBytecode(PrintBytecodeCommand),
     Publish(PublishCommand, ExtraPublishArgs),
     Run(RunCommand<ExtraValueArgs>, ExtraRunArgs),
+    SetTime(SetTimeCommand),
+    SetVersion(SetVersionCommand),
     View(ViewCommand),
+    AssertExists(AssertExistsCommand),
     Subcommand(SubCommands),
 }
 
@@ -385,9 +594,18 @@ impl<
                 FromArgMatches::from_arg_matches(matches)?,
                 FromArgMatches::from_arg_matches(matches)?,
             ),
+            Some(("set-time", matches)) => {
+                TaskCommand::SetTime(FromArgMatches::from_arg_matches(matches)?)
+            }
+            Some(("set-version", matches)) => {
+                TaskCommand::SetVersion(FromArgMatches::from_arg_matches(matches)?)
+            }
             Some(("view", matches)) => {
                 TaskCommand::View(FromArgMatches::from_arg_matches(matches)?)
             }
+            Some(("assert-exists", matches)) => {
+                TaskCommand::AssertExists(FromArgMatches::from_arg_matches(matches)?)
+            }
             _ => TaskCommand::Subcommand(SubCommands::from_arg_matches(matches)?),
         })
     }
@@ -416,7 +634,10 @@ impl<
             .subcommand(
                 RunCommand::<ExtraValueArgs>::augment_args(ExtraRunArgs::command()).name("run"),
             )
+            .subcommand(SetTimeCommand::command().name("set-time"))
+            .subcommand(SetVersionCommand::command().name("set-version"))
             .subcommand(ViewCommand::command().name("view"))
+            .subcommand(AssertExistsCommand::command().name("assert-exists"))
     }
 
     fn into_app_for_update<'help>() -> Command<'help> {
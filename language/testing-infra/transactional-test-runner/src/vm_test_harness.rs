@@ -2,10 +2,17 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::BTreeMap, path::Path};
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{PoisonError, RwLock},
+};
 
 use crate::{
-    framework::{run_test_impl, CompiledState, MoveTestAdapter},
+    framework::{
+        run_directory_impl, run_test_impl, run_test_impl_with_options, CompiledState, FileResult,
+        MoveTestAdapter, RunTestOptions,
+    },
     tasks::{EmptyCommand, InitCommand, SyntaxChoice, TaskInput},
 };
 use anyhow::{anyhow, Result};
@@ -24,17 +31,20 @@ use move_compiler::{
 };
 use move_core_types::{
     account_address::AccountAddress,
+    effects::ChangeSet,
     identifier::{IdentStr, Identifier},
     language_storage::{ModuleId, StructTag, TypeTag},
-    resolver::MoveResolver,
+    resolver::{MoveResolver, ResourceResolver},
     value::MoveValue,
 };
 use move_resource_viewer::MoveValueAnnotator;
-use move_stdlib::move_stdlib_named_addresses;
+use move_stdlib::{move_stdlib_named_addresses, natives::timestamp::NativeTimestampContext};
 use move_symbol_pool::Symbol;
 use move_vm_runtime::{
     config::VMConfig,
     move_vm::MoveVM,
+    native_extensions::NativeContextExtensions,
+    native_functions::NativeFunctionTable,
     session::{SerializedReturnValues, Session},
 };
 use move_vm_test_utils::{gas_schedule::GasStatus, InMemoryStorage};
@@ -43,10 +53,32 @@ use serde_json::Value;
 
 const STD_ADDR: AccountAddress = AccountAddress::ONE;
 
+// Natives which tests can add to the VM with `//# init --natives <name>`, by name.
+static NATIVE_REGISTRY: Lazy<RwLock<BTreeMap<String, NativeFunctionTable>>> =
+    Lazy::new(|| RwLock::new(BTreeMap::new()));
+
+/// Registers `natives` under `name`, so that a test can run with them in addition to the
+/// natives of the standard library by passing `--natives <name>` to `//# init`. Registering a
+/// name again replaces its natives.
+pub fn register_natives(name: impl Into<String>, natives: NativeFunctionTable) {
+    NATIVE_REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name.into(), natives);
+}
+
 struct SimpleVMTestAdapter<'a> {
     compiled_state: CompiledState<'a>,
     storage: InMemoryStorage,
     default_syntax: SyntaxChoice,
+    // The block timestamp seen by sessions, in microseconds.
+    timestamp_micros: u64,
+    // The gas used by the last metered session, reported by `take_gas_used`.
+    gas_used: Option<u64>,
+    // The number of events emitted by the last session, reported by `take_events_emitted`.
+    events_emitted: Option<usize>,
+    // The natives registered with `register_natives` which the test selected in `init`.
+    extra_natives: NativeFunctionTable,
 }
 
 pub fn view_resource_in_move_storage(
@@ -71,6 +103,19 @@ pub fn view_resource_in_move_storage(
     }
 }
 
+#[derive(Debug, Parser)]
+pub struct AdapterInitArgs {
+    /// The names of native function sets registered with `register_natives` which the VM uses
+    /// in addition to the standard library natives.
+    #[clap(
+        long = "natives",
+        takes_value(true),
+        multiple_values(true),
+        multiple_occurrences(true)
+    )]
+    pub natives: Vec<String>,
+}
+
 #[derive(Debug, Parser)]
 pub struct AdapterPublishArgs {
     #[clap(long)]
@@ -91,7 +136,7 @@ pub struct AdapterExecuteArgs {
 }
 
 impl<'a> MoveTestAdapter<'a> for SimpleVMTestAdapter<'a> {
-    type ExtraInitArgs = EmptyCommand;
+    type ExtraInitArgs = AdapterInitArgs;
     type ExtraPublishArgs = AdapterPublishArgs;
     type ExtraValueArgs = ();
     type ExtraRunArgs = AdapterExecuteArgs;
@@ -108,15 +153,39 @@ impl<'a> MoveTestAdapter<'a> for SimpleVMTestAdapter<'a> {
     fn init(
         default_syntax: SyntaxChoice,
         pre_compiled_deps: Option<&'a FullyCompiledProgram>,
-        task_opt: Option<TaskInput<(InitCommand, EmptyCommand)>>,
+        task_opt: Option<TaskInput<(InitCommand, AdapterInitArgs)>>,
     ) -> (Self, Option<String>) {
-        let additional_mapping = match task_opt.map(|t| t.command) {
-            Some((InitCommand { named_addresses }, _)) => {
-                verify_and_create_named_address_mapping(named_addresses).unwrap()
-            }
-            None => BTreeMap::new(),
+        let (additional_mapping, state_snapshot, native_sets) = match task_opt.map(|t| t.command) {
+            Some((
+                InitCommand {
+                    named_addresses,
+                    state_snapshot,
+                },
+                AdapterInitArgs { natives },
+            )) => (
+                verify_and_create_named_address_mapping(named_addresses).unwrap(),
+                state_snapshot,
+                natives,
+            ),
+            None => (BTreeMap::new(), None, vec![]),
         };
 
+        let mut extra_natives = vec![];
+        {
+            let registry = NATIVE_REGISTRY
+                .read()
+                .unwrap_or_else(PoisonError::into_inner);
+            for name in native_sets {
+                match registry.get(&name) {
+                    Some(natives) => extra_natives.extend(natives.iter().cloned()),
+                    None => panic!(
+                        "Invalid init. No natives are registered under the name '{}'",
+                        name
+                    ),
+                }
+            }
+        }
+
         let mut named_address_mapping = move_stdlib_named_addresses();
         for (name, addr) in additional_mapping {
             if named_address_mapping.contains_key(&name) {
@@ -131,6 +200,10 @@ impl<'a> MoveTestAdapter<'a> for SimpleVMTestAdapter<'a> {
             compiled_state: CompiledState::new(named_address_mapping, pre_compiled_deps, None),
             default_syntax,
             storage: InMemoryStorage::new(),
+            timestamp_micros: 0,
+            gas_used: None,
+            events_emitted: None,
+            extra_natives,
         };
 
         adapter
@@ -166,6 +239,15 @@ impl<'a> MoveTestAdapter<'a> for SimpleVMTestAdapter<'a> {
                 .compiled_state
                 .add_and_generate_interface_file(module.clone());
         }
+        if let Some(path) = state_snapshot {
+            adapter.load_state_snapshot(&path).unwrap_or_else(|e| {
+                panic!(
+                    "Invalid init. Failed to load the state snapshot {}: {}",
+                    path.display(),
+                    e
+                )
+            });
+        }
         (adapter, None)
     }
 
@@ -306,12 +388,48 @@ impl<'a> MoveTestAdapter<'a> for SimpleVMTestAdapter<'a> {
         view_resource_in_move_storage(&self.storage, address, module, resource, type_args)
     }
 
+    fn resource_exists(
+        &mut self,
+        address: AccountAddress,
+        module: &ModuleId,
+        resource: &IdentStr,
+        type_args: Vec<TypeTag>,
+    ) -> Result<bool> {
+        let tag = StructTag {
+            address: *module.address(),
+            module: module.name().to_owned(),
+            name: resource.to_owned(),
+            type_params: type_args,
+        };
+        Ok(self.storage.get_resource(&address, &tag).unwrap().is_some())
+    }
+
+    fn set_time(&mut self, micros: u64) -> Result<Option<String>> {
+        if micros < self.timestamp_micros {
+            return Err(anyhow!(
+                "Cannot move the timestamp back from {} to {} microseconds",
+                self.timestamp_micros,
+                micros
+            ));
+        }
+        self.timestamp_micros = micros;
+        Ok(None)
+    }
+
     fn handle_subcommand(
         &mut self,
         _: TaskInput<Self::Subcommand>,
     ) -> Result<(Option<String>, Option<Value>)> {
         unreachable!()
     }
+
+    fn take_gas_used(&mut self) -> Option<u64> {
+        self.gas_used.take()
+    }
+
+    fn take_events_emitted(&mut self) -> Option<usize> {
+        self.events_emitted.take()
+    }
 }
 
 pub fn format_vm_error(e: &VMError) -> String {
@@ -338,6 +456,19 @@ pub fn format_vm_error(e: &VMError) -> String {
 }
 
 impl<'a> SimpleVMTestAdapter<'a> {
+    /// Applies the BCS-serialized `ChangeSet` at `path` to the storage. The modules it publishes
+    /// become available to compile against.
+    fn load_state_snapshot(&mut self, path: &Path) -> Result<()> {
+        let changeset: ChangeSet = bcs::from_bytes(&std::fs::read(path)?)?;
+        for (_, _, op) in changeset.modules() {
+            if let Some(blob) = op.ok() {
+                let module = CompiledModule::deserialize(blob)?;
+                self.compiled_state.add_and_generate_interface_file(module);
+            }
+        }
+        self.storage.apply(changeset)
+    }
+
     fn perform_session_action<Ret>(
         &mut self,
         gas_budget: Option<u64>,
@@ -345,32 +476,38 @@ impl<'a> SimpleVMTestAdapter<'a> {
         vm_config: VMConfig,
     ) -> VMResult<Ret> {
         // start session
-        let vm = MoveVM::new_with_config(
-            move_stdlib::natives::all_natives(
-                STD_ADDR,
-                // TODO: come up with a suitable gas schedule
-                move_stdlib::natives::GasParameters::zeros(),
-            ),
-            vm_config,
+        let natives = move_stdlib::natives::all_natives(
+            STD_ADDR,
+            // TODO: come up with a suitable gas schedule
+            move_stdlib::natives::GasParameters::zeros(),
         )
-        .unwrap();
+        .into_iter()
+        .chain(self.extra_natives.iter().cloned());
+        let vm = MoveVM::new_with_config(natives, vm_config).unwrap();
         let (mut session, mut gas_status) = {
             let gas_status = move_cli::sandbox::utils::get_gas_status(
                 &move_vm_test_utils::gas_schedule::INITIAL_COST_SCHEDULE,
                 gas_budget,
             )
             .unwrap();
-            let session = vm.new_session(&self.storage);
+            let mut extensions = NativeContextExtensions::default();
+            extensions.add(NativeTimestampContext::new(self.timestamp_micros));
+            let session = vm.new_session_with_extensions(&self.storage, extensions);
             (session, gas_status)
         };
 
         // perform op
-        let res = f(&mut session, &mut gas_status)?;
+        let res = f(&mut session, &mut gas_status);
+        if let Some(gas_budget) = gas_budget {
+            self.gas_used = Some(gas_budget - u64::from(gas_status.remaining_gas()));
+        }
+        let res = res?;
 
         // save changeset
-        // TODO support events
-        let (changeset, _events) = session.finish()?;
+        // TODO support events, only their number is kept for `--no-events`
+        let (changeset, events) = session.finish()?;
         self.storage.apply(changeset).unwrap();
+        self.events_emitted = Some(events.len());
         Ok(res)
     }
 }
@@ -428,6 +565,25 @@ pub fn run_test(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     run_test_impl::<SimpleVMTestAdapter>(path, Some(&*PRECOMPILED_MOVE_STDLIB))
 }
 
+pub fn run_test_with_options(
+    path: &Path,
+    options: &RunTestOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    run_test_impl_with_options::<SimpleVMTestAdapter>(
+        path,
+        Some(&*PRECOMPILED_MOVE_STDLIB),
+        options,
+    )
+}
+
+pub fn run_directory(dir: &Path) -> Vec<FileResult> {
+    run_directory_impl::<SimpleVMTestAdapter>(
+        dir,
+        Some(&*PRECOMPILED_MOVE_STDLIB),
+        &RunTestOptions::default(),
+    )
+}
+
 impl From<AdapterExecuteArgs> for VMConfig {
     fn from(arg: AdapterExecuteArgs) -> VMConfig {
         VMConfig {
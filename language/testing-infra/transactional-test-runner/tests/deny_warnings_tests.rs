@@ -0,0 +1,50 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_transactional_test_runner::{
+    framework::RunTestOptions, vm_test_harness::run_test_with_options,
+};
+use std::path::Path;
+
+// The warnings name the temporary file of the task, so they cannot be part of an expected output
+// file. Instead, the expected output is empty and the output is checked through the diff.
+const WARNINGS: &str = "tests/fixtures/deny_warnings.move";
+
+fn run_against_empty_baseline(options: &RunTestOptions) -> String {
+    run_test_with_options(Path::new(WARNINGS), options)
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn warnings_pass_by_default() {
+    let diff = run_against_empty_baseline(&RunTestOptions::default());
+    assert!(
+        diff.contains("warning[W09003]: unused assignment"),
+        "{}",
+        diff
+    );
+    assert!(!diff.contains("--deny-warnings"), "{}", diff);
+    assert!(diff.contains("return values: 2"), "{}", diff);
+}
+
+#[test]
+fn deny_warnings_fails_task() {
+    let options = RunTestOptions {
+        deny_warnings: true,
+        ..Default::default()
+    };
+    let diff = run_against_empty_baseline(&options);
+    assert!(
+        diff.contains("Error: Compilation produced warnings, which are denied by --deny-warnings"),
+        "{}",
+        diff
+    );
+    assert!(
+        diff.contains("warning[W09003]: unused assignment"),
+        "{}",
+        diff
+    );
+    // The module was not published.
+    assert!(!diff.contains("return values: 2"), "{}", diff);
+}
@@ -0,0 +1,10 @@
+// Used by deny_warnings_tests.rs. The module compiles with an unused assignment warning.
+
+//# init --addresses A=0x42
+
+//# publish
+module A::M {
+    public fun f(): u64 { let x = 1; 2 }
+}
+
+//# run A::M::f
@@ -0,0 +1,10 @@
+// Used by keep_going_tests.rs. Tasks 1 and 2 fail: the view lacks a resource and the publish
+// has no module to publish.
+
+//# init --addresses A=0x42
+
+//# view --address 0x1
+
+//# publish
+
+//# view --address 0x1 --resource 0x1::M::R
@@ -0,0 +1,8 @@
+// Used by keep_going_tests.rs. Task 1 fails with B bound to an address, which task 2 must no
+// longer see: only the named addresses from init are left.
+
+//# init --addresses A=0x42
+
+//# publish --addresses B=0x43
+
+//# view --address B --resource A::M::R
@@ -0,0 +1,29 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_transactional_test_runner::vm_test_harness::run_test;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn inline_expected_output_mismatch() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("mismatch.move");
+    fs::write(
+        &path,
+        r#"//# init --addresses A=0x42
+
+//# view --address 0x1 --resource 0x42::N::R
+//* [Resource Exists]
+"#,
+    )
+    .unwrap();
+
+    let err = run_test(&path).unwrap_err().to_string();
+    assert!(
+        err.contains("Inline expected output differs from actual output"),
+        "{}",
+        err
+    );
+    assert!(err.contains("[No Resource Exists]"), "{}", err);
+}
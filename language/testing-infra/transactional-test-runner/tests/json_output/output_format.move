@@ -0,0 +1,13 @@
+//# init --addresses A=0x42
+
+//# publish
+module A::M {
+    public fun f(x: u64): u64 { x + 1 }
+}
+
+//# run --gas-budget 1000
+script {
+    fun main() { assert!(A::M::f(1) == 3, 7); }
+}
+
+//# view --address 0x42 --resource 0x42::M::R
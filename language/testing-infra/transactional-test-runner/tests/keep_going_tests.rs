@@ -0,0 +1,52 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_transactional_test_runner::{
+    framework::RunTestOptions,
+    vm_test_harness::{run_test, run_test_with_options},
+};
+use std::path::Path;
+
+// A failing task fails the whole file, so this cannot be checked against an expected output file.
+const TWO_FAILING_TASKS: &str = "tests/fixtures/keep_going.move";
+const FAILING_TASK_WITH_ADDRESSES: &str = "tests/fixtures/keep_going_addresses.move";
+
+#[test]
+fn keep_going_reports_all_failures() {
+    let options = RunTestOptions {
+        keep_going: true,
+        ..Default::default()
+    };
+    let err = run_test_with_options(Path::new(TWO_FAILING_TASKS), &options)
+        .unwrap_err()
+        .to_string();
+    assert!(err.starts_with("2 tasks failed:"), "{}", err);
+    assert!(err.contains("task 1 'view'. lines 6-6:"), "{}", err);
+    assert!(err.contains("task 2 'publish'. lines 8-8:"), "{}", err);
+}
+
+#[test]
+fn keep_going_clears_named_address_overrides_of_failed_task() {
+    let options = RunTestOptions {
+        keep_going: true,
+        ..Default::default()
+    };
+    let err = run_test_with_options(Path::new(FAILING_TASK_WITH_ADDRESSES), &options)
+        .unwrap_err()
+        .to_string();
+    assert!(err.starts_with("2 tasks failed:"), "{}", err);
+    assert!(err.contains("task 1 'publish'. lines 6-6:"), "{}", err);
+    assert!(
+        err.contains("task 2 'view'. lines 8-8: Failed to resolve named address 'B'"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn failure_aborts_run_by_default() {
+    let err = run_test(Path::new(TWO_FAILING_TASKS))
+        .unwrap_err()
+        .to_string();
+    assert!(!err.contains("tasks failed"), "{}", err);
+}
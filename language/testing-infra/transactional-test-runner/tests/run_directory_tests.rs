@@ -0,0 +1,36 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_transactional_test_runner::vm_test_harness::run_directory;
+use std::fs;
+use tempfile::tempdir;
+
+const SCRIPT: &str = r#"//# run
+script {
+    fun main() {}
+}
+"#;
+
+#[test]
+fn run_directory_aggregates_file_results() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("pass.move"), SCRIPT).unwrap();
+    fs::write(dir.path().join("pass.exp"), "processed 1 task\n").unwrap();
+    fs::create_dir(dir.path().join("nested")).unwrap();
+    fs::write(dir.path().join("nested/fail.move"), SCRIPT).unwrap();
+    fs::write(dir.path().join("nested/fail.exp"), "processed 2 tasks\n").unwrap();
+    fs::write(dir.path().join("README.md"), "not a test").unwrap();
+
+    let results = run_directory(dir.path());
+    assert_eq!(results.len(), 2, "{:?}", results);
+
+    let fail = &results[0];
+    assert_eq!(fail.path, dir.path().join("nested/fail.move"));
+    assert!(!fail.passed());
+    let error = fail.error.as_ref().unwrap();
+    assert!(error.contains("Expected errors differ"), "{}", error);
+
+    let pass = &results[1];
+    assert_eq!(pass.path, dir.path().join("pass.move"));
+    assert!(pass.passed(), "{:?}", pass.error);
+}
@@ -0,0 +1,91 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_binary_format::CompiledModule;
+use move_compiler::{compiled_unit::AnnotatedCompiledUnit, Compiler};
+use move_core_types::{
+    account_address::AccountAddress,
+    effects::{ChangeSet, Op},
+    identifier::Identifier,
+    language_storage::StructTag,
+};
+use move_transactional_test_runner::vm_test_harness::run_test;
+use std::{collections::BTreeMap, fs, path::Path};
+use tempfile::tempdir;
+
+const SNAPSHOT_MODULE: &str = r#"module 0x42::M {
+    struct R has key { value: u64 }
+
+    public fun value(addr: address): u64 acquires R {
+        borrow_global<R>(addr).value
+    }
+}
+"#;
+
+const TASKS: &str = r#"//# init --state-snapshot {{SNAPSHOT}}
+
+//# view --address 0x42 --resource 0x42::M::R
+
+//# run
+script {
+    fun main() { assert!(0x42::M::value(@0x42) == 7, 0); }
+}
+"#;
+
+const EXPECTED_OUTPUT: &str = "processed 3 tasks
+
+task 1 'view'. lines 3-3:
+key 0x00000000000000000000000000000042::M::R {
+    value: 7
+}
+";
+
+fn compile_module(dir: &Path, source: &str) -> CompiledModule {
+    let path = dir.join("M.move");
+    fs::write(&path, source).unwrap();
+    let (_, units) = Compiler::from_files(
+        vec![path.to_str().unwrap().to_owned()],
+        vec![],
+        BTreeMap::<String, _>::new(),
+    )
+    .build_and_report()
+    .unwrap();
+    match units.into_iter().next() {
+        Some(AnnotatedCompiledUnit::Module(module)) => module.named_module.module,
+        _ => panic!("expected a single module"),
+    }
+}
+
+#[test]
+fn view_resource_from_state_snapshot() {
+    let dir = tempdir().unwrap();
+    let module = compile_module(dir.path(), SNAPSHOT_MODULE);
+    let mut module_bytes = vec![];
+    module.serialize(&mut module_bytes).unwrap();
+
+    let addr = AccountAddress::from_hex_literal("0x42").unwrap();
+    let mut changeset = ChangeSet::new();
+    changeset
+        .add_module_op(module.self_id(), Op::New(module_bytes))
+        .unwrap();
+    let tag = StructTag {
+        address: addr,
+        module: Identifier::new("M").unwrap(),
+        name: Identifier::new("R").unwrap(),
+        type_params: vec![],
+    };
+    changeset
+        .add_resource_op(addr, tag, Op::New(bcs::to_bytes(&7u64).unwrap()))
+        .unwrap();
+    let snapshot_path = dir.path().join("snapshot.bcs");
+    fs::write(&snapshot_path, bcs::to_bytes(&changeset).unwrap()).unwrap();
+
+    let path = dir.path().join("snapshot.move");
+    fs::write(
+        &path,
+        TASKS.replace("{{SNAPSHOT}}", snapshot_path.to_str().unwrap()),
+    )
+    .unwrap();
+    fs::write(path.with_extension("exp"), EXPECTED_OUTPUT).unwrap();
+    run_test(&path).unwrap();
+}
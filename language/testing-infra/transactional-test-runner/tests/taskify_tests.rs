@@ -0,0 +1,70 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use move_transactional_test_runner::tasks::taskify_with_max_tasks;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+#[derive(Debug, Parser)]
+struct EmptyCommand {}
+
+fn test_file(num_tasks: usize) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    for i in 0..num_tasks {
+        writeln!(
+            file,
+            "//# run\nscript {{ fun main() {{ let _x = {}; }} }}\n",
+            i
+        )
+        .unwrap();
+    }
+    file
+}
+
+#[test]
+fn taskify_within_max_tasks() {
+    let file = test_file(3);
+    let tasks = taskify_with_max_tasks::<EmptyCommand>(file.path(), 3).unwrap();
+    assert_eq!(tasks.len(), 3);
+}
+
+#[test]
+fn taskify_exceeding_max_tasks() {
+    let file = test_file(4);
+    let err = taskify_with_max_tasks::<EmptyCommand>(file.path(), 3).unwrap_err();
+    assert!(err.to_string().contains("Too many tasks"), "{}", err);
+}
+
+#[test]
+fn taskify_with_zero_max_tasks() {
+    let file = test_file(1);
+    let err = taskify_with_max_tasks::<EmptyCommand>(file.path(), 0).unwrap_err();
+    assert!(err.to_string().contains("Too many tasks"), "{}", err);
+}
+
+#[test]
+fn taskify_stops_reading_at_max_tasks() {
+    let mut file = test_file(4);
+    // Reading this line would panic, as it is not valid UTF-8.
+    file.write_all(b"\xff\n").unwrap();
+    let err = taskify_with_max_tasks::<EmptyCommand>(file.path(), 3).unwrap_err();
+    assert!(err.to_string().contains("Too many tasks"), "{}", err);
+}
+
+#[test]
+fn taskify_captures_inline_expected_output() {
+    let mut file = NamedTempFile::new().unwrap();
+    write!(
+        file,
+        "//# run\nscript {{ fun main() {{}} }}\n\n//# view\n//* line 1\n//*   line 2\n"
+    )
+    .unwrap();
+    let tasks = taskify_with_max_tasks::<EmptyCommand>(file.path(), 3).unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].expected_output, None);
+    assert_eq!(
+        tasks[1].expected_output.as_deref(),
+        Some("line 1\n  line 2")
+    );
+}
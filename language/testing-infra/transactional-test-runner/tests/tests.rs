@@ -2,7 +2,83 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-pub const TEST_DIR: &str = "tests";
-use move_transactional_test_runner::vm_test_harness::run_test;
+pub const TEST_DIR: &str = "tests/vm_test_harness";
+// Tests whose expected output is in the JSON format of `--output-format json`.
+pub const JSON_TEST_DIR: &str = "tests/json_output";
+use move_core_types::{
+    account_address::AccountAddress, gas_algebra::InternalGas, identifier::Identifier,
+};
+use move_stdlib::natives::event;
+use move_transactional_test_runner::{
+    framework::{OutputFormat, RunTestOptions},
+    vm_test_harness::{register_natives, run_test, run_test_with_options},
+};
+use move_vm_types::{natives::function::NativeResult, values::Value};
+use std::{
+    path::Path,
+    sync::{Arc, Once},
+};
 
-datatest_stable::harness!(run_test, TEST_DIR, r".*\.(mvir|move)$");
+// Tests in a `debug_only` directory rely on the VM's trace hook, which release builds leave out.
+const DEBUG_ONLY_DIR: &str = "debug_only";
+
+// Registers the natives which tests select with `//# init --natives <name>`.
+fn register_test_natives() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        register_natives(
+            "dummy",
+            vec![(
+                AccountAddress::from_hex_literal("0x42").unwrap(),
+                Identifier::new("M").unwrap(),
+                Identifier::new("forty_two").unwrap(),
+                Arc::new(|_context, _ty_args, _args| {
+                    Ok(NativeResult::ok(
+                        InternalGas::zero(),
+                        vec![Value::u64(42)].into(),
+                    ))
+                }),
+            )],
+        );
+        let event_natives = event::make_all(event::GasParameters {
+            write_to_event_store: event::WriteToEventStoreGasParameters {
+                unit_cost: 0.into(),
+            },
+        })
+        .map(|(name, native)| {
+            (
+                AccountAddress::from_hex_literal("0x42").unwrap(),
+                Identifier::new("event").unwrap(),
+                Identifier::new(name).unwrap(),
+                native,
+            )
+        })
+        .collect();
+        register_natives("event", event_natives);
+    });
+}
+
+fn run_test_(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    register_test_natives();
+    if !cfg!(debug_assertions) && path.components().any(|c| c.as_os_str() == DEBUG_ONLY_DIR) {
+        return Ok(());
+    }
+    run_test(path)
+}
+
+fn run_json_test(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let options = RunTestOptions {
+        output_format: OutputFormat::Json,
+        ..Default::default()
+    };
+    run_test_with_options(path, &options)
+}
+
+datatest_stable::harness!(
+    run_test_,
+    TEST_DIR,
+    r".*\.(mvir|move)$",
+    run_json_test,
+    JSON_TEST_DIR,
+    r".*\.move$",
+);
@@ -0,0 +1,23 @@
+//# init --addresses A=42
+
+//# publish
+module A::M {
+    struct R has key { value: u64 }
+
+    public entry fun create(account: signer) {
+        move_to(&account, R { value: 7 })
+    }
+}
+
+//# assert-exists --address A --resource A::M::R --absent
+
+//# run A::M::create --signers A
+
+//# assert-exists --address A --resource A::M::R
+
+//# assert-exists --address 0x43 --resource A::M::R --absent
+
+// The expectation is not met.
+//# assert-exists --address A --resource A::M::R --absent
+
+//# assert-exists --address 0x43 --resource A::M::R
@@ -0,0 +1,12 @@
+// tests.rs registers A::M::forty_two, which returns 42, under the name dummy.
+
+//# init --addresses A=0x42 --natives dummy
+
+//# publish
+module A::M {
+    native public fun forty_two(): u64;
+
+    public fun call(): u64 { forty_two() + 1 }
+}
+
+//# run A::M::call
@@ -0,0 +1,24 @@
+//# init --addresses A=42
+
+//# publish
+module A::M {
+    public fun double(x: u64): u64 {
+        x * 2
+    }
+
+    public fun check(x: u64) {
+        assert!(double(x) < 10, 7);
+    }
+}
+
+//# run A::M::double --args 2 --trace
+
+// The trace of a failed execution shows where it aborted.
+//# run A::M::check --args 5 --trace
+
+//# run --trace
+script {
+    fun main() {
+        A::M::check(1);
+    }
+}
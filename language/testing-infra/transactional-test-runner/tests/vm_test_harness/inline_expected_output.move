@@ -0,0 +1,23 @@
+//# init --addresses A=0x42
+
+//# publish
+module A::N {
+    struct R has key { v: u64 }
+
+    public fun give(s: &signer) {
+        move_to(s, R { v: 7 })
+    }
+}
+//* 
+
+//# run --signers 0x1
+script {
+    fun main(s: signer) {
+        A::N::give(&s)
+    }
+}
+
+//# view --address 0x1 --resource 0x42::N::R
+//* key 0x00000000000000000000000000000042::N::R {
+//*     v: 7
+//* }
@@ -0,0 +1,20 @@
+// The hash of 0x42::M is the SHA3-256 hash of the bytes the publish task stores for it.
+
+//# publish
+module 0x42::M {
+    public fun value(): u64 { 7 }
+}
+
+//# publish
+module 0x42::Hash {
+    use std::option::Option;
+    use std::type_info;
+
+    public fun of(name: vector<u8>): Option<vector<u8>> {
+        type_info::module_hash(@0x42, &name)
+    }
+}
+
+//# run 0x42::Hash::of --args b"M"
+
+//# run 0x42::Hash::of --args b"N"
@@ -0,0 +1,28 @@
+//# init --addresses A=0x42
+
+//# publish
+module A::M {
+    public fun value(): u64 { 42 }
+}
+
+// Task-level addresses take precedence over the ones set by init.
+//# publish --addresses A=0x43
+module A::M {
+    public fun value(): u64 { 43 }
+}
+
+//# run
+script {
+    use A::M;
+    fun main() { assert!(M::value() == 42, 0); }
+}
+
+//# run --addresses A=0x43
+script {
+    use A::M;
+    fun main() { assert!(M::value() == 43, 1); }
+}
+
+//# run 0x42::M::value
+
+//# run 0x43::M::value
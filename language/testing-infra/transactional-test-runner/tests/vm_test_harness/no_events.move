@@ -0,0 +1,25 @@
+// tests.rs registers the event natives of the nursery for A::event under the name event.
+
+//# init --addresses A=0x42 --natives event
+
+//# publish
+module A::event {
+    native public fun write_to_event_store<T: drop + store>(guid: vector<u8>, count: u64, msg: T);
+}
+
+//# publish
+module A::M {
+    struct Ping has drop, store { value: u64 }
+
+    public fun ping() {
+        A::event::write_to_event_store(x"01", 0, Ping { value: 1 })
+    }
+
+    public fun quiet(): u64 { 1 }
+}
+
+//# run A::M::ping --no-events
+
+//# run A::M::quiet --no-events
+
+//# run A::M::ping
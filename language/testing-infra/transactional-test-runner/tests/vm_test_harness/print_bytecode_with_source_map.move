@@ -0,0 +1,16 @@
+//# print-bytecode --with-source-map
+main() {
+    let x: u64;
+label b0:
+    x = 1;
+    _ = move(x);
+    return;
+}
+
+//# print-bytecode --input=module --with-source-map
+module 0x42.M {
+    f(a: u64): u64 {
+    label b0:
+        return move(a) + 1;
+    }
+}
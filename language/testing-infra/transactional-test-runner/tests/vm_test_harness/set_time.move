@@ -0,0 +1,29 @@
+//# init --addresses A=42
+
+//# publish
+module A::Clock {
+    use std::timestamp;
+
+    public fun assert_now(expected: u64) {
+        assert!(timestamp::now_microseconds() == expected, 1000);
+    }
+
+    public fun now(): u64 {
+        timestamp::now_microseconds()
+    }
+}
+
+//# run A::Clock::assert_now --args 0
+
+//# set-time --micros 1000000
+
+//# run A::Clock::assert_now --args 1000000
+
+//# run A::Clock::now
+
+//# set-time --micros 2500000
+
+//# run A::Clock::now
+
+// Time cannot move backwards.
+//# set-time --micros 1
@@ -0,0 +1,19 @@
+//# publish
+module 0x42::M {
+    struct R has key {}
+}
+
+//# set-version 1
+
+//# view --address 0x1 --resource 0x42::M::R (skip-if-version < 2)
+
+// The annotation may follow the last line of a command that spans several lines.
+//# view --address 0x2
+//#      --resource 0x42::M::R (skip-if-version >= 2)
+
+//# set-version 2
+
+//# view --address 0x1 --resource 0x42::M::R (skip-if-version < 2)
+
+//# view --address 0x2
+//#      --resource 0x42::M::R (skip-if-version >= 2)
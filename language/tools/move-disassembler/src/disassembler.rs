@@ -9,7 +9,7 @@ use move_binary_format::{
     binary_views::BinaryIndexedView,
     control_flow_graph::{ControlFlowGraph, VMControlFlowGraph},
     file_format::{
-        Ability, AbilitySet, Bytecode, CodeUnit, FieldHandleIndex, FunctionDefinition,
+        Ability, AbilitySet, Bytecode, CodeOffset, CodeUnit, FieldHandleIndex, FunctionDefinition,
         FunctionDefinitionIndex, FunctionHandle, Signature, SignatureIndex, SignatureToken,
         StructDefinition, StructDefinitionIndex, StructFieldInformation, StructTypeParameter,
         TableIndex, TypeSignature, Visibility,
@@ -42,6 +42,11 @@ pub struct DisassemblerOptions {
     /// Print the locals inside each function body.
     #[clap(long = "print-locals")]
     pub print_locals: bool,
+
+    /// Print the source line ahead of the instructions compiled from it. Requires the source
+    /// code to be set on the source mapping.
+    #[clap(long = "print-source-lines")]
+    pub print_source_lines: bool,
 }
 
 impl DisassemblerOptions {
@@ -51,6 +56,7 @@ impl DisassemblerOptions {
             print_code: true,
             print_basic_blocks: true,
             print_locals: true,
+            print_source_lines: false,
         }
     }
 }
@@ -885,6 +891,10 @@ impl<'a> Disassembler<'a> {
             })
             .collect();
 
+        if self.options.print_source_lines {
+            self.annotate_source_lines(function_source_map, &mut instrs);
+        }
+
         if self.options.print_basic_blocks {
             let cfg = VMControlFlowGraph::new(&code.code);
             for (block_number, block_id) in cfg.blocks().iter().enumerate() {
@@ -898,6 +908,33 @@ impl<'a> Disassembler<'a> {
         Ok(instrs)
     }
 
+    /// Prefixes each instruction which starts a new source line with that line. Does nothing if
+    /// the source code is not available.
+    fn annotate_source_lines(
+        &self,
+        function_source_map: &FunctionSourceMap,
+        instrs: &mut [String],
+    ) {
+        let source = match &self.source_mapper.source_code {
+            Some((_, source)) => source,
+            None => return,
+        };
+        let mut last_line = None;
+        for (pc, instr) in instrs.iter_mut().enumerate() {
+            let line = function_source_map
+                .get_code_location(pc as CodeOffset)
+                .and_then(|loc| source.get(..loc.start() as usize))
+                .map(|prefix| prefix.matches('\n').count() + 1);
+            if let Some(line) = line {
+                if last_line != Some(line) {
+                    let text = source.lines().nth(line - 1).unwrap_or("").trim();
+                    *instr = format!("\t// line {}: {}\n{}", line, text, instr);
+                }
+            }
+            last_line = line;
+        }
+    }
+
     fn disassemble_struct_type_formals(
         source_map_ty_params: &[SourceName],
         type_parameters: &[StructTypeParameter],